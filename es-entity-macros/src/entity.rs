@@ -40,6 +40,8 @@ pub struct EsEntity {
     new_entity_ident: Option<syn::Ident>,
     #[darling(default, rename = "event")]
     event_ident: Option<syn::Ident>,
+    #[darling(default)]
+    snapshot: bool,
     data: darling::ast::Data<(), Field>,
 }
 
@@ -53,6 +55,16 @@ impl EsEntity {
         }
     }
 
+    fn snapshot_fields(&self) -> Vec<&Field> {
+        match &self.data {
+            darling::ast::Data::Struct(fields) => fields
+                .iter()
+                .filter(|field| !field.is_events_field() && !field.nested)
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
     fn nested_fields(&self) -> Vec<&Field> {
         match &self.data {
             darling::ast::Data::Struct(fields) => {
@@ -114,6 +126,38 @@ impl ToTokens for EsEntity {
             }
         });
 
+        let snapshot = if self.snapshot {
+            let names: Vec<_> = self
+                .snapshot_fields()
+                .into_iter()
+                .filter_map(|f| f.ident.as_ref())
+                .collect();
+            let keys = names.iter().map(|n| n.to_string());
+            Some(quote! {
+                impl #ident {
+                    /// Serializes the projected state of the entity (all fields except the
+                    /// events and nested entities) - this is not the event stream.
+                    ///
+                    /// # Panics
+                    ///
+                    /// If a field's `Serialize` impl fails.
+                    pub fn to_snapshot_json(&self) -> es_entity::prelude::serde_json::Value {
+                        let mut map = es_entity::prelude::serde_json::Map::new();
+                        #(
+                            map.insert(
+                                #keys.to_string(),
+                                es_entity::prelude::serde_json::to_value(&self.#names)
+                                    .expect("Couldn't serialize snapshot field"),
+                            );
+                        )*
+                        es_entity::prelude::serde_json::Value::Object(map)
+                    }
+                }
+            })
+        } else {
+            None
+        };
+
         tokens.append_all(quote! {
             impl es_entity::EsEntity for #ident {
                 type Event = #event;
@@ -128,6 +172,8 @@ impl ToTokens for EsEntity {
             }
 
             #(#nested)*
+
+            #snapshot
         });
     }
 }
@@ -239,4 +285,55 @@ mod tests {
 
         assert_eq!(output.to_string(), expected.to_string());
     }
+
+    #[test]
+    fn test_derive_with_snapshot() {
+        let input: syn::DeriveInput = parse_quote! {
+            #[derive(EsEntity)]
+            #[es_entity(snapshot)]
+            pub struct User {
+                pub id: UserId,
+                pub email: String,
+                #[es_entity(nested)]
+                children: Nested<ChildEntity>,
+                events: EntityEvents<UserEvent>
+            }
+        };
+
+        let entity = EsEntity::from_derive_input(&input).unwrap();
+        let output = entity
+            .snapshot_fields()
+            .into_iter()
+            .map(|f| f.ident.as_ref().unwrap().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(output, vec!["id", "email"]);
+
+        let output = derive(input).unwrap().to_string();
+        let expected = quote! {
+            impl User {
+                /// Serializes the projected state of the entity (all fields except the
+                /// events and nested entities) - this is not the event stream.
+                ///
+                /// # Panics
+                ///
+                /// If a field's `Serialize` impl fails.
+                pub fn to_snapshot_json(&self) -> es_entity::prelude::serde_json::Value {
+                    let mut map = es_entity::prelude::serde_json::Map::new();
+                    map.insert(
+                        "id".to_string(),
+                        es_entity::prelude::serde_json::to_value(&self.id)
+                            .expect("Couldn't serialize snapshot field"),
+                    );
+                    map.insert(
+                        "email".to_string(),
+                        es_entity::prelude::serde_json::to_value(&self.email)
+                            .expect("Couldn't serialize snapshot field"),
+                    );
+                    es_entity::prelude::serde_json::Value::Object(map)
+                }
+            }
+        };
+
+        assert!(output.ends_with(&expected.to_string()));
+    }
 }
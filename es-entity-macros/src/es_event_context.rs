@@ -1,19 +1,70 @@
 use proc_macro2::TokenStream as TokenStream2;
-use syn::{Ident, ItemFn, Token, parse::Parse, parse::ParseStream, punctuated::Punctuated};
+use syn::{
+    Ident, ItemFn, Token,
+    ext::IdentExt,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+};
+
+/// A captured value: either a parameter (`arg`) or a field path rooted at
+/// a parameter (`cmd.tenant_id`). The last segment is used as the key.
+struct ContextArg {
+    root: Ident,
+    fields: Vec<Ident>,
+}
+
+impl ContextArg {
+    fn key_ident(&self) -> &Ident {
+        self.fields.last().unwrap_or(&self.root)
+    }
+
+    fn key(&self) -> String {
+        self.key_ident().to_string()
+    }
+
+    fn path(&self) -> String {
+        std::iter::once(&self.root)
+            .chain(&self.fields)
+            .map(|i| i.to_string())
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+}
+
+impl Parse for ContextArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let root = input.call(Ident::parse_any)?;
+        let mut fields = Vec::new();
+        while input.peek(Token![.]) {
+            input.parse::<Token![.]>()?;
+            fields.push(input.parse()?);
+        }
+        Ok(ContextArg { root, fields })
+    }
+}
 
 struct MacroArgs {
-    args: Vec<Ident>,
+    args: Vec<ContextArg>,
 }
 
 impl Parse for MacroArgs {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let args = Punctuated::<Ident, Token![,]>::parse_terminated(input)?;
+        let args = Punctuated::<ContextArg, Token![,]>::parse_terminated(input)?;
         Ok(MacroArgs {
             args: args.into_iter().collect(),
         })
     }
 }
 
+fn is_fn_param(sig: &syn::Signature, ident: &Ident) -> bool {
+    sig.inputs.iter().any(|input| match input {
+        syn::FnArg::Receiver(_) => ident == "self",
+        syn::FnArg::Typed(pat_type) => {
+            matches!(&*pat_type.pat, syn::Pat::Ident(pat) if &pat.ident == ident)
+        }
+    })
+}
+
 // Wrapper for the proc macro that converts between TokenStream types
 pub fn make(
     args: proc_macro::TokenStream,
@@ -35,13 +86,43 @@ pub fn make_internal(args: TokenStream2, input: ItemFn) -> darling::Result<Token
 
     let is_async = sig.asyncness.is_some();
 
+    let mut errors = darling::Error::accumulator();
+    for arg in macro_args.args.iter().filter(|a| !a.fields.is_empty()) {
+        if !is_fn_param(&sig, &arg.root) {
+            errors.push(
+                darling::Error::custom(format!(
+                    "`{}` is not a parameter of this function",
+                    arg.root
+                ))
+                .with_span(&arg.root),
+            );
+        }
+    }
+    let mut captured = std::collections::HashMap::new();
+    for arg in macro_args.args.iter() {
+        if let Some(first) = captured.insert(arg.key(), arg) {
+            errors.push(
+                darling::Error::custom(format!(
+                    "`{}` uses the same context key `{}` as `{}`",
+                    arg.path(),
+                    arg.key(),
+                    first.path()
+                ))
+                .with_span(arg.key_ident()),
+            );
+        }
+    }
+    errors.finish()?;
+
     let insert_stmts: Vec<_> = macro_args
         .args
         .iter()
         .map(|arg| {
-            let arg_name = arg.to_string();
+            let key = arg.key();
+            let root = &arg.root;
+            let fields = &arg.fields;
             quote::quote! {
-                let _ = ctx.insert(#arg_name, &#arg);
+                let _ = ctx.insert(#key, &#root #(.#fields)*);
             }
         })
         .collect();
@@ -143,6 +224,72 @@ mod tests {
         assert_eq!(output.to_string(), expected.to_string());
     }
 
+    #[test]
+    fn field_path_args() {
+        let input: ItemFn = parse_quote! {
+            pub async fn create(&self, cmd: CreateUserCommand) {
+                unimplemented!()
+            }
+        };
+
+        let args = quote! { cmd.tenant_id, cmd.actor.id };
+
+        let output = make_internal(args, input).unwrap();
+
+        let expected = quote! {
+            pub async fn create(&self, cmd: CreateUserCommand) {
+                use es_entity::context::WithEventContext;
+                let data = es_entity::context::EventContext::current().data();
+                async {
+                    {
+                        let mut ctx = es_entity::context::EventContext::current();
+                        let _ = ctx.insert("tenant_id", &cmd.tenant_id);
+                        let _ = ctx.insert("id", &cmd.actor.id);
+                    }
+                    {
+                        unimplemented!()
+                    }
+                }.with_event_context(data).await
+            }
+        };
+
+        assert_eq!(output.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn field_path_unknown_root() {
+        let input: ItemFn = parse_quote! {
+            pub fn create(&self, cmd: CreateUserCommand) {
+                unimplemented!()
+            }
+        };
+
+        let args = quote! { command.tenant_id };
+
+        let err = make_internal(args, input).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("`command` is not a parameter of this function")
+        );
+    }
+
+    #[test]
+    fn field_path_duplicate_key() {
+        let input: ItemFn = parse_quote! {
+            pub fn create(&self, cmd: CreateUserCommand) {
+                unimplemented!()
+            }
+        };
+
+        let args = quote! { cmd.id, cmd.actor.id };
+
+        let err = make_internal(args, input).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("`cmd.actor.id` uses the same context key `id` as `cmd.id`")
+        );
+    }
+
     #[test]
     fn async_no_args() {
         let input: ItemFn = parse_quote! {
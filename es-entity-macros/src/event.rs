@@ -1,7 +1,10 @@
 use convert_case::{Case, Casing};
 use darling::{FromDeriveInput, ToTokens};
 use proc_macro2::TokenStream;
-use quote::{TokenStreamExt, quote};
+use quote::{TokenStreamExt, quote, quote_spanned};
+use syn::spanned::Spanned;
+
+use crate::event_header::{serde_attr_value, serde_field_name};
 
 #[derive(Debug, Clone, FromDeriveInput)]
 #[darling(attributes(es_event))]
@@ -11,6 +14,15 @@ pub struct EsEvent {
     id: syn::Type,
     #[darling(default, rename = "event_context")]
     event_ctx: Option<bool>,
+    #[darling(default)]
+    implicit_id: bool,
+    #[darling(default)]
+    version: Option<u32>,
+    #[darling(default)]
+    header: Option<syn::Type>,
+    /// Generates `event_schema()` - requires the enum to derive `JsonSchema`.
+    #[darling(default)]
+    json_schema: bool,
 }
 
 /// Information about forgettable fields in an event enum.
@@ -23,10 +35,16 @@ struct ForgettableInfo {
 
 pub fn derive(ast: syn::DeriveInput) -> darling::Result<proc_macro2::TokenStream> {
     let event = EsEvent::from_derive_input(&ast)?;
+    validate_event_types(&ast)?;
+    if event.implicit_id {
+        validate_implicit_id(&ast)?;
+    }
+    validate_schema_version(&ast, event.version)?;
     let forgettable_info = extract_forgettable_info(&ast);
     let ident = &event.ident;
 
     let mut tokens = quote!(#event);
+    tokens.append_all(header_tokens(&ast, &event)?);
 
     // Generate forgettable support methods
     let has_forgettable = forgettable_info.has_forgettable;
@@ -114,6 +132,10 @@ pub fn derive(ast: syn::DeriveInput) -> darling::Result<proc_macro2::TokenStream
         }
     });
 
+    if event.json_schema {
+        tokens.append_all(json_schema_tokens(ident));
+    }
+
     Ok(tokens)
 }
 
@@ -153,6 +175,272 @@ fn extract_forgettable_info(ast: &syn::DeriveInput) -> ForgettableInfo {
     }
 }
 
+/// The `#[es_event(...)]` attributes accepted on a variant.
+#[derive(Default)]
+struct VariantAttrs {
+    rename: Option<String>,
+    version: Option<u32>,
+}
+
+/// Parse `#[es_event(rename = "...")]` (or its alias `tag_value`) and
+/// `#[es_event(version = N)]` on a variant.
+fn variant_attrs(variant: &syn::Variant) -> darling::Result<VariantAttrs> {
+    let mut attrs = VariantAttrs::default();
+    for attr in &variant.attrs {
+        if !attr.path().is_ident("es_event") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") || meta.path.is_ident("tag_value") {
+                let lit: syn::LitStr = meta.value()?.parse()?;
+                if attrs.rename.as_ref().is_some_and(|v| v != &lit.value()) {
+                    return Err(meta.error("conflicting `rename` / `tag_value` values"));
+                }
+                attrs.rename = Some(lit.value());
+                Ok(())
+            } else if meta.path.is_ident("version") {
+                let lit: syn::LitInt = meta.value()?.parse()?;
+                attrs.version = Some(lit.base10_parse()?);
+                Ok(())
+            } else {
+                Err(meta.error(
+                    "unsupported es_event attribute on variant, expected `rename`, `tag_value` or `version`",
+                ))
+            }
+        })?;
+    }
+    Ok(attrs)
+}
+
+fn variant_rename(variant: &syn::Variant) -> darling::Result<Option<String>> {
+    Ok(variant_attrs(variant)?.rename)
+}
+
+/// The string persisted in the `event_type` column for a variant.
+fn variant_event_type(variant: &syn::Variant) -> darling::Result<String> {
+    Ok(variant_rename(variant)?.unwrap_or_else(|| variant.ident.to_string().to_case(Case::Snake)))
+}
+
+/// Ensure every variant maps to a distinct `event_type` and that a custom
+/// `rename` agrees with the discriminant serde writes for the variant.
+fn validate_event_types(ast: &syn::DeriveInput) -> darling::Result<()> {
+    let syn::Data::Enum(data) = &ast.data else {
+        return Ok(());
+    };
+    let rename_rule = parse_serde_rename_all(ast);
+    let mut errors = darling::Error::accumulator();
+    let mut seen = std::collections::HashMap::new();
+    for variant in &data.variants {
+        let Some(event_type) = errors.handle(variant_event_type(variant)) else {
+            continue;
+        };
+        if let Some(Some(rename)) = errors.handle(variant_rename(variant)) {
+            let serde_name = serde_variant_name(variant, &rename_rule);
+            if serde_name != rename {
+                errors.push(
+                    darling::Error::custom(format!(
+                        "es_event rename \"{rename}\" does not match the serialized variant name \"{serde_name}\"; add #[serde(rename = \"{rename}\")] to the variant",
+                    ))
+                    .with_span(&variant.ident),
+                );
+            }
+        }
+        if let Some(other) = seen.insert(event_type.clone(), &variant.ident) {
+            errors.push(
+                darling::Error::custom(format!(
+                    "variants `{other}` and `{}` share the event type \"{event_type}\"",
+                    variant.ident,
+                ))
+                .with_span(&variant.ident),
+            );
+        }
+    }
+    errors.finish()
+}
+
+#[cfg(feature = "json-schema")]
+fn json_schema_tokens(ident: &syn::Ident) -> TokenStream {
+    quote! {
+        impl #ident {
+            /// JSON schema of the persisted event envelope (the `event` column).
+            ///
+            /// Describes the tagged union including the `type` discriminator.
+            pub fn event_schema() -> es_entity::prelude::schemars::Schema {
+                es_entity::prelude::schemars::schema_for!(#ident)
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "json-schema"))]
+fn json_schema_tokens(ident: &syn::Ident) -> TokenStream {
+    quote_spanned! { ident.span() =>
+        compile_error!("#[es_event(json_schema)] requires the `json-schema` feature of es-entity");
+    }
+}
+
+/// With `implicit_id` the `id` key of the payload is owned by the macro.
+fn validate_implicit_id(ast: &syn::DeriveInput) -> darling::Result<()> {
+    let syn::Data::Enum(data) = &ast.data else {
+        return Ok(());
+    };
+    let mut errors = darling::Error::accumulator();
+    for variant in &data.variants {
+        if let Some(field) = variant
+            .fields
+            .iter()
+            .find(|f| f.ident.as_ref().is_some_and(|i| i == "id"))
+        {
+            errors.push(
+                darling::Error::custom(
+                    "`id` is injected by `implicit_id`; remove the field from the variant",
+                )
+                .with_span(field),
+            );
+        }
+    }
+    errors.finish()
+}
+
+/// A versioned event owns the `schema_version` key of the payload.
+fn validate_schema_version(
+    ast: &syn::DeriveInput,
+    enum_version: Option<u32>,
+) -> darling::Result<()> {
+    let syn::Data::Enum(data) = &ast.data else {
+        return Ok(());
+    };
+    let mut errors = darling::Error::accumulator();
+    for variant in &data.variants {
+        let Some(attrs) = errors.handle(variant_attrs(variant)) else {
+            continue;
+        };
+        if attrs.version.or(enum_version).is_none() {
+            continue;
+        }
+        let rename_all = variant_fields_rename_all(ast, variant);
+        if let Some(field) = variant.fields.iter().find(|f| {
+            serde_field_name(f, rename_all.as_deref()).is_some_and(|n| n == "schema_version")
+        }) {
+            errors.push(
+                darling::Error::custom(
+                    "`schema_version` is written by `#[es_event(version = ..)]`; rename the field",
+                )
+                .with_span(field),
+            );
+        }
+    }
+    errors.finish()
+}
+
+/// With `#[es_event(header = "..")]` every variant embeds the header via `#[serde(flatten)]`.
+///
+/// Generates the `header()` accessor and compile time checks that no variant field (nor
+/// a key written by the macro) collides with a field of the header.
+fn header_tokens(ast: &syn::DeriveInput, event: &EsEvent) -> darling::Result<TokenStream> {
+    let Some(header) = &event.header else {
+        return Ok(quote! {});
+    };
+    let syn::Data::Enum(data) = &ast.data else {
+        return Ok(quote! {});
+    };
+    let ident = &ast.ident;
+    let header_str = header.to_token_stream().to_string();
+    let is_header = |field: &syn::Field| field.ty.to_token_stream().to_string() == header_str;
+
+    let mut errors = darling::Error::accumulator();
+    let mut reserved = vec!["type"];
+    if event.implicit_id {
+        reserved.push("id");
+    }
+    if event.version.is_some()
+        || data
+            .variants
+            .iter()
+            .any(|v| variant_attrs(v).is_ok_and(|a| a.version.is_some()))
+    {
+        reserved.push("schema_version");
+    }
+    let mut checks: Vec<_> = reserved
+        .into_iter()
+        .map(|key| {
+            let msg =
+                format!("`{key}` is written by `EsEvent` and can not be a field of the event header `{header_str}`");
+            quote_spanned! {header.span()=>
+                assert!(!es_entity::header_has_field(<#header as es_entity::EsEventHeader>::FIELDS, #key), #msg);
+            }
+        })
+        .collect();
+    let mut arms = Vec::new();
+    for variant in &data.variants {
+        let variant_ident = &variant.ident;
+        let header_fields: Vec<_> = variant.fields.iter().filter(|f| is_header(f)).collect();
+        match header_fields.as_slice() {
+            [field] if field.ident.is_some() && is_flattened(field) => {
+                let field_ident = &field.ident;
+                arms.push(quote! {
+                    Self::#variant_ident { #field_ident, .. } => #field_ident,
+                });
+            }
+            _ => {
+                errors.push(
+                    darling::Error::custom(format!(
+                        "variant `{variant_ident}` must embed the header once as `#[serde(flatten)] header: {header_str}`",
+                    ))
+                    .with_span(variant_ident),
+                );
+                continue;
+            }
+        }
+        let rename_all = variant_fields_rename_all(ast, variant);
+        for field in variant.fields.iter().filter(|f| !is_header(f)) {
+            let Some(name) = serde_field_name(field, rename_all.as_deref()) else {
+                continue;
+            };
+            let msg = format!(
+                "field `{name}` of `{ident}::{variant_ident}` collides with a field of the event header `{header_str}`"
+            );
+            checks.push(quote_spanned! {field.span()=>
+                assert!(!es_entity::header_has_field(<#header as es_entity::EsEventHeader>::FIELDS, #name), #msg);
+            });
+        }
+    }
+    errors.finish()?;
+
+    Ok(quote! {
+        impl #ident {
+            /// The header shared by all variants.
+            pub fn header(&self) -> &#header {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+
+        const _: () = {
+            #(#checks)*
+        };
+    })
+}
+
+fn is_flattened(field: &syn::Field) -> bool {
+    field.attrs.iter().any(|attr| {
+        let mut flatten = false;
+        if attr.path().is_ident("serde") {
+            let _ = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("flatten") {
+                    flatten = true;
+                } else {
+                    // Consume any value so parse_nested_meta can continue to the next item
+                    let _ = meta.value().and_then(|v| v.parse::<syn::Expr>());
+                }
+                Ok(())
+            });
+        }
+        flatten
+    })
+}
+
 /// Check if a type's last path segment is "Forgettable".
 fn is_forgettable_type(ty: &syn::Type) -> bool {
     if let syn::Type::Path(type_path) = ty
@@ -163,6 +451,12 @@ fn is_forgettable_type(ty: &syn::Type) -> bool {
     false
 }
 
+/// The `rename_all` rule serde applies to the fields of `variant`.
+fn variant_fields_rename_all(ast: &syn::DeriveInput, variant: &syn::Variant) -> Option<String> {
+    serde_attr_value(&variant.attrs, "rename_all")
+        .or_else(|| serde_attr_value(&ast.attrs, "rename_all_fields"))
+}
+
 /// Parse the `rename_all` value from `#[serde(tag = "type", rename_all = "...")]`.
 fn parse_serde_rename_all(ast: &syn::DeriveInput) -> Option<String> {
     for attr in &ast.attrs {
@@ -257,9 +551,10 @@ impl ToTokens for EsEvent {
                     .iter()
                     .map(|v| {
                         let variant_ident = &v.ident;
-                        let snake_name = variant_ident.to_string().to_case(Case::Snake);
+                        let event_type = variant_event_type(v)
+                            .unwrap_or_else(|_| variant_ident.to_string().to_case(Case::Snake));
                         quote! {
-                            Self::#variant_ident { .. } => #snake_name,
+                            Self::#variant_ident { .. } => #event_type,
                         }
                     })
                     .collect();
@@ -268,6 +563,75 @@ impl ToTokens for EsEvent {
             _ => panic!("EsEvent can only be derived for enums"),
         };
 
+        let implicit_id = if self.implicit_id {
+            quote! {
+                fn inject_entity_id(
+                    entity_id: &Self::EntityId,
+                    payload: &mut es_entity::prelude::serde_json::Value,
+                ) {
+                    if let Some(payload) = payload.as_object_mut() {
+                        payload.insert(
+                            "id".to_string(),
+                            es_entity::prelude::serde_json::to_value(entity_id)
+                                .expect("Failed to serialize entity id"),
+                        );
+                    }
+                }
+
+                fn strip_entity_id(payload: &mut es_entity::prelude::serde_json::Value) {
+                    if let Some(payload) = payload.as_object_mut() {
+                        payload.remove("id");
+                    }
+                }
+            }
+        } else {
+            quote! {}
+        };
+
+        let schema_version = match &self.data {
+            darling::ast::Data::Enum(variants) => {
+                let versions: Vec<_> = variants
+                    .iter()
+                    .map(|v| {
+                        variant_attrs(v)
+                            .ok()
+                            .and_then(|a| a.version)
+                            .or(self.version)
+                    })
+                    .collect();
+                if versions.iter().all(Option::is_none) {
+                    quote! {}
+                } else if let Some(version) = self.version
+                    && versions.iter().all(|v| *v == self.version)
+                {
+                    quote! {
+                        fn schema_version(&self) -> Option<u32> {
+                            Some(#version)
+                        }
+                    }
+                } else {
+                    let arms = variants.iter().zip(versions).map(|(v, version)| {
+                        let variant_ident = &v.ident;
+                        let version = match version {
+                            Some(version) => quote! { Some(#version) },
+                            None => quote! { None },
+                        };
+                        quote! {
+                            Self::#variant_ident { .. } => #version,
+                        }
+                    });
+                    quote! {
+                        fn schema_version(&self) -> Option<u32> {
+                            match self {
+                                #(#arms)*
+                            }
+                        }
+                    }
+                }
+            }
+            _ => quote! {},
+        };
+
         tokens.append_all(quote! {
             impl es_entity::EsEvent for #ident {
                 type EntityId = #id;
@@ -281,6 +645,10 @@ impl ToTokens for EsEvent {
                         #match_arms
                     }
                 }
+
+                #implicit_id
+
+                #schema_version
             }
         });
     }
@@ -305,12 +673,13 @@ mod tests {
         let mut tokens = TokenStream::new();
         event.to_tokens(&mut tokens);
 
+        let event_context = cfg!(feature = "event-context");
         let expected = quote! {
             impl es_entity::EsEvent for UserEvent {
                 type EntityId = UserId;
 
                 fn event_context() -> bool {
-                    false
+                    #event_context
                 }
 
                 fn event_type(&self) -> &'static str {
@@ -326,4 +695,324 @@ mod tests {
 
         assert_eq!(tokens.to_string(), expected.to_string());
     }
+
+    #[test]
+    fn rename_overrides_event_type() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[es_event(id = "UserId")]
+            #[serde(tag = "type", rename_all = "snake_case")]
+            enum UserEvent {
+                #[es_event(rename = "USR_CREATED_V2")]
+                #[serde(rename = "USR_CREATED_V2")]
+                Initialized { id: UserId },
+                NameUpdated { name: String },
+            }
+        };
+        validate_event_types(&input).unwrap();
+        let event = EsEvent::from_derive_input(&input).unwrap();
+        let mut tokens = TokenStream::new();
+        event.to_tokens(&mut tokens);
+
+        let event_context = cfg!(feature = "event-context");
+        let expected = quote! {
+            impl es_entity::EsEvent for UserEvent {
+                type EntityId = UserId;
+
+                fn event_context() -> bool {
+                    #event_context
+                }
+
+                fn event_type(&self) -> &'static str {
+                    match self {
+                        Self::Initialized { .. } => "USR_CREATED_V2",
+                        Self::NameUpdated { .. } => "name_updated",
+                    }
+                }
+            }
+        };
+
+        assert_eq!(tokens.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn rename_must_match_serde_name() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[es_event(id = "UserId")]
+            #[serde(tag = "type", rename_all = "snake_case")]
+            enum UserEvent {
+                #[es_event(rename = "USR_CREATED_V2")]
+                Initialized { id: UserId },
+            }
+        };
+        assert!(validate_event_types(&input).is_err());
+    }
+
+    #[test]
+    fn renames_must_be_unique() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[es_event(id = "UserId")]
+            #[serde(tag = "type", rename_all = "snake_case")]
+            enum UserEvent {
+                #[es_event(rename = "name_updated")]
+                #[serde(rename = "name_updated")]
+                Initialized { id: UserId },
+                NameUpdated { name: String },
+            }
+        };
+        assert!(validate_event_types(&input).is_err());
+    }
+
+    #[test]
+    fn rename_sets_event_type() {
+        let variant: syn::Variant = syn::parse_quote! {
+            #[es_event(rename = "legacy_created")]
+            #[serde(rename = "legacy_created")]
+            Created { id: UserId }
+        };
+        assert_eq!(variant_event_type(&variant).unwrap(), "legacy_created");
+    }
+
+    #[test]
+    fn tag_value_is_an_alias_of_rename() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[es_event(id = "UserId")]
+            #[serde(tag = "type", rename_all = "snake_case")]
+            enum UserEvent {
+                #[es_event(tag_value = "USR_CREATED_V2")]
+                #[serde(rename = "USR_CREATED_V2")]
+                Initialized { id: UserId },
+                NameUpdated { name: String },
+            }
+        };
+        validate_event_types(&input).unwrap();
+        let syn::Data::Enum(data) = &input.data else {
+            unreachable!()
+        };
+        assert_eq!(
+            variant_event_type(&data.variants[0]).unwrap(),
+            "USR_CREATED_V2"
+        );
+    }
+
+    #[test]
+    fn tag_value_must_match_serde_name() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[es_event(id = "UserId")]
+            #[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+            enum UserEvent {
+                #[es_event(tag_value = "initialized")]
+                Initialized { id: UserId },
+            }
+        };
+        assert!(validate_event_types(&input).is_err());
+    }
+
+    #[test]
+    fn tag_values_must_be_unique() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[es_event(id = "UserId")]
+            #[serde(tag = "type", rename_all = "snake_case")]
+            enum UserEvent {
+                #[es_event(tag_value = "name_updated")]
+                #[serde(rename = "name_updated")]
+                Initialized { id: UserId },
+                NameUpdated { name: String },
+            }
+        };
+        assert!(validate_event_types(&input).is_err());
+    }
+
+    #[test]
+    fn conflicting_rename_and_tag_value() {
+        let variant: syn::Variant = syn::parse_quote! {
+            #[es_event(rename = "legacy_created", tag_value = "other")]
+            Created { id: UserId }
+        };
+        assert!(variant_rename(&variant).is_err());
+    }
+
+    #[test]
+    fn serde_rename_alone_keeps_event_type() {
+        let variant: syn::Variant = syn::parse_quote! {
+            #[serde(rename = "legacy_created")]
+            Created { id: UserId }
+        };
+        assert_eq!(variant_event_type(&variant).unwrap(), "created");
+    }
+
+    #[test]
+    fn implicit_id_rejects_id_field() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[es_event(id = "UserId", implicit_id)]
+            enum UserEvent {
+                Initialized { id: UserId, name: String },
+            }
+        };
+        assert!(validate_implicit_id(&input).is_err());
+
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[es_event(id = "UserId", implicit_id)]
+            enum UserEvent {
+                Initialized { name: String },
+            }
+        };
+        assert!(validate_implicit_id(&input).is_ok());
+    }
+
+    #[test]
+    fn variant_version_overrides_enum_version() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[es_event(id = "UserId", version = 2)]
+            enum UserEvent {
+                Initialized { id: UserId },
+                #[es_event(version = 3)]
+                NameUpdated { name: String },
+            }
+        };
+        let event = EsEvent::from_derive_input(&input).unwrap();
+        let mut tokens = TokenStream::new();
+        event.to_tokens(&mut tokens);
+
+        let event_context = cfg!(feature = "event-context");
+        let expected = quote! {
+            impl es_entity::EsEvent for UserEvent {
+                type EntityId = UserId;
+
+                fn event_context() -> bool {
+                    #event_context
+                }
+
+                fn event_type(&self) -> &'static str {
+                    match self {
+                        Self::Initialized { .. } => "initialized",
+                        Self::NameUpdated { .. } => "name_updated",
+                    }
+                }
+
+                fn schema_version(&self) -> Option<u32> {
+                    match self {
+                        Self::Initialized { .. } => Some(2u32),
+                        Self::NameUpdated { .. } => Some(3u32),
+                    }
+                }
+            }
+        };
+
+        assert_eq!(tokens.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn header_generates_accessor_and_collision_checks() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[es_event(id = "UserId", header = "EventHeader")]
+            enum UserEvent {
+                Initialized {
+                    #[serde(flatten)]
+                    header: EventHeader,
+                    name: String,
+                },
+                Closed {
+                    #[serde(flatten)]
+                    meta: EventHeader,
+                },
+            }
+        };
+        let event = EsEvent::from_derive_input(&input).unwrap();
+        let tokens = header_tokens(&input, &event).unwrap();
+
+        let expected = quote! {
+            impl UserEvent {
+                /// The header shared by all variants.
+                pub fn header(&self) -> &EventHeader {
+                    match self {
+                        Self::Initialized { header, .. } => header,
+                        Self::Closed { meta, .. } => meta,
+                    }
+                }
+            }
+
+            const _: () = {
+                assert!(!es_entity::header_has_field(<EventHeader as es_entity::EsEventHeader>::FIELDS, "type"), "`type` is written by `EsEvent` and can not be a field of the event header `EventHeader`");
+                assert!(!es_entity::header_has_field(<EventHeader as es_entity::EsEventHeader>::FIELDS, "name"), "field `name` of `UserEvent::Initialized` collides with a field of the event header `EventHeader`");
+            };
+        };
+
+        assert_eq!(tokens.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn header_collision_checks_use_serde_field_names() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[es_event(id = "UserId", header = "EventHeader")]
+            #[serde(tag = "type", rename_all_fields = "camelCase")]
+            enum UserEvent {
+                Initialized {
+                    #[serde(flatten)]
+                    header: EventHeader,
+                    display_name: String,
+                },
+                #[serde(rename_all = "kebab-case")]
+                Closed {
+                    #[serde(flatten)]
+                    header: EventHeader,
+                    closed_by: String,
+                },
+            }
+        };
+        let event = EsEvent::from_derive_input(&input).unwrap();
+        let tokens = header_tokens(&input, &event).unwrap().to_string();
+
+        assert!(tokens.contains(r#"FIELDS , "displayName")"#));
+        assert!(tokens.contains(r#"FIELDS , "closed-by")"#));
+    }
+
+    #[test]
+    fn header_must_be_flattened_into_every_variant() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[es_event(id = "UserId", header = "EventHeader")]
+            enum UserEvent {
+                Initialized { header: EventHeader, name: String },
+                Closed {},
+            }
+        };
+        let event = EsEvent::from_derive_input(&input).unwrap();
+        let err = header_tokens(&input, &event).unwrap_err();
+        assert_eq!(err.len(), 2);
+    }
+
+    #[test]
+    fn version_rejects_schema_version_field() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[es_event(id = "UserId")]
+            enum UserEvent {
+                #[es_event(version = 2)]
+                Initialized { schema_version: u32 },
+            }
+        };
+        assert!(validate_schema_version(&input, None).is_err());
+        assert!(validate_schema_version(&input, Some(2)).is_err());
+
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[es_event(id = "UserId")]
+            enum UserEvent {
+                Initialized { schema_version: u32 },
+            }
+        };
+        assert!(validate_schema_version(&input, None).is_ok());
+
+        // The serialized name is what collides
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[es_event(id = "UserId", version = 2)]
+            enum UserEvent {
+                Initialized {
+                    #[serde(rename = "schema_version")]
+                    version: u32,
+                },
+                #[serde(rename_all = "camelCase")]
+                Updated { schema_version: u32 },
+            }
+        };
+        let err = validate_schema_version(&input, Some(2)).unwrap_err();
+        assert_eq!(err.len(), 1);
+    }
 }
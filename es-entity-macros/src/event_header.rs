@@ -0,0 +1,173 @@
+use darling::{FromDeriveInput, ToTokens};
+use proc_macro2::TokenStream;
+use quote::{TokenStreamExt, quote};
+use syn::ext::IdentExt;
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(supports(struct_named), forward_attrs(serde))]
+pub struct EsEventHeader {
+    ident: syn::Ident,
+    attrs: Vec<syn::Attribute>,
+    generics: syn::Generics,
+    data: darling::ast::Data<(), syn::Field>,
+}
+
+pub fn derive(ast: syn::DeriveInput) -> darling::Result<proc_macro2::TokenStream> {
+    let header = EsEventHeader::from_derive_input(&ast)?;
+    Ok(quote!(#header))
+}
+
+/// The string value of `key` in the `#[serde(..)]` attributes, eg. `rename` or `rename_all`.
+pub fn serde_attr_value(attrs: &[syn::Attribute], key: &str) -> Option<String> {
+    for attr in attrs {
+        if !attr.path().is_ident("serde") {
+            continue;
+        }
+        let mut found = None;
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident(key) {
+                let lit: syn::LitStr = meta.value()?.parse()?;
+                found = Some(lit.value());
+            } else {
+                // Consume any value so parse_nested_meta can continue to the next item
+                let _ = meta.value().and_then(|v| v.parse::<syn::LitStr>());
+            }
+            Ok(())
+        });
+        if found.is_some() {
+            return found;
+        }
+    }
+    None
+}
+
+/// Applies a serde `rename_all` rule to a (snake_case) field name the way serde does.
+fn apply_rename_rule(field: &str, rule: &str) -> String {
+    let pascal = || {
+        field
+            .split('_')
+            .map(|part| {
+                let mut chars = part.chars();
+                chars
+                    .next()
+                    .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                    .unwrap_or_default()
+            })
+            .collect::<String>()
+    };
+    match rule {
+        "UPPERCASE" | "SCREAMING_SNAKE_CASE" => field.to_ascii_uppercase(),
+        "PascalCase" => pascal(),
+        "camelCase" => {
+            let pascal = pascal();
+            let mut chars = pascal.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_lowercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        }
+        "kebab-case" => field.replace('_', "-"),
+        "SCREAMING-KEBAB-CASE" => field.to_ascii_uppercase().replace('_', "-"),
+        _ => field.to_owned(),
+    }
+}
+
+/// The key serde writes for a named field, honoring `#[serde(rename = "...")]` and
+/// the `rename_all` rule of the enclosing struct (or enum variant).
+pub fn serde_field_name(field: &syn::Field, rename_all: Option<&str>) -> Option<String> {
+    let ident = field.ident.as_ref()?;
+    if let Some(name) = serde_attr_value(&field.attrs, "rename") {
+        return Some(name);
+    }
+    let name = ident.unraw().to_string();
+    Some(match rename_all {
+        Some(rule) => apply_rename_rule(&name, rule),
+        None => name,
+    })
+}
+
+impl ToTokens for EsEventHeader {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ident = &self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let rename_all = serde_attr_value(&self.attrs, "rename_all");
+        let fields: Vec<_> = self
+            .data
+            .as_ref()
+            .take_struct()
+            .expect("EsEventHeader can only be derived for structs")
+            .fields
+            .into_iter()
+            .filter_map(|field| serde_field_name(field, rename_all.as_deref()))
+            .collect();
+
+        tokens.append_all(quote! {
+            impl #impl_generics es_entity::EsEventHeader for #ident #ty_generics #where_clause {
+                const FIELDS: &'static [&'static str] = &[#(#fields),*];
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lists_serialized_field_names() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            struct EventHeader {
+                actor: String,
+                #[serde(rename = "at")]
+                recorded: chrono::DateTime<chrono::Utc>,
+            }
+        };
+        let tokens = derive(input).unwrap();
+
+        let expected = quote! {
+            impl es_entity::EsEventHeader for EventHeader {
+                const FIELDS: &'static [&'static str] = &["actor", "at"];
+            }
+        };
+
+        assert_eq!(tokens.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn applies_container_rename_all() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[serde(rename_all = "camelCase")]
+            struct EventHeader {
+                actor_id: String,
+                #[serde(rename = "at")]
+                recorded_at: chrono::DateTime<chrono::Utc>,
+            }
+        };
+        let tokens = derive(input).unwrap();
+
+        let expected = quote! {
+            impl es_entity::EsEventHeader for EventHeader {
+                const FIELDS: &'static [&'static str] = &["actorId", "at"];
+            }
+        };
+
+        assert_eq!(tokens.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn rename_rules_match_serde() {
+        let cases = [
+            ("lowercase", "actor_id"),
+            ("UPPERCASE", "ACTOR_ID"),
+            ("PascalCase", "ActorId"),
+            ("camelCase", "actorId"),
+            ("snake_case", "actor_id"),
+            ("SCREAMING_SNAKE_CASE", "ACTOR_ID"),
+            ("kebab-case", "actor-id"),
+            ("SCREAMING-KEBAB-CASE", "ACTOR-ID"),
+        ];
+        for (rule, expected) in cases {
+            assert_eq!(apply_rename_rule("actor_id", rule), expected, "{rule}");
+        }
+    }
+}
@@ -5,6 +5,7 @@
 mod entity;
 mod es_event_context;
 mod event;
+mod event_header;
 mod query;
 mod repo;
 mod retry_on_concurrent_modification;
@@ -21,6 +22,34 @@ pub fn es_event_derive(input: TokenStream) -> TokenStream {
     }
 }
 
+#[proc_macro_derive(EsEventHeader)]
+pub fn es_event_header_derive(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as syn::DeriveInput);
+    match event_header::derive(ast) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.write_errors().into(),
+    }
+}
+
+/// Retries an async method when it fails with a concurrent modification error.
+///
+/// Accepts the optional arguments:
+/// - `max_retries` (default `3`): total number of attempts. The result of the last attempt
+///   is returned as is once they are exhausted.
+/// - `backoff_ms`: sleep (via [`Clock::sleep`](es_entity::clock::Clock::sleep)) between
+///   attempts, doubling the duration after every attempt.
+/// - `any_error`: retry on any error instead of only on `was_concurrent_modification()`.
+/// - `is_conflict`: path to a `fn(&E) -> bool` deciding whether the error `E` is worth a
+///   retry. Needed when the error type wraps the repo error and has no
+///   `was_concurrent_modification()` of its own.
+///
+/// ```rust,ignore
+/// #[es_entity::retry_on_concurrent_modification(max_retries = 5, backoff_ms = 10)]
+/// async fn rename(&self, id: UserId, name: String) -> Result<(), UserModifyError> { .. }
+///
+/// #[es_entity::retry_on_concurrent_modification(is_conflict = AppError::is_conflict)]
+/// async fn transfer(&self, cmd: Transfer) -> Result<(), AppError> { .. }
+/// ```
 #[proc_macro_attribute]
 pub fn retry_on_concurrent_modification(args: TokenStream, input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as syn::ItemFn);
@@ -49,6 +78,7 @@ pub fn retry_on_concurrent_modification(args: TokenStream, input: TokenStream) -
 /// #[es_event_context]              // No arguments captured
 /// #[es_event_context(arg1)]         // Capture single argument
 /// #[es_event_context(arg1, arg2)]   // Capture multiple arguments
+/// #[es_event_context(cmd.tenant_id)] // Capture a field of an argument
 /// ```
 ///
 /// # Examples
@@ -103,6 +133,11 @@ pub fn retry_on_concurrent_modification(args: TokenStream, input: TokenStream) -
 /// Arguments are captured using their parameter names as keys. For example,
 /// `user_id: UserId` will be stored with key `"user_id"` in the context.
 ///
+/// Field paths rooted at a parameter use their final segment as key, so
+/// `cmd.tenant_id` is stored as `"tenant_id"`. The root must be a parameter
+/// of the function and two captures may not end up with the same key
+/// (eg. `cmd.id` and `cmd.actor.id`). Captured values must implement `Serialize`.
+///
 /// # See Also
 ///
 /// - [`EventContext`](es_entity::context::EventContext) - The context management system
@@ -11,6 +11,11 @@ pub struct QueryInput {
     pub(super) arg_exprs: Vec<syn::Expr>,
     pub(super) entity: Option<syn::Ident>,
     pub(super) forgettable_tbl: Option<String>,
+    pub(super) event_context_column: Option<String>,
+    pub(super) events_tbl: Option<String>,
+    pub(super) discriminator: Option<(String, String)>,
+    /// Placeholder bound to `first + 1` as `LIMIT` - the row beyond `first` only probes for a next page.
+    pub(super) probe_limit: Option<String>,
 }
 
 impl QueryInput {
@@ -83,11 +88,35 @@ impl Parse for QueryInput {
         let mut tbl_prefix = None;
         let mut entity = None;
         let mut forgettable_tbl = None;
+        let mut event_context_column = None;
+        let mut events_tbl = None;
+        let mut discriminator_column = None;
+        let mut discriminator = None;
+        let mut probe_limit = None;
 
         while !input.is_empty() {
             if expect_comma {
                 let _ = input.parse::<syn::token::Comma>()?;
             }
+            // Positional form as written in `es_query!`: the query followed by its args
+            if input.peek(syn::LitStr) {
+                sql = Some((
+                    Punctuated::<syn::LitStr, syn::Token![+]>::parse_separated_nonempty(input)?
+                        .iter()
+                        .map(syn::LitStr::value)
+                        .collect(),
+                    input.span(),
+                ));
+                if input.peek(syn::token::Comma) {
+                    let _ = input.parse::<syn::token::Comma>()?;
+                    args = Some(
+                        Punctuated::<syn::Expr, syn::Token![,]>::parse_terminated(input)?
+                            .into_iter()
+                            .collect(),
+                    );
+                }
+                break;
+            }
             let key: syn::Ident = input.parse()?;
 
             let _ = input.parse::<syn::token::Eq>()?;
@@ -109,6 +138,16 @@ impl Parse for QueryInput {
                 entity = Some(input.parse::<syn::Ident>()?);
             } else if key == "forgettable_tbl" {
                 forgettable_tbl = Some(input.parse::<syn::LitStr>()?.value());
+            } else if key == "event_context_column" {
+                event_context_column = Some(input.parse::<syn::LitStr>()?.value());
+            } else if key == "events_tbl" {
+                events_tbl = Some(input.parse::<syn::LitStr>()?.value());
+            } else if key == "discriminator_column" {
+                discriminator_column = Some(input.parse::<syn::LitStr>()?.value());
+            } else if key == "discriminator" {
+                discriminator = Some(input.parse::<syn::LitStr>()?.value());
+            } else if key == "probe_limit" {
+                probe_limit = Some(input.parse::<syn::LitStr>()?.value());
             } else {
                 let message = format!("unexpected input key: {key}");
                 return Err(syn::Error::new_spanned(key, message));
@@ -118,6 +157,15 @@ impl Parse for QueryInput {
         }
 
         let (sql, sql_span) = sql.ok_or_else(|| input.error("expected `sql` key"))?;
+        let discriminator = match (discriminator_column, discriminator) {
+            (Some(column), Some(value)) => Some((column, value)),
+            (None, None) => None,
+            _ => {
+                return Err(input.error(
+                    "`discriminator_column` and `discriminator` must be specified together",
+                ));
+            }
+        };
 
         Ok(QueryInput {
             tbl_prefix,
@@ -126,6 +174,10 @@ impl Parse for QueryInput {
             arg_exprs: args.unwrap_or_default(),
             entity,
             forgettable_tbl,
+            event_context_column,
+            events_tbl,
+            discriminator,
+            probe_limit,
         })
     }
 }
@@ -152,6 +204,25 @@ mod tests {
         assert_eq!(input.table_name_without_prefix().unwrap(), "users");
     }
 
+    #[test]
+    fn parse_positional_input_with_discriminator() {
+        let input: QueryInput = parse_quote!(
+            events_tbl = "account_events",
+            discriminator_column = "account_type",
+            discriminator = "savings",
+            entity = SavingsAccount,
+            "SELECT * FROM savings_accounts WHERE id = $1",
+            id as &SavingsAccountId,
+        );
+        assert_eq!(input.events_tbl, Some("account_events".to_string()));
+        assert_eq!(
+            input.discriminator,
+            Some(("account_type".to_string(), "savings".to_string()))
+        );
+        assert_eq!(input.sql, "SELECT * FROM savings_accounts WHERE id = $1");
+        assert_eq!(input.arg_exprs.len(), 1);
+    }
+
     #[test]
     fn test_order_by_columns() {
         let test_cases = vec![
@@ -193,6 +264,10 @@ mod tests {
                 arg_exprs: vec![],
                 entity: None,
                 forgettable_tbl: None,
+                event_context_column: None,
+                events_tbl: None,
+                discriminator: None,
+                probe_limit: None,
             };
             assert_eq!(input.order_by_columns(), expected, "Failed for SQL: {sql}",);
         }
@@ -54,7 +54,11 @@ impl ToTokens for EsQuery {
             syn::Ident::new(&format!("{entity_snake}_repo_types"), Span::call_site());
         let order_by = self.input.order_by();
 
-        let events_table = syn::Ident::new(&format!("{singular}_events"), Span::call_site());
+        let events_table = self
+            .input
+            .events_tbl
+            .clone()
+            .unwrap_or_else(|| format!("{singular}_events"));
         let args = &self.input.arg_exprs;
         let context_arg = format!("${}", args.len() + 1);
 
@@ -74,9 +78,49 @@ impl ToTokens for EsQuery {
                 )
             };
 
+        let context_column = self
+            .input
+            .event_context_column
+            .as_deref()
+            .unwrap_or("context");
+
+        let discriminator_condition = self
+            .input
+            .discriminator
+            .as_ref()
+            .map(|(column, value)| format!(" AND e.{} = '{}'", column, value.replace('\'', "''")))
+            .unwrap_or_default();
+
+        // The row beyond `first` only tells `fetch_n` that there is a next page
+        // so it is joined with its first event only instead of being fully loaded.
+        let (probed_entities, entities, probe_condition) = if let Some(ref probe_limit) =
+            self.input.probe_limit
+        {
+            (
+                format!(
+                    ", probed AS (SELECT i.*, ROW_NUMBER() OVER (ORDER BY {}) AS es_page_row FROM entities i)",
+                    order_by.trim_end_matches(',')
+                ),
+                "probed",
+                format!(" AND (i.es_page_row < {probe_limit} OR e.sequence = 1)"),
+            )
+        } else {
+            (String::new(), "entities", String::new())
+        };
+
         let query = format!(
-            "WITH entities AS ({}) SELECT i.id AS \"entity_id: Repo__Id\", e.sequence, e.event, CASE WHEN {} THEN e.context ELSE NULL::jsonb END as \"context: es_entity::ContextData\", e.recorded_at, {} FROM entities i JOIN {} e ON i.id = e.id{} ORDER BY {} e.sequence",
-            self.input.sql, context_arg, payload_column, events_table, forgettable_join, order_by
+            "WITH entities AS ({}){} SELECT i.id AS \"entity_id: Repo__Id\", e.sequence, e.event, CASE WHEN {} THEN e.{} ELSE NULL::jsonb END as \"context: es_entity::ContextData\", e.recorded_at, {} FROM {} i JOIN {} e ON i.id = e.id{}{}{} ORDER BY {} e.sequence",
+            self.input.sql,
+            probed_entities,
+            context_arg,
+            context_column,
+            payload_column,
+            entities,
+            events_table,
+            probe_condition,
+            discriminator_condition,
+            forgettable_join,
+            order_by
         );
 
         let forgettable_check = if self.input.forgettable_tbl.is_none() {
@@ -101,12 +145,36 @@ impl ToTokens for EsQuery {
             quote! {}
         };
 
+        let event_context_column_check = if self.input.event_context_column.is_none() {
+            quote! {
+                const _: () = assert!(
+                    !REPO__HAS_CUSTOM_EVENT_CONTEXT_COLUMN,
+                    "es_query! requires `event_context_column` parameter when the repo uses event_context_column"
+                );
+            }
+        } else {
+            quote! {}
+        };
+
+        let events_discriminator_check = if self.input.discriminator.is_none() {
+            quote! {
+                const _: () = assert!(
+                    !REPO__HAS_EVENTS_DISCRIMINATOR,
+                    "es_query! requires `events_tbl`, `discriminator_column` and `discriminator` parameters when the repo uses events_discriminator"
+                );
+            }
+        } else {
+            quote! {}
+        };
+
         tokens.append_all(quote! {
             {
                 use #repo_types_mod::*;
 
                 #forgettable_check
                 #tbl_prefix_check
+                #event_context_column_check
+                #events_discriminator_check
 
                 es_entity::EsQuery::<Self, <Self as es_entity::EsRepo>::EsQueryFlavor, _, _>::new(
                     sqlx::query_as!(
@@ -150,6 +218,14 @@ mod tests {
                     !REPO__HAS_TBL_PREFIX,
                     "es_query! requires `tbl_prefix` parameter when the repo uses tbl_prefix"
                 );
+                const _: () = assert!(
+                    !REPO__HAS_CUSTOM_EVENT_CONTEXT_COLUMN,
+                    "es_query! requires `event_context_column` parameter when the repo uses event_context_column"
+                );
+                const _: () = assert!(
+                    !REPO__HAS_EVENTS_DISCRIMINATOR,
+                    "es_query! requires `events_tbl`, `discriminator_column` and `discriminator` parameters when the repo uses events_discriminator"
+                );
 
                 es_entity::EsQuery::<Self, <Self as es_entity::EsRepo>::EsQueryFlavor, _, _>::new(
                     sqlx::query_as!(
@@ -185,6 +261,14 @@ mod tests {
                     !Repo__Event::HAS_FORGETTABLE_FIELDS,
                     "es_query! requires `forgettable_tbl` parameter when the event type has Forgettable<T> fields"
                 );
+                const _: () = assert!(
+                    !REPO__HAS_CUSTOM_EVENT_CONTEXT_COLUMN,
+                    "es_query! requires `event_context_column` parameter when the repo uses event_context_column"
+                );
+                const _: () = assert!(
+                    !REPO__HAS_EVENTS_DISCRIMINATOR,
+                    "es_query! requires `events_tbl`, `discriminator_column` and `discriminator` parameters when the repo uses events_discriminator"
+                );
 
                 es_entity::EsQuery::<Self, <Self as es_entity::EsRepo>::EsQueryFlavor, _, _>::new(
                     sqlx::query_as!(
@@ -227,6 +311,14 @@ mod tests {
                     !REPO__HAS_TBL_PREFIX,
                     "es_query! requires `tbl_prefix` parameter when the repo uses tbl_prefix"
                 );
+                const _: () = assert!(
+                    !REPO__HAS_CUSTOM_EVENT_CONTEXT_COLUMN,
+                    "es_query! requires `event_context_column` parameter when the repo uses event_context_column"
+                );
+                const _: () = assert!(
+                    !REPO__HAS_EVENTS_DISCRIMINATOR,
+                    "es_query! requires `events_tbl`, `discriminator_column` and `discriminator` parameters when the repo uses events_discriminator"
+                );
 
                 es_entity::EsQuery::<Self, <Self as es_entity::EsRepo>::EsQueryFlavor, _, _>::new(
                     sqlx::query_as!(
@@ -243,4 +335,130 @@ mod tests {
 
         assert_eq!(tokens.to_string(), expected.to_string());
     }
+
+    #[test]
+    fn query_with_events_discriminator() {
+        let input: QueryInput = parse_quote!(
+            events_tbl = "account_events",
+            discriminator_column = "account_type",
+            discriminator = "savings",
+            entity = SavingsAccount,
+            sql = "SELECT * FROM savings_accounts WHERE id = $1",
+            args = [id as SavingsAccountId]
+        );
+
+        let query = EsQuery::from(input);
+        let mut tokens = TokenStream::new();
+        query.to_tokens(&mut tokens);
+
+        let expected = quote! {
+            {
+                use savings_account_repo_types::*;
+
+                const _: () = assert!(
+                    !Repo__Event::HAS_FORGETTABLE_FIELDS,
+                    "es_query! requires `forgettable_tbl` parameter when the event type has Forgettable<T> fields"
+                );
+                const _: () = assert!(
+                    !REPO__HAS_CUSTOM_EVENT_CONTEXT_COLUMN,
+                    "es_query! requires `event_context_column` parameter when the repo uses event_context_column"
+                );
+
+                es_entity::EsQuery::<Self, <Self as es_entity::EsRepo>::EsQueryFlavor, _, _>::new(
+                    sqlx::query_as!(
+                        Repo__DbEvent,
+                        "WITH entities AS (SELECT * FROM savings_accounts WHERE id = $1) SELECT i.id AS \"entity_id: Repo__Id\", e.sequence, e.event, CASE WHEN $2 THEN e.context ELSE NULL::jsonb END as \"context: es_entity::ContextData\", e.recorded_at, NULL::jsonb as \"forgettable_payload?\" FROM entities i JOIN account_events e ON i.id = e.id AND e.account_type = 'savings' ORDER BY i.id, e.sequence",
+                        id as SavingsAccountId,
+                        <<<Self as es_entity::EsRepo>::Entity as EsEntity>::Event>::event_context(),
+                    )
+                )
+            }
+        };
+
+        assert_eq!(tokens.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn query_with_probe_limit() {
+        let input: QueryInput = parse_quote!(
+            probe_limit = "$1",
+            entity = User,
+            sql = "SELECT name, id FROM users ORDER BY name, id LIMIT $1",
+            args = [(first + 1) as i64]
+        );
+
+        let query = EsQuery::from(input);
+        let mut tokens = TokenStream::new();
+        query.to_tokens(&mut tokens);
+
+        let expected = quote! {
+            {
+                use user_repo_types::*;
+
+                const _: () = assert!(
+                    !Repo__Event::HAS_FORGETTABLE_FIELDS,
+                    "es_query! requires `forgettable_tbl` parameter when the event type has Forgettable<T> fields"
+                );
+                const _: () = assert!(
+                    !REPO__HAS_CUSTOM_EVENT_CONTEXT_COLUMN,
+                    "es_query! requires `event_context_column` parameter when the repo uses event_context_column"
+                );
+                const _: () = assert!(
+                    !REPO__HAS_EVENTS_DISCRIMINATOR,
+                    "es_query! requires `events_tbl`, `discriminator_column` and `discriminator` parameters when the repo uses events_discriminator"
+                );
+
+                es_entity::EsQuery::<Self, <Self as es_entity::EsRepo>::EsQueryFlavor, _, _>::new(
+                    sqlx::query_as!(
+                        Repo__DbEvent,
+                        "WITH entities AS (SELECT name, id FROM users ORDER BY name, id LIMIT $1), probed AS (SELECT i.*, ROW_NUMBER() OVER (ORDER BY i.name, i.id, i.id) AS es_page_row FROM entities i) SELECT i.id AS \"entity_id: Repo__Id\", e.sequence, e.event, CASE WHEN $2 THEN e.context ELSE NULL::jsonb END as \"context: es_entity::ContextData\", e.recorded_at, NULL::jsonb as \"forgettable_payload?\" FROM probed i JOIN user_events e ON i.id = e.id AND (i.es_page_row < $1 OR e.sequence = 1) ORDER BY i.name, i.id, i.id, e.sequence",
+                        (first + 1) as i64,
+                        <<<Self as es_entity::EsRepo>::Entity as EsEntity>::Event>::event_context(),
+                    )
+                )
+            }
+        };
+
+        assert_eq!(tokens.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn query_with_event_context_column() {
+        let input: QueryInput = parse_quote!(
+            entity = User,
+            event_context_column = "metadata",
+            sql = "SELECT * FROM users WHERE id = $1",
+            args = [id as UserId]
+        );
+
+        let query = EsQuery::from(input);
+        let mut tokens = TokenStream::new();
+        query.to_tokens(&mut tokens);
+
+        let expected = quote! {
+            {
+                use user_repo_types::*;
+
+                const _: () = assert!(
+                    !Repo__Event::HAS_FORGETTABLE_FIELDS,
+                    "es_query! requires `forgettable_tbl` parameter when the event type has Forgettable<T> fields"
+                );
+                const _: () = assert!(
+                    !REPO__HAS_EVENTS_DISCRIMINATOR,
+                    "es_query! requires `events_tbl`, `discriminator_column` and `discriminator` parameters when the repo uses events_discriminator"
+                );
+
+                es_entity::EsQuery::<Self, <Self as es_entity::EsRepo>::EsQueryFlavor, _, _>::new(
+                    sqlx::query_as!(
+                        Repo__DbEvent,
+                        "WITH entities AS (SELECT * FROM users WHERE id = $1) SELECT i.id AS \"entity_id: Repo__Id\", e.sequence, e.event, CASE WHEN $2 THEN e.metadata ELSE NULL::jsonb END as \"context: es_entity::ContextData\", e.recorded_at, NULL::jsonb as \"forgettable_payload?\" FROM entities i JOIN user_events e ON i.id = e.id ORDER BY i.id, e.sequence",
+                        id as UserId,
+                        <<<Self as es_entity::EsRepo>::Entity as EsEntity>::Event>::event_context(),
+                    )
+                )
+            }
+        };
+
+        assert_eq!(tokens.to_string(), expected.to_string());
+    }
 }
@@ -6,46 +6,49 @@ use super::options::{ClockFieldInfo, RepositoryOptions};
 
 pub struct Begin<'a> {
     clock_field: ClockFieldInfo<'a>,
+    has_read_pool: bool,
 }
 
 impl<'a> From<&'a RepositoryOptions> for Begin<'a> {
     fn from(opts: &'a RepositoryOptions) -> Self {
         Self {
             clock_field: opts.clock_field(),
+            has_read_pool: opts.read_pool_accessor().is_some(),
         }
     }
 }
 
 impl ToTokens for Begin<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let begin_op_body = match &self.clock_field {
+        let read_pool = RepositoryOptions::read_pool(self.has_read_pool);
+        let clock = match &self.clock_field {
             ClockFieldInfo::None => {
                 // No clock field - always use global clock
-                quote! {
-                    self.begin_op_with_clock(es_entity::clock::Clock::handle()).await
-                }
+                quote! { es_entity::clock::Clock::handle() }
             }
             ClockFieldInfo::Optional(clock_field) => {
                 // Optional clock field - use if Some, fallback to global
                 quote! {
                     match &self.#clock_field {
-                        Some(clock) => self.begin_op_with_clock(clock).await,
-                        None => self.begin_op_with_clock(es_entity::clock::Clock::handle()).await,
+                        Some(clock) => clock,
+                        None => es_entity::clock::Clock::handle(),
                     }
                 }
             }
             ClockFieldInfo::Required(clock_field) => {
                 // Required clock field - always use it
-                quote! {
-                    self.begin_op_with_clock(&self.#clock_field).await
-                }
+                quote! { &self.#clock_field }
+            }
+            ClockFieldInfo::Accessor(clock) => {
+                // Clock configured via `clock = "..."`
+                quote! { &#clock }
             }
         };
 
         tokens.append_all(quote! {
             #[inline(always)]
             pub async fn begin_op(&self) -> Result<es_entity::DbOp<'static>, sqlx::Error> {
-                #begin_op_body
+                self.begin_op_with_clock(#clock).await
             }
 
             #[inline(always)]
@@ -55,6 +58,23 @@ impl ToTokens for Begin<'_> {
             ) -> Result<es_entity::DbOp<'static>, sqlx::Error> {
                 es_entity::DbOp::init_with_clock(self.pool(), clock).await
             }
+
+            /// Like `begin_op` but pins `data` as the context of every event persisted
+            /// through the operation (instead of the thread-local `EventContext`).
+            #[inline(always)]
+            pub async fn begin_op_with_context(
+                &self,
+                data: es_entity::ContextData,
+            ) -> Result<es_entity::DbOp<'static>, sqlx::Error> {
+                Ok(self.begin_op().await?.with_context(data))
+            }
+
+            /// Begins a `READ ONLY` transaction on the read pool so that accidental
+            /// writes are rejected by the database.
+            #[inline(always)]
+            pub async fn begin_read_only_op(&self) -> Result<es_entity::DbOp<'static>, sqlx::Error> {
+                es_entity::DbOp::init_read_only_with_clock(#read_pool, #clock).await
+            }
         });
     }
 }
@@ -63,6 +63,7 @@ impl<'a> ComboCursor<'a> {
                 let tag =
                     syn::Ident::new(&format!("By{}", cursor.column.name()), Span::call_site());
                 let ident = cursor.ident();
+                let field = cursor.column.name().to_string();
                 quote! {
                     impl From<#ident> for #self_ident {
                         fn from(cursor: #ident) -> Self {
@@ -76,7 +77,7 @@ impl<'a> ComboCursor<'a> {
                         fn try_from(cursor: #self_ident) -> Result<Self, Self::Error> {
                             match cursor {
                                 #self_ident::#tag(cursor) => Ok(cursor),
-                                _ => Err(es_entity::CursorDestructureError::from((stringify!(#self_ident), stringify!(#ident)))),
+                                _ => Err(es_entity::CursorDestructureError::from((stringify!(#self_ident), stringify!(#ident), #field))),
                             }
                         }
                     }
@@ -98,14 +99,14 @@ impl<'a> ComboCursor<'a> {
     }
 
     pub fn sort_by(&self) -> TokenStream {
-        let mut default = true;
         let variants = self.cursors.iter().map(|cursor| {
             let name = syn::Ident::new(
                 &format!("{}", cursor.column.name()).to_case(Case::UpperCamel),
                 Span::call_site(),
             );
-            if default {
-                default = false;
+            // `id` is always listable so it is the default regardless of
+            // the order the `list_by` columns are declared in.
+            if cursor.column.is_id() {
                 quote! {
                     #[default]
                     #name
@@ -137,8 +138,50 @@ impl<'a> ComboCursor<'a> {
                 #(#variants),*
             }
         };
+        let column_names: Vec<_> = self
+            .cursors
+            .iter()
+            .map(|cursor| cursor.column.name().to_string())
+            .collect();
+        let parse_arms = self
+            .cursors
+            .iter()
+            .zip(&column_names)
+            .map(|(cursor, column)| {
+                let variant = syn::Ident::new(
+                    &format!("{}", cursor.column.name()).to_case(Case::UpperCamel),
+                    Span::call_site(),
+                );
+                quote! {
+                    #column => Ok(Self::#variant),
+                }
+            });
         quote! {
             #sort_by_enum
+
+            impl #name {
+                /// The snake_case column names accepted by `FromStr` / `TryFrom<&str>`.
+                pub const VALUES: &'static [&'static str] = &[#(#column_names),*];
+            }
+
+            impl std::str::FromStr for #name {
+                type Err = es_entity::SortByParseError;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    match s {
+                        #(#parse_arms)*
+                        _ => Err(es_entity::SortByParseError::new(s, Self::VALUES)),
+                    }
+                }
+            }
+
+            impl TryFrom<&str> for #name {
+                type Error = es_entity::SortByParseError;
+
+                fn try_from(s: &str) -> Result<Self, Self::Error> {
+                    s.parse()
+                }
+            }
         }
     }
 
@@ -150,18 +193,11 @@ impl<'a> ComboCursor<'a> {
                 type Error = String;
 
                 fn encode_cursor(&self) -> String {
-                    use es_entity::graphql::base64::{engine::general_purpose, Engine as _};
-                    let json = es_entity::prelude::serde_json::to_string(&self).expect("could not serialize token");
-                    general_purpose::STANDARD_NO_PAD.encode(json.as_bytes())
+                    es_entity::graphql::encode_cursor(self)
                 }
 
                 fn decode_cursor(s: &str) -> Result<Self, Self::Error> {
-                    use es_entity::graphql::base64::{engine::general_purpose, Engine as _};
-                    let bytes = general_purpose::STANDARD_NO_PAD
-                        .decode(s.as_bytes())
-                        .map_err(|e| e.to_string())?;
-                    let json = String::from_utf8(bytes).map_err(|e| e.to_string())?;
-                    es_entity::prelude::serde_json::from_str(&json).map_err(|e| e.to_string())
+                    es_entity::graphql::decode_cursor(s)
                 }
             }
         }
@@ -251,7 +287,7 @@ mod tests {
                 fn try_from(cursor: UserCursor) -> Result<Self, Self::Error> {
                     match cursor {
                         UserCursor::Byid(cursor) => Ok(cursor),
-                        _ => Err(es_entity::CursorDestructureError::from((stringify!(UserCursor), stringify!(UserByIdCursor)))),
+                        _ => Err(es_entity::CursorDestructureError::from((stringify!(UserCursor), stringify!(UserByIdCursor), "id"))),
                     }
                 }
             }
@@ -267,7 +303,7 @@ mod tests {
                 fn try_from(cursor: UserCursor) -> Result<Self, Self::Error> {
                     match cursor {
                         UserCursor::Byname(cursor) => Ok(cursor),
-                        _ => Err(es_entity::CursorDestructureError::from((stringify!(UserCursor), stringify!(UserByNameCursor)))),
+                        _ => Err(es_entity::CursorDestructureError::from((stringify!(UserCursor), stringify!(UserByNameCursor), "name"))),
                     }
                 }
             }
@@ -313,7 +349,7 @@ mod tests {
             cursor_mod: &cursor_mod,
         };
 
-        let cursors = vec![id_cursor, status_cursor, created_at_cursor];
+        let cursors = vec![created_at_cursor, id_cursor, status_cursor];
 
         let combo_cursor = ComboCursor {
             entity: &entity,
@@ -325,10 +361,36 @@ mod tests {
         let expected = quote! {
             #[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
             pub enum OrderSortBy {
+                CreatedAt,
                 #[default]
                 Id,
-                Status,
-                CreatedAt
+                Status
+            }
+
+            impl OrderSortBy {
+                /// The snake_case column names accepted by `FromStr` / `TryFrom<&str>`.
+                pub const VALUES: &'static [&'static str] = &["created_at", "id", "status"];
+            }
+
+            impl std::str::FromStr for OrderSortBy {
+                type Err = es_entity::SortByParseError;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    match s {
+                        "created_at" => Ok(Self::CreatedAt),
+                        "id" => Ok(Self::Id),
+                        "status" => Ok(Self::Status),
+                        _ => Err(es_entity::SortByParseError::new(s, Self::VALUES)),
+                    }
+                }
+            }
+
+            impl TryFrom<&str> for OrderSortBy {
+                type Error = es_entity::SortByParseError;
+
+                fn try_from(s: &str) -> Result<Self, Self::Error> {
+                    s.parse()
+                }
             }
         };
 
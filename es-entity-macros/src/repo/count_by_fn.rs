@@ -0,0 +1,132 @@
+use darling::ToTokens;
+use proc_macro2::{Span, TokenStream};
+use quote::{TokenStreamExt, quote};
+
+use super::options::*;
+
+pub struct CountByFn<'a> {
+    column: &'a Column,
+    table_name: &'a str,
+    query_error: syn::Ident,
+    delete: DeleteOption,
+    has_read_pool: bool,
+}
+
+impl<'a> CountByFn<'a> {
+    pub fn new(column: &'a Column, opts: &'a RepositoryOptions) -> Self {
+        Self {
+            column,
+            table_name: opts.table_name(),
+            query_error: opts.query_error(),
+            delete: opts.delete,
+            has_read_pool: opts.read_pool_accessor().is_some(),
+        }
+    }
+}
+
+impl ToTokens for CountByFn<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let column_name = self.column.name();
+        let column_type = self.column.ty();
+        let query_error = &self.query_error;
+        let read_pool = RepositoryOptions::read_pool(self.has_read_pool);
+
+        let fn_name = syn::Ident::new(&format!("count_by_{}", column_name), Span::call_site());
+        let fn_in_op = syn::Ident::new(
+            &format!("count_by_{}_in_op", column_name),
+            Span::call_site(),
+        );
+
+        let query = format!(
+            "SELECT {}, COUNT(*) FROM {}{} GROUP BY {}",
+            column_name,
+            self.table_name,
+            if self.delete.is_soft() {
+                " WHERE deleted = FALSE"
+            } else {
+                ""
+            },
+            column_name,
+        );
+
+        tokens.append_all(quote! {
+            pub async fn #fn_name(
+                &self
+            ) -> Result<std::collections::HashMap<#column_type, i64>, #query_error> {
+                self.#fn_in_op(#read_pool).await
+            }
+
+            pub async fn #fn_in_op<'a, OP>(
+                &self,
+                op: OP
+            ) -> Result<std::collections::HashMap<#column_type, i64>, #query_error>
+                where
+                    OP: es_entity::IntoOneTimeExecutor<'a>
+            {
+                use es_entity::prelude::sqlx::Row;
+
+                let rows = op
+                    .into_executor()
+                    .fetch_all(sqlx::query(#query).try_map(|row: es_entity::db::Row| {
+                        Ok((row.try_get::<#column_type, _>(0)?, row.try_get::<i64, _>(1)?))
+                    }))
+                    .await?;
+                Ok(rows.into_iter().collect())
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn count_by_fn() {
+        let column = Column::new(
+            syn::Ident::new("status", Span::call_site()),
+            syn::parse_str("Status").unwrap(),
+        );
+
+        let count_by_fn = CountByFn {
+            column: &column,
+            table_name: "entities",
+            query_error: syn::Ident::new("EntityQueryError", Span::call_site()),
+            delete: DeleteOption::Soft,
+            has_read_pool: false,
+        };
+
+        let mut tokens = TokenStream::new();
+        count_by_fn.to_tokens(&mut tokens);
+
+        let expected = quote! {
+            pub async fn count_by_status(
+                &self
+            ) -> Result<std::collections::HashMap<Status, i64>, EntityQueryError> {
+                self.count_by_status_in_op(self.pool()).await
+            }
+
+            pub async fn count_by_status_in_op<'a, OP>(
+                &self,
+                op: OP
+            ) -> Result<std::collections::HashMap<Status, i64>, EntityQueryError>
+                where
+                    OP: es_entity::IntoOneTimeExecutor<'a>
+            {
+                use es_entity::prelude::sqlx::Row;
+
+                let rows = op
+                    .into_executor()
+                    .fetch_all(sqlx::query(
+                        "SELECT status, COUNT(*) FROM entities WHERE deleted = FALSE GROUP BY status"
+                    ).try_map(|row: es_entity::db::Row| {
+                        Ok((row.try_get::<Status, _>(0)?, row.try_get::<i64, _>(1)?))
+                    }))
+                    .await?;
+                Ok(rows.into_iter().collect())
+            }
+        };
+
+        assert_eq!(tokens.to_string(), expected.to_string());
+    }
+}
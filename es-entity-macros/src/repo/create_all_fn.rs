@@ -12,6 +12,8 @@ pub struct CreateAllFn<'a> {
     nested_fn_names: Vec<syn::Ident>,
     post_hydrate_error: Option<&'a syn::Type>,
     post_persist_error: Option<&'a syn::Type>,
+    batch_size: usize,
+    clock: ClockFieldInfo<'a>,
     #[cfg(feature = "instrument")]
     repo_name_snake: String,
 }
@@ -29,6 +31,8 @@ impl<'a> From<&'a RepositoryOptions> for CreateAllFn<'a> {
             columns: &opts.columns,
             post_hydrate_error: opts.post_hydrate_hook.as_ref().map(|h| &h.error),
             post_persist_error: opts.post_persist_hook.as_ref().map(|h| &h.error),
+            batch_size: opts.batch_size(),
+            clock: opts.clock_field(),
             #[cfg(feature = "instrument")]
             repo_name_snake: opts.repo_name_snake_case(),
         }
@@ -37,8 +41,10 @@ impl<'a> From<&'a RepositoryOptions> for CreateAllFn<'a> {
 
 impl ToTokens for CreateAllFn<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
+        let op_now = RepositoryOptions::op_now_arg(self.clock);
         let entity = self.entity;
         let create_error = &self.create_error;
+        let batch_size = self.batch_size;
 
         let nested = self.nested_fn_names.iter().map(|f| {
             quote! {
@@ -59,13 +65,20 @@ impl ToTokens for CreateAllFn<'_> {
             .columns
             .create_all_arg_collection(syn::parse_quote! { new_entity });
 
+        let (updated_at_column, updated_at_value) = self
+            .columns
+            .updated_at_expr("$1")
+            .map(|expr| ("updated_at, ".to_string(), format!("{expr}, ")))
+            .unwrap_or_default();
         let query = format!(
-            "INSERT INTO {} (created_at, {}) \
-            SELECT COALESCE($1, NOW()), unnested.{} \
+            "INSERT INTO {} (created_at, {}{}) \
+            SELECT COALESCE($1, NOW()), {}unnested.{} \
             FROM UNNEST({}) \
             AS unnested({})",
             table_name,
+            updated_at_column,
             column_names.join(", "),
+            updated_at_value,
             column_names.join(", unnested."),
             placeholders,
             column_names.join(", "),
@@ -92,6 +105,18 @@ impl ToTokens for CreateAllFn<'_> {
         #[cfg(not(feature = "instrument"))]
         let (instrument_attr, error_recording) = (quote! {}, quote! {});
 
+        #[cfg(feature = "otel-metrics")]
+        let metrics_recording = {
+            let entity_name = entity.to_string();
+            quote! {
+                if let Ok(ref created) = __result {
+                    es_entity::metrics::record_created(#entity_name, created.len());
+                }
+            }
+        };
+        #[cfg(not(feature = "otel-metrics"))]
+        let metrics_recording = quote! {};
+
         let post_hydrate_check = if self.post_hydrate_error.is_some() {
             quote! {
                 self.execute_post_hydrate_hook(&entity).map_err(#create_error::PostHydrateError)?;
@@ -134,47 +159,53 @@ impl ToTokens for CreateAllFn<'_> {
                         return Ok(res);
                     }
 
-                    #arg_collection
-
-                    let now = op.maybe_now();
-                    sqlx::query(#query)
-                       .bind(now)
-                       #(#bindings)*
-                       .fetch_all(op.as_executor())
-                       .await
-                       .map_err(|e| match &e {
-                           sqlx::Error::Database(db_err) if db_err.is_unique_violation() => {
-                               #create_error::ConstraintViolation {
-                                   column: Self::map_constraint_column(db_err.constraint()),
-                                   value: es_entity::extract_constraint_value(db_err.as_ref()),
-                                   inner: e,
+                    let mut remaining = new_entities.into_iter().peekable();
+                    while remaining.peek().is_some() {
+                        let new_entities: Vec<_> = remaining.by_ref().take(#batch_size).collect();
+
+                        #arg_collection
+
+                        let now = #op_now;
+                        sqlx::query(#query)
+                           .bind(now)
+                           #(#bindings)*
+                           .fetch_all(op.as_executor())
+                           .await
+                           .map_err(|e| match &e {
+                               sqlx::Error::Database(db_err) if db_err.is_unique_violation() => {
+                                   #create_error::ConstraintViolation {
+                                       column: Self::map_constraint_column(db_err.constraint()),
+                                       value: es_entity::extract_constraint_value(db_err.as_ref()),
+                                       inner: e,
+                                   }
                                }
-                           }
-                           _ => #create_error::Sqlx(e),
-                       })?;
+                               _ => #create_error::Sqlx(e),
+                           })?;
 
 
-                    let mut all_events: Vec<es_entity::EntityEvents<<#entity as es_entity::EsEntity>::Event>> = new_entities.into_iter().map(Self::convert_new).collect();
-                    let mut n_persisted = Self::extract_concurrent_modification(
-                        self.persist_events_batch(op, &mut all_events).await,
-                        #create_error::ConcurrentModification,
-                    )?;
+                        let mut all_events: Vec<es_entity::EntityEvents<<#entity as es_entity::EsEntity>::Event>> = new_entities.into_iter().map(Self::convert_new).collect();
+                        let mut n_persisted = Self::extract_concurrent_modification(
+                            self.persist_events_batch(op, &mut all_events).await,
+                            #create_error::ConcurrentModification,
+                        )?;
 
-                    for events in all_events.into_iter() {
-                        let n_events = n_persisted.remove(events.id()).expect("n_events exists");
-                        let #maybe_mut_entity = Self::hydrate_entity(events)?;
+                        for events in all_events.into_iter() {
+                            let n_events = n_persisted.remove(events.id()).expect("n_events exists");
+                            let #maybe_mut_entity = Self::hydrate_entity(events)?;
 
-                        #(#nested)*
+                            #(#nested)*
 
-                        #post_hydrate_check
-                        #post_persist_check
-                        res.push(entity);
+                            #post_hydrate_check
+                            #post_persist_check
+                            res.push(entity);
+                        }
                     }
 
                     Ok(res)
                 }.await;
 
                 #error_recording
+                #metrics_recording
                 __result
             }
         });
@@ -204,6 +235,8 @@ mod tests {
             nested_fn_names: Vec::new(),
             post_hydrate_error: None,
             post_persist_error: None,
+            batch_size: 1000,
+            clock: ClockFieldInfo::None,
             #[cfg(feature = "instrument")]
             repo_name_snake: "test_repo".to_string(),
         };
@@ -214,6 +247,15 @@ mod tests {
         let mut tokens = TokenStream::new();
         create_fn.to_tokens(&mut tokens);
 
+        #[cfg(feature = "otel-metrics")]
+        let metrics_recording = quote! {
+            if let Ok(ref created) = __result {
+                es_entity::metrics::record_created("Entity", created.len());
+            }
+        };
+        #[cfg(not(feature = "otel-metrics"))]
+        let metrics_recording = quote! {};
+
         let expected = quote! {
             pub async fn create_all(
                 &self,
@@ -239,53 +281,59 @@ mod tests {
                         return Ok(res);
                     }
 
-                    let mut id_collection = Vec::new();
-                    let mut name_collection = Vec::new();
-
-                    for new_entity in new_entities.iter() {
-                        let id: &EntityId = &new_entity.id;
-                        let name: &String = &new_entity.name;
-
-                        id_collection.push(id);
-                        name_collection.push(name);
-                    }
-
-                    let now = op.maybe_now();
-                    sqlx::query(
-                        "INSERT INTO entities (created_at, id, name) SELECT COALESCE($1, NOW()), unnested.id, unnested.name FROM UNNEST($2, $3) AS unnested(id, name)")
-                        .bind(now)
-                        .bind(id_collection)
-                        .bind(name_collection)
-                        .fetch_all(op.as_executor())
-                        .await
-                        .map_err(|e| match &e {
-                            sqlx::Error::Database(db_err) if db_err.is_unique_violation() => {
-                                EntityCreateError::ConstraintViolation {
-                                    column: Self::map_constraint_column(db_err.constraint()),
-                                    value: es_entity::extract_constraint_value(db_err.as_ref()),
-                                    inner: e,
+                    let mut remaining = new_entities.into_iter().peekable();
+                    while remaining.peek().is_some() {
+                        let new_entities: Vec<_> = remaining.by_ref().take(1000usize).collect();
+
+                        let mut id_collection = Vec::new();
+                        let mut name_collection = Vec::new();
+
+                        for new_entity in new_entities.iter() {
+                            let id: &EntityId = &new_entity.id;
+                            let name: &String = &new_entity.name;
+
+                            id_collection.push(id);
+                            name_collection.push(name);
+                        }
+
+                        let now = op.maybe_now();
+                        sqlx::query(
+                            "INSERT INTO entities (created_at, id, name) SELECT COALESCE($1, NOW()), unnested.id, unnested.name FROM UNNEST($2, $3) AS unnested(id, name)")
+                            .bind(now)
+                            .bind(id_collection)
+                            .bind(name_collection)
+                            .fetch_all(op.as_executor())
+                            .await
+                            .map_err(|e| match &e {
+                                sqlx::Error::Database(db_err) if db_err.is_unique_violation() => {
+                                    EntityCreateError::ConstraintViolation {
+                                        column: Self::map_constraint_column(db_err.constraint()),
+                                        value: es_entity::extract_constraint_value(db_err.as_ref()),
+                                        inner: e,
+                                    }
                                 }
-                            }
-                            _ => EntityCreateError::Sqlx(e),
-                        })?;
+                                _ => EntityCreateError::Sqlx(e),
+                            })?;
 
 
-                    let mut all_events: Vec<es_entity::EntityEvents<<#entity as es_entity::EsEntity>::Event>> = new_entities.into_iter().map(Self::convert_new).collect();
-                    let mut n_persisted = Self::extract_concurrent_modification(
-                        self.persist_events_batch(op, &mut all_events).await,
-                        EntityCreateError::ConcurrentModification,
-                    )?;
+                        let mut all_events: Vec<es_entity::EntityEvents<<#entity as es_entity::EsEntity>::Event>> = new_entities.into_iter().map(Self::convert_new).collect();
+                        let mut n_persisted = Self::extract_concurrent_modification(
+                            self.persist_events_batch(op, &mut all_events).await,
+                            EntityCreateError::ConcurrentModification,
+                        )?;
 
-                    for events in all_events.into_iter() {
-                        let n_events = n_persisted.remove(events.id()).expect("n_events exists");
-                        let entity = Self::hydrate_entity(events)?;
+                        for events in all_events.into_iter() {
+                            let n_events = n_persisted.remove(events.id()).expect("n_events exists");
+                            let entity = Self::hydrate_entity(events)?;
 
-                        res.push(entity);
+                            res.push(entity);
+                        }
                     }
 
                     Ok(res)
                 }.await;
 
+                #metrics_recording
                 __result
             }
         };
@@ -9,9 +9,12 @@ pub struct CreateFn<'a> {
     table_name: &'a str,
     columns: &'a Columns,
     create_error: syn::Ident,
+    find_error: syn::Ident,
+    ignore_conflicts: bool,
     nested_fn_names: Vec<syn::Ident>,
     post_hydrate_error: Option<&'a syn::Type>,
     post_persist_error: Option<&'a syn::Type>,
+    clock: ClockFieldInfo<'a>,
     #[cfg(feature = "instrument")]
     repo_name_snake: String,
 }
@@ -22,6 +25,8 @@ impl<'a> From<&'a RepositoryOptions> for CreateFn<'a> {
             table_name: opts.table_name(),
             entity: opts.entity(),
             create_error: opts.create_error(),
+            find_error: opts.find_error(),
+            ignore_conflicts: opts.create.ignores_conflicts(),
             nested_fn_names: opts
                 .all_nested()
                 .map(|f| f.create_nested_fn_name())
@@ -29,6 +34,7 @@ impl<'a> From<&'a RepositoryOptions> for CreateFn<'a> {
             columns: &opts.columns,
             post_hydrate_error: opts.post_hydrate_hook.as_ref().map(|h| &h.error),
             post_persist_error: opts.post_persist_hook.as_ref().map(|h| &h.error),
+            clock: opts.clock_field(),
             #[cfg(feature = "instrument")]
             repo_name_snake: opts.repo_name_snake_case(),
         }
@@ -37,8 +43,10 @@ impl<'a> From<&'a RepositoryOptions> for CreateFn<'a> {
 
 impl ToTokens for CreateFn<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
+        let op_now = RepositoryOptions::op_now_arg(self.clock);
         let entity = self.entity;
         let create_error = &self.create_error;
+        let find_error = &self.find_error;
 
         let nested = self.nested_fn_names.iter().map(|f| {
             quote! {
@@ -60,14 +68,59 @@ impl ToTokens for CreateFn<'_> {
         let placeholders = self.columns.insert_placeholders(0);
         let args = self.columns.create_query_args();
 
+        let now_param = format!("${}", column_names.len() + 1);
+        let (updated_at_column, updated_at_value) = self
+            .columns
+            .updated_at_expr(&now_param)
+            .map(|expr| (", updated_at".to_string(), format!(", {expr}")))
+            .unwrap_or_default();
         let query = format!(
-            "INSERT INTO {} ({}, created_at) VALUES ({}, COALESCE(${}, NOW()))",
+            "INSERT INTO {} ({}, created_at{}) VALUES ({}, COALESCE({}, NOW()){}){}",
             table_name,
             column_names.join(", "),
+            updated_at_column,
             placeholders,
-            column_names.len() + 1,
+            now_param,
+            updated_at_value,
+            if self.ignore_conflicts {
+                " ON CONFLICT (id) DO NOTHING"
+            } else {
+                ""
+            },
         );
 
+        // Nothing was inserted so the events must not be persisted either.
+        // It is only a redelivery if the stored entity starts with the same events.
+        let (create_ty, insert_result, already_exists_check, created) = if self.ignore_conflicts {
+            let post_hydrate_error_arm = if self.post_hydrate_error.is_some() {
+                quote! { #find_error::PostHydrateError(e) => #create_error::PostHydrateError(e), }
+            } else {
+                quote! {}
+            };
+            (
+                quote! { es_entity::CreateOutcome<#entity> },
+                quote! { let __inserted = },
+                quote! {
+                    if __inserted.rows_affected() == 0 {
+                        let existing = self.find_by_id_in_op(&mut *op, id).await.map_err(|e| match e {
+                            #find_error::Sqlx(e) => #create_error::Sqlx(e),
+                            #find_error::NotFound { .. } => #create_error::AlreadyExists,
+                            #find_error::HydrationError(e) => #create_error::HydrationError(e),
+                            #post_hydrate_error_arm
+                        })?;
+                        let events = Self::convert_new(new_entity);
+                        if !es_entity::EsEntity::events(&existing).begins_with(&events) {
+                            return Err(#create_error::AlreadyExists);
+                        }
+                        return Ok(es_entity::CreateOutcome::Ignored(existing));
+                    }
+                },
+                quote! { es_entity::CreateOutcome::Created(entity) },
+            )
+        } else {
+            (quote! { #entity }, quote! {}, quote! {}, quote! { entity })
+        };
+
         #[cfg(feature = "instrument")]
         let (instrument_attr, record_id, error_recording) = {
             let entity_name = entity.to_string();
@@ -92,6 +145,23 @@ impl ToTokens for CreateFn<'_> {
         #[cfg(not(feature = "instrument"))]
         let (instrument_attr, record_id, error_recording) = (quote! {}, quote! {}, quote! {});
 
+        #[cfg(feature = "otel-metrics")]
+        let metrics_recording = {
+            let entity_name = entity.to_string();
+            let created = if self.ignore_conflicts {
+                quote! { matches!(__result, Ok(es_entity::CreateOutcome::Created(_))) }
+            } else {
+                quote! { __result.is_ok() }
+            };
+            quote! {
+                if #created {
+                    es_entity::metrics::record_created(#entity_name, 1);
+                }
+            }
+        };
+        #[cfg(not(feature = "otel-metrics"))]
+        let metrics_recording = quote! {};
+
         let post_hydrate_check = if self.post_hydrate_error.is_some() {
             quote! {
                 self.execute_post_hydrate_hook(&entity).map_err(#create_error::PostHydrateError)?;
@@ -130,7 +200,7 @@ impl ToTokens for CreateFn<'_> {
             pub async fn create(
                 &self,
                 new_entity: <#entity as es_entity::EsEntity>::New
-            ) -> Result<#entity, #create_error> {
+            ) -> Result<#create_ty, #create_error> {
                 let mut op = self.begin_op().await?;
                 let res = self.create_in_op(&mut op, new_entity).await?;
                 op.commit().await?;
@@ -142,18 +212,18 @@ impl ToTokens for CreateFn<'_> {
                 &self,
                 op: &mut OP,
                 new_entity: <#entity as es_entity::EsEntity>::New
-            ) -> Result<#entity, #create_error>
+            ) -> Result<#create_ty, #create_error>
             where
                 OP: es_entity::AtomicOperation
             {
-                let __result: Result<#entity, #create_error> = async {
+                let __result: Result<#create_ty, #create_error> = async {
                     #assignments
                     #record_id
 
-                     sqlx::query!(
+                    #insert_result sqlx::query!(
                          #query,
                          #(#args)*
-                         op.maybe_now()
+                         #op_now
                     )
                     .execute(op.as_executor())
                     .await
@@ -167,6 +237,7 @@ impl ToTokens for CreateFn<'_> {
                         }
                         _ => #create_error::Sqlx(e),
                     })?;
+                    #already_exists_check
 
                     let mut events = Self::convert_new(new_entity);
                     let n_events = Self::extract_concurrent_modification(
@@ -179,12 +250,25 @@ impl ToTokens for CreateFn<'_> {
 
                     #post_hydrate_check
                     #post_persist_check
-                    Ok(entity)
+                    Ok(#created)
                 }.await;
 
                 #error_recording
+                #metrics_recording
                 __result
             }
+
+            /// Dry run of `create`: converts and hydrates `new_entity` without writing anything.
+            ///
+            /// Surfaces hydration errors before a (bulk) import. Database constraints are not checked.
+            pub fn validate_new(
+                &self,
+                new_entity: <#entity as es_entity::EsEntity>::New
+            ) -> Result<#entity, #create_error> {
+                let entity = Self::hydrate_entity(Self::convert_new(new_entity))?;
+                #post_hydrate_check
+                Ok(entity)
+            }
         });
     }
 }
@@ -207,10 +291,13 @@ mod tests {
             table_name: "entities",
             entity: &entity,
             create_error,
+            find_error: syn::Ident::new("EntityFindError", Span::call_site()),
+            ignore_conflicts: false,
             columns: &columns,
             nested_fn_names: Vec::new(),
             post_hydrate_error: None,
             post_persist_error: None,
+            clock: ClockFieldInfo::None,
             #[cfg(feature = "instrument")]
             repo_name_snake: "test_repo".to_string(),
         };
@@ -218,6 +305,15 @@ mod tests {
         let mut tokens = TokenStream::new();
         create_fn.to_tokens(&mut tokens);
 
+        #[cfg(feature = "otel-metrics")]
+        let metrics_recording = quote! {
+            if __result.is_ok() {
+                es_entity::metrics::record_created("Entity", 1);
+            }
+        };
+        #[cfg(not(feature = "otel-metrics"))]
+        let metrics_recording = quote! {};
+
         let expected = quote! {
             #[inline(always)]
             fn convert_new<Entity, Event>(item: Entity) -> es_entity::EntityEvents<Event>
@@ -285,8 +381,20 @@ mod tests {
                     Ok(entity)
                 }.await;
 
+                #metrics_recording
                 __result
             }
+
+            /// Dry run of `create`: converts and hydrates `new_entity` without writing anything.
+            ///
+            /// Surfaces hydration errors before a (bulk) import. Database constraints are not checked.
+            pub fn validate_new(
+                &self,
+                new_entity: <Entity as es_entity::EsEntity>::New
+            ) -> Result<Entity, EntityCreateError> {
+                let entity = Self::hydrate_entity(Self::convert_new(new_entity))?;
+                Ok(entity)
+            }
         };
 
         assert_eq!(tokens.to_string(), expected.to_string());
@@ -308,10 +416,13 @@ mod tests {
             table_name: "entities",
             entity: &entity,
             create_error,
+            find_error: syn::Ident::new("EntityFindError", Span::call_site()),
+            ignore_conflicts: false,
             columns: &columns,
             nested_fn_names: Vec::new(),
             post_hydrate_error: None,
             post_persist_error: None,
+            clock: ClockFieldInfo::None,
             #[cfg(feature = "instrument")]
             repo_name_snake: "test_repo".to_string(),
         };
@@ -319,6 +430,15 @@ mod tests {
         let mut tokens = TokenStream::new();
         create_fn.to_tokens(&mut tokens);
 
+        #[cfg(feature = "otel-metrics")]
+        let metrics_recording = quote! {
+            if __result.is_ok() {
+                es_entity::metrics::record_created("Entity", 1);
+            }
+        };
+        #[cfg(not(feature = "otel-metrics"))]
+        let metrics_recording = quote! {};
+
         let expected = quote! {
             #[inline(always)]
             fn convert_new<Entity, Event>(item: Entity) -> es_entity::EntityEvents<Event>
@@ -388,10 +508,71 @@ mod tests {
                     Ok(entity)
                 }.await;
 
+                #metrics_recording
                 __result
             }
+
+            /// Dry run of `create`: converts and hydrates `new_entity` without writing anything.
+            ///
+            /// Surfaces hydration errors before a (bulk) import. Database constraints are not checked.
+            pub fn validate_new(
+                &self,
+                new_entity: <Entity as es_entity::EsEntity>::New
+            ) -> Result<Entity, EntityCreateError> {
+                let entity = Self::hydrate_entity(Self::convert_new(new_entity))?;
+                Ok(entity)
+            }
         };
 
         assert_eq!(tokens.to_string(), expected.to_string());
     }
+
+    #[test]
+    fn create_fn_ignoring_conflicts() {
+        let entity = Ident::new("Entity", Span::call_site());
+        let create_error = syn::Ident::new("EntityCreateError", Span::call_site());
+        let id = Ident::new("EntityId", Span::call_site());
+        let mut columns = Columns::default();
+        columns.set_id_column(&id);
+
+        let create_fn = CreateFn {
+            table_name: "entities",
+            entity: &entity,
+            create_error,
+            find_error: syn::Ident::new("EntityFindError", Span::call_site()),
+            ignore_conflicts: true,
+            columns: &columns,
+            nested_fn_names: Vec::new(),
+            post_hydrate_error: None,
+            post_persist_error: None,
+            clock: ClockFieldInfo::None,
+            #[cfg(feature = "instrument")]
+            repo_name_snake: "test_repo".to_string(),
+        };
+
+        let mut tokens = TokenStream::new();
+        create_fn.to_tokens(&mut tokens);
+        let output = tokens.to_string();
+
+        assert!(output.contains(
+            "INSERT INTO entities (id, created_at) VALUES ($1, COALESCE($2, NOW())) ON CONFLICT (id) DO NOTHING"
+        ));
+        // The conflict is detected before any event gets persisted
+        let check = output
+            .find("return Ok (es_entity :: CreateOutcome :: Ignored (existing))")
+            .expect("Ignored check");
+        assert!(check < output.find("persist_events").expect("persist_events"));
+        // and only counts as applied if the stored events match
+        let compare = output
+            .find("begins_with (& events)")
+            .expect("events comparison");
+        assert!(compare < check);
+        assert!(output.contains("return Err (EntityCreateError :: AlreadyExists)"));
+        assert!(
+            output.contains(
+                "EntityFindError :: NotFound { .. } => EntityCreateError :: AlreadyExists"
+            )
+        );
+        assert!(output.contains("Ok (es_entity :: CreateOutcome :: Created (entity))"));
+    }
 }
@@ -0,0 +1,357 @@
+use darling::ToTokens;
+use proc_macro2::TokenStream;
+use quote::{TokenStreamExt, quote};
+
+use super::options::*;
+
+pub struct DeleteAllFn<'a> {
+    id: &'a syn::Ident,
+    entity: &'a syn::Ident,
+    table_name: &'a str,
+    columns: &'a Columns,
+    modify_error: syn::Ident,
+    delete_option: &'a DeleteOption,
+    nested_delete_fn_names: Vec<syn::Ident>,
+    post_persist_error: Option<&'a syn::Type>,
+    forgettable_table_name: Option<&'a str>,
+    batch_size: usize,
+    clock: ClockFieldInfo<'a>,
+    #[cfg(feature = "instrument")]
+    repo_name_snake: String,
+}
+
+impl<'a> From<&'a RepositoryOptions> for DeleteAllFn<'a> {
+    fn from(opts: &'a RepositoryOptions) -> Self {
+        Self {
+            id: opts.id(),
+            entity: opts.entity(),
+            modify_error: opts.modify_error(),
+            columns: &opts.columns,
+            table_name: opts.table_name(),
+            delete_option: &opts.delete,
+            nested_delete_fn_names: opts
+                .all_nested()
+                .map(|f| f.delete_nested_fn_name())
+                .collect(),
+            post_persist_error: opts.post_persist_hook.as_ref().map(|h| &h.error),
+            forgettable_table_name: opts.forgettable_table_name(),
+            batch_size: opts.batch_size(),
+            clock: opts.clock_field(),
+            #[cfg(feature = "instrument")]
+            repo_name_snake: opts.repo_name_snake_case(),
+        }
+    }
+}
+
+impl ToTokens for DeleteAllFn<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let op_now = RepositoryOptions::op_now_arg(self.clock);
+        if !self.delete_option.is_soft() {
+            return;
+        }
+
+        let entity = self.entity;
+        let modify_error = &self.modify_error;
+        let batch_size = self.batch_size;
+
+        let nested_phase = if self.nested_delete_fn_names.is_empty() {
+            None
+        } else {
+            let nested = self.nested_delete_fn_names.iter().map(|f| {
+                quote! {
+                    Self::#f::<_, _, #modify_error>(op, entity).await?;
+                }
+            });
+            Some(quote! {
+                for entity in entities.iter() {
+                    #(#nested)*
+                }
+            })
+        };
+
+        // Same semantics as `delete_in_op`: forgettable index columns are set
+        // to NULL and the remaining columns are re-persisted from the entity.
+        let (vec_declarations, per_entity_pushes, mut bind_tokens) = self
+            .columns
+            .delete_all_arg_parts(syn::parse_quote! { entity });
+        let column_names = self.columns.delete_all_column_names();
+        let mut set_clause = vec![self.columns.sql_bulk_updates_for_delete()];
+        if let Some(expr) = self
+            .columns
+            .updated_at_expr(&format!("${}", column_names.len() + 1))
+        {
+            set_clause.push(format!("updated_at = {expr}"));
+            bind_tokens.push(quote! { .bind(#op_now) });
+        }
+        set_clause.retain(|s| !s.is_empty());
+        let set_clause = set_clause.join(", ");
+        let placeholders = (1..=column_names.len())
+            .map(|i| format!("${i}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let column_list = column_names.join(", ");
+        let table_name = self.table_name;
+        let query = format!(
+            "UPDATE {table_name} SET {set_clause}{}deleted = TRUE \
+                 FROM UNNEST({placeholders}) \
+                 AS unnested({column_list}) \
+                 WHERE {table_name}.id = unnested.id",
+            if set_clause.is_empty() { "" } else { ", " },
+        );
+
+        let id_type = self.id;
+        let forget_payloads = if let Some(forgettable_tbl) = self.forgettable_table_name {
+            let forget_query = format!("DELETE FROM {} WHERE entity_id = ANY($1)", forgettable_tbl);
+            quote! {
+                let ids: Vec<&#id_type> = entities.iter().map(|entity| &entity.id).collect();
+                sqlx::query(#forget_query)
+                    .bind(ids)
+                    .execute(op.as_executor())
+                    .await?;
+            }
+        } else {
+            quote! {}
+        };
+
+        #[cfg(feature = "instrument")]
+        let (instrument_attr, error_recording) = {
+            let entity_name = entity.to_string();
+            let repo_name = &self.repo_name_snake;
+            let span_name = format!("{}.delete_all", repo_name);
+            (
+                quote! {
+                    #[tracing::instrument(name = #span_name, skip_all, fields(entity = #entity_name, count = entities.len(), error = tracing::field::Empty, exception.message = tracing::field::Empty, exception.type = tracing::field::Empty))]
+                },
+                quote! {
+                    if let Err(ref e) = __result {
+                        tracing::Span::current().record("error", true);
+                        tracing::Span::current().record("exception.message", tracing::field::display(e));
+                        tracing::Span::current().record("exception.type", std::any::type_name_of_val(e));
+                    }
+                },
+            )
+        };
+        #[cfg(not(feature = "instrument"))]
+        let (instrument_attr, error_recording) = (quote! {}, quote! {});
+
+        let persist_events = if self.post_persist_error.is_some() {
+            quote! {
+                let n_persisted = Self::extract_concurrent_modification(
+                    self.persist_events_batch(op, &mut all_event_refs).await,
+                    #modify_error::ConcurrentModification,
+                )?;
+                drop(all_event_refs);
+
+                for entity in entities.iter_mut() {
+                    if let Some(&n_events) = n_persisted.get(&entity.id) {
+                        if n_events > 0 {
+                            self.execute_post_persist_hook(op, &entity, entity.events().last_persisted(n_events)).await.map_err(#modify_error::PostPersistHookError)?;
+                        }
+                    }
+                }
+            }
+        } else {
+            quote! {
+                Self::extract_concurrent_modification(
+                    self.persist_events_batch(op, &mut all_event_refs).await,
+                    #modify_error::ConcurrentModification,
+                )?;
+            }
+        };
+
+        tokens.append_all(quote! {
+            pub async fn delete_all(
+                &self,
+                entities: &mut [#entity]
+            ) -> Result<(), #modify_error> {
+                let mut op = self.begin_op().await?;
+                self.delete_all_in_op(&mut op, entities).await?;
+                op.commit().await?;
+                Ok(())
+            }
+
+            #instrument_attr
+            pub async fn delete_all_in_op<OP>(
+                &self,
+                op: &mut OP,
+                entities: &mut [#entity]
+            ) -> Result<(), #modify_error>
+            where
+                OP: es_entity::AtomicOperation
+            {
+                let __result: Result<(), #modify_error> = async {
+                    for entities in entities.chunks_mut(#batch_size) {
+                        #nested_phase
+
+                        #vec_declarations
+                        for entity in entities.iter() {
+                            #per_entity_pushes
+                        }
+
+                        sqlx::query(#query)
+                            #(#bind_tokens)*
+                            .execute(op.as_executor())
+                            .await
+                            .map_err(|e| match &e {
+                                sqlx::Error::Database(db_err) if db_err.is_unique_violation() => {
+                                    #modify_error::ConstraintViolation {
+                                        column: Self::map_constraint_column(db_err.constraint()),
+                                        value: es_entity::extract_constraint_value(db_err.as_ref()),
+                                        inner: e,
+                                    }
+                                }
+                                _ => #modify_error::Sqlx(e),
+                            })?;
+
+                        #forget_payloads
+
+                        let mut all_event_refs: Vec<_> = entities.iter_mut()
+                            .filter_map(|entity| {
+                                let events = Self::extract_events(entity);
+                                if events.any_new() { Some(events) } else { None }
+                            })
+                            .collect();
+                        if all_event_refs.is_empty() {
+                            continue;
+                        }
+                        #persist_events
+                    }
+
+                    Ok(())
+                }.await;
+
+                #error_recording
+                __result
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proc_macro2::Span;
+    use syn::Ident;
+
+    #[test]
+    fn delete_all_fn() {
+        let id = Ident::new("EntityId", Span::call_site());
+        let entity = Ident::new("Entity", Span::call_site());
+        let mut columns = Columns::default();
+        columns.set_id_column(&id);
+
+        let delete_all_fn = DeleteAllFn {
+            id: &id,
+            entity: &entity,
+            table_name: "entities",
+            columns: &columns,
+            modify_error: syn::Ident::new("EntityModifyError", Span::call_site()),
+            delete_option: &DeleteOption::Soft,
+            nested_delete_fn_names: Vec::new(),
+            post_persist_error: None,
+            forgettable_table_name: None,
+            batch_size: 1000,
+            clock: ClockFieldInfo::None,
+            #[cfg(feature = "instrument")]
+            repo_name_snake: "test_repo".to_string(),
+        };
+
+        let mut tokens = TokenStream::new();
+        delete_all_fn.to_tokens(&mut tokens);
+
+        let expected = quote! {
+            pub async fn delete_all(
+                &self,
+                entities: &mut [Entity]
+            ) -> Result<(), EntityModifyError> {
+                let mut op = self.begin_op().await?;
+                self.delete_all_in_op(&mut op, entities).await?;
+                op.commit().await?;
+                Ok(())
+            }
+
+            pub async fn delete_all_in_op<OP>(
+                &self,
+                op: &mut OP,
+                entities: &mut [Entity]
+            ) -> Result<(), EntityModifyError>
+            where
+                OP: es_entity::AtomicOperation
+            {
+                let __result: Result<(), EntityModifyError> = async {
+                    for entities in entities.chunks_mut(1000usize) {
+                        let mut id_collection = Vec::new();
+                        for entity in entities.iter() {
+                            let id = &entity.id;
+                            id_collection.push(id);
+                        }
+
+                        sqlx::query("UPDATE entities SET deleted = TRUE FROM UNNEST($1) AS unnested(id) WHERE entities.id = unnested.id")
+                            .bind(id_collection)
+                            .execute(op.as_executor())
+                            .await
+                            .map_err(|e| match &e {
+                                sqlx::Error::Database(db_err) if db_err.is_unique_violation() => {
+                                    EntityModifyError::ConstraintViolation {
+                                        column: Self::map_constraint_column(db_err.constraint()),
+                                        value: es_entity::extract_constraint_value(db_err.as_ref()),
+                                        inner: e,
+                                    }
+                                }
+                                _ => EntityModifyError::Sqlx(e),
+                            })?;
+
+                        let mut all_event_refs: Vec<_> = entities.iter_mut()
+                            .filter_map(|entity| {
+                                let events = Self::extract_events(entity);
+                                if events.any_new() { Some(events) } else { None }
+                            })
+                            .collect();
+                        if all_event_refs.is_empty() {
+                            continue;
+                        }
+                        Self::extract_concurrent_modification(
+                            self.persist_events_batch(op, &mut all_event_refs).await,
+                            EntityModifyError::ConcurrentModification,
+                        )?;
+                    }
+
+                    Ok(())
+                }.await;
+
+                __result
+            }
+        };
+
+        assert_eq!(tokens.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn delete_all_fn_not_generated_without_soft_delete() {
+        let id = Ident::new("EntityId", Span::call_site());
+        let entity = Ident::new("Entity", Span::call_site());
+        let mut columns = Columns::default();
+        columns.set_id_column(&id);
+
+        let delete_all_fn = DeleteAllFn {
+            id: &id,
+            entity: &entity,
+            table_name: "entities",
+            columns: &columns,
+            modify_error: syn::Ident::new("EntityModifyError", Span::call_site()),
+            delete_option: &DeleteOption::No,
+            nested_delete_fn_names: Vec::new(),
+            post_persist_error: None,
+            forgettable_table_name: None,
+            batch_size: 1000,
+            clock: ClockFieldInfo::None,
+            #[cfg(feature = "instrument")]
+            repo_name_snake: "test_repo".to_string(),
+        };
+
+        let mut tokens = TokenStream::new();
+        delete_all_fn.to_tokens(&mut tokens);
+
+        assert!(tokens.is_empty());
+    }
+}
@@ -14,6 +14,7 @@ pub struct DeleteFn<'a> {
     nested_delete_fn_names: Vec<syn::Ident>,
     post_persist_error: Option<&'a syn::Type>,
     forgettable_table_name: Option<&'a str>,
+    clock: ClockFieldInfo<'a>,
     #[cfg(feature = "instrument")]
     repo_name_snake: String,
 }
@@ -33,6 +34,7 @@ impl<'a> DeleteFn<'a> {
                 .collect(),
             post_persist_error: opts.post_persist_hook.as_ref().map(|h| &h.error),
             forgettable_table_name: opts.forgettable_table_name(),
+            clock: opts.clock_field(),
             #[cfg(feature = "instrument")]
             repo_name_snake: opts.repo_name_snake_case(),
         }
@@ -41,6 +43,7 @@ impl<'a> DeleteFn<'a> {
 
 impl ToTokens for DeleteFn<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
+        let op_now = RepositoryOptions::op_now_arg(self.clock);
         if !self.delete_option.is_soft() {
             return;
         }
@@ -59,14 +62,22 @@ impl ToTokens for DeleteFn<'_> {
         let assignments = self
             .columns
             .variable_assignments_for_delete(syn::parse_quote! { entity });
-        let column_updates = self.columns.sql_updates_for_delete();
+        let mut args = self.columns.update_query_args_for_delete();
+        let mut column_updates = vec![self.columns.sql_updates_for_delete()];
+        if let Some(expr) = self
+            .columns
+            .updated_at_expr(&format!("${}", args.len() + 1))
+        {
+            column_updates.push(format!("updated_at = {expr}"));
+            args.push(quote! { #op_now });
+        }
+        column_updates.retain(|u| !u.is_empty());
+        column_updates.push("deleted = TRUE".to_string());
         let query = format!(
-            "UPDATE {} SET {}{}deleted = TRUE WHERE id = $1",
+            "UPDATE {} SET {} WHERE id = $1",
             self.table_name,
-            column_updates,
-            if column_updates.is_empty() { "" } else { ", " }
+            column_updates.join(", "),
         );
-        let args = self.columns.update_query_args_for_delete();
 
         #[cfg(feature = "instrument")]
         let (instrument_attr, record_id, error_recording) = {
@@ -208,6 +219,7 @@ mod tests {
             nested_delete_fn_names: Vec::new(),
             post_persist_error: None,
             forgettable_table_name: None,
+            clock: ClockFieldInfo::None,
             #[cfg(feature = "instrument")]
             repo_name_snake: "test_repo".to_string(),
         };
@@ -302,6 +314,7 @@ mod tests {
             nested_delete_fn_names: Vec::new(),
             post_persist_error: None,
             forgettable_table_name: None,
+            clock: ClockFieldInfo::None,
             #[cfg(feature = "instrument")]
             repo_name_snake: "test_repo".to_string(),
         };
@@ -392,6 +405,7 @@ mod tests {
             nested_delete_fn_names: Vec::new(),
             post_persist_error: None,
             forgettable_table_name: Some("entities_forgettable_payloads"),
+            clock: ClockFieldInfo::None,
             #[cfg(feature = "instrument")]
             repo_name_snake: "test_repo".to_string(),
         };
@@ -6,6 +6,7 @@ use super::options::{PostHydrateHookConfig, PostPersistHookConfig, RepositoryOpt
 
 pub struct ErrorTypes<'a> {
     entity: &'a syn::Ident,
+    id: &'a syn::Ident,
     column_enum: syn::Ident,
     create_error: syn::Ident,
     modify_error: syn::Ident,
@@ -13,6 +14,8 @@ pub struct ErrorTypes<'a> {
     query_error: syn::Ident,
     forget_error: syn::Ident,
     forgettable: bool,
+    hard_delete: bool,
+    ignores_create_conflicts: bool,
     column_variants: Vec<ColumnVariant>,
     nested: Vec<NestedErrorInfo>,
     post_hydrate_hook: &'a Option<PostHydrateHookConfig>,
@@ -23,6 +26,8 @@ struct ColumnVariant {
     variant_name: syn::Ident,
     column_name: String,
     constraint_names: Vec<String>,
+    /// Variants of the columns covered by a composite `unique(...)` constraint.
+    composite_of: Vec<syn::Ident>,
 }
 
 struct NestedErrorInfo {
@@ -60,7 +65,7 @@ impl NestedErrorInfo {
 impl<'a> ErrorTypes<'a> {
     pub fn new(opts: &'a RepositoryOptions) -> Self {
         let table_name = opts.table_name();
-        let column_variants: Vec<ColumnVariant> = opts
+        let mut column_variants: Vec<ColumnVariant> = opts
             .columns
             .column_enum_columns()
             .map(|col| {
@@ -78,9 +83,25 @@ impl<'a> ErrorTypes<'a> {
                     variant_name,
                     column_name: col_name,
                     constraint_names,
+                    composite_of: Vec::new(),
                 }
             })
             .collect();
+        column_variants.extend(opts.unique_constraints().iter().map(|unique| {
+            let names = unique.column_names();
+            ColumnVariant {
+                variant_name: syn::Ident::new(
+                    &names.join("_").to_case(Case::UpperCamel),
+                    Span::call_site(),
+                ),
+                column_name: names.join(", "),
+                constraint_names: vec![unique.constraint_name(table_name)],
+                composite_of: names
+                    .iter()
+                    .map(|n| syn::Ident::new(&n.to_case(Case::UpperCamel), Span::call_site()))
+                    .collect(),
+            }
+        }));
 
         let type_param_idents: Vec<&syn::Ident> =
             opts.generics.type_params().map(|p| &p.ident).collect();
@@ -112,6 +133,7 @@ impl<'a> ErrorTypes<'a> {
 
         Self {
             entity: opts.entity(),
+            id: opts.id(),
             column_enum: opts.column_enum(),
             create_error: opts.create_error(),
             modify_error: opts.modify_error(),
@@ -119,6 +141,8 @@ impl<'a> ErrorTypes<'a> {
             query_error: opts.query_error(),
             forget_error: opts.forget_error(),
             forgettable: opts.forgettable_enabled(),
+            hard_delete: opts.hard_delete_enabled(),
+            ignores_create_conflicts: opts.create.ignores_conflicts(),
             column_variants,
             nested,
             post_hydrate_hook: &opts.post_hydrate_hook,
@@ -204,6 +228,43 @@ impl<'a> ErrorTypes<'a> {
         self.generate_map_constraint_column()
     }
 
+    /// `was_duplicate_composite` is only generated if the repo declares composite
+    /// `unique(...)` constraints.
+    fn generate_was_duplicate_composite(&self) -> TokenStream {
+        let column_enum = &self.column_enum;
+        let arms: Vec<_> = self
+            .column_variants
+            .iter()
+            .filter(|v| !v.composite_of.is_empty())
+            .map(|v| {
+                let variant = &v.variant_name;
+                let parts = &v.composite_of;
+                let n = parts.len();
+                quote! {
+                    Some(#column_enum::#variant) => {
+                        columns.len() == #n && [#(#column_enum::#parts),*].iter().all(|c| columns.contains(c))
+                    }
+                }
+            })
+            .collect();
+        if arms.is_empty() {
+            return quote! {};
+        }
+
+        quote! {
+            /// Returns `true` if a composite unique constraint covering exactly `columns` (in any order) was violated.
+            pub fn was_duplicate_composite(&self, columns: &[#column_enum]) -> bool {
+                match self {
+                    Self::ConstraintViolation { column, .. } => match column {
+                        #(#arms)*
+                        _ => false,
+                    },
+                    _ => false,
+                }
+            }
+        }
+    }
+
     fn generate_column_enum(&self) -> TokenStream {
         let column_enum = &self.column_enum;
         let variants: Vec<_> = self
@@ -258,6 +319,13 @@ impl<'a> ErrorTypes<'a> {
                     _ => None,
                 }
             }
+
+            /// Returns the column guarded by the Postgres constraint `name` (if it belongs to this repo).
+            ///
+            /// Useful for translating a raw `sqlx::Error` into a domain specific error.
+            pub fn column_for_constraint(name: &str) -> Option<#column_enum> {
+                Self::map_constraint_column(Some(name))
+            }
         }
     }
 
@@ -265,6 +333,7 @@ impl<'a> ErrorTypes<'a> {
         let create_error = &self.create_error;
         let column_enum = &self.column_enum;
         let entity = self.entity;
+        let was_duplicate_composite = self.generate_was_duplicate_composite();
 
         // Nested child variants
         let nested_variants: Vec<_> = self
@@ -373,6 +442,19 @@ impl<'a> ErrorTypes<'a> {
             (quote! {}, quote! {}, quote! {})
         };
 
+        let (ae_variant, ae_display_arm, ae_source_arm, ae_wd_check) = if self
+            .ignores_create_conflicts
+        {
+            (
+                quote! { AlreadyExists, },
+                quote! { Self::AlreadyExists => write!(f, "{}CreateError - AlreadyExists", #entity_name), },
+                quote! { Self::AlreadyExists => None, },
+                quote! { Self::AlreadyExists => true, },
+            )
+        } else {
+            (quote! {}, quote! {}, quote! {}, quote! {})
+        };
+
         quote! {
             #[derive(Debug)]
             pub enum #create_error {
@@ -380,6 +462,7 @@ impl<'a> ErrorTypes<'a> {
                 ConstraintViolation { column: Option<#column_enum>, value: Option<String>, inner: sqlx::Error },
                 ConcurrentModification,
                 HydrationError(es_entity::EntityHydrationError),
+                #ae_variant
                 #pp_variant
                 #ph_variant
                 #(#nested_variants)*
@@ -392,6 +475,7 @@ impl<'a> ErrorTypes<'a> {
                         Self::ConstraintViolation { column, value, inner } => write!(f, "{}CreateError - ConstraintViolation({:?}, {:?}): {}", #entity_name, column, value, inner),
                         Self::ConcurrentModification => write!(f, "{}CreateError - ConcurrentModification", #entity_name),
                         Self::HydrationError(e) => write!(f, "{}CreateError - HydrationError: {}", #entity_name, e),
+                        #ae_display_arm
                         #pp_display_arm
                         #ph_display_arm
                         #(#nested_display_arms)*
@@ -406,6 +490,7 @@ impl<'a> ErrorTypes<'a> {
                         Self::ConstraintViolation { inner, .. } => Some(inner),
                         Self::ConcurrentModification => None,
                         Self::HydrationError(e) => Some(e),
+                        #ae_source_arm
                         #pp_source_arm
                         #ph_source_arm
                         #(#nested_source_arms)*
@@ -439,6 +524,7 @@ impl<'a> ErrorTypes<'a> {
                 pub fn was_duplicate(&self) -> bool {
                     match self {
                         Self::ConstraintViolation { .. } => true,
+                        #ae_wd_check
                         #(#nested_wd_checks)*
                         _ => false,
                     }
@@ -448,6 +534,8 @@ impl<'a> ErrorTypes<'a> {
                     matches!(self, Self::ConstraintViolation { column: Some(c), .. } if *c == column)
                 }
 
+                #was_duplicate_composite
+
                 pub fn duplicate_value(&self) -> Option<&str> {
                     match self {
                         Self::ConstraintViolation { value: Some(v), .. } => Some(v.as_str()),
@@ -471,6 +559,7 @@ impl<'a> ErrorTypes<'a> {
         let modify_error = &self.modify_error;
         let column_enum = &self.column_enum;
         let entity = self.entity;
+        let was_duplicate_composite = self.generate_was_duplicate_composite();
 
         // Nested variants: both Modify and Create for each child
         let nested_variants: Vec<_> = self
@@ -624,12 +713,23 @@ impl<'a> ErrorTypes<'a> {
             (quote! {}, quote! {}, quote! {})
         };
 
+        let (hd_variant, hd_display_arm, hd_source_arm) = if self.hard_delete {
+            (
+                quote! { UnpersistedEvents, },
+                quote! { Self::UnpersistedEvents => write!(f, "{}ModifyError - UnpersistedEvents", #entity_name), },
+                quote! { Self::UnpersistedEvents => None, },
+            )
+        } else {
+            (quote! {}, quote! {}, quote! {})
+        };
+
         quote! {
             #[derive(Debug)]
             pub enum #modify_error {
                 Sqlx(sqlx::Error),
                 ConstraintViolation { column: Option<#column_enum>, value: Option<String>, inner: sqlx::Error },
                 ConcurrentModification,
+                #hd_variant
                 #pp_variant
                 #(#nested_variants)*
             }
@@ -640,6 +740,7 @@ impl<'a> ErrorTypes<'a> {
                         Self::Sqlx(e) => write!(f, "{}ModifyError - Sqlx: {}", #entity_name, e),
                         Self::ConstraintViolation { column, value, inner } => write!(f, "{}ModifyError - ConstraintViolation({:?}, {:?}): {}", #entity_name, column, value, inner),
                         Self::ConcurrentModification => write!(f, "{}ModifyError - ConcurrentModification", #entity_name),
+                        #hd_display_arm
                         #pp_display_arm
                         #(#nested_display_arms)*
                     }
@@ -652,6 +753,7 @@ impl<'a> ErrorTypes<'a> {
                         Self::Sqlx(e) => Some(e),
                         Self::ConstraintViolation { inner, .. } => Some(inner),
                         Self::ConcurrentModification => None,
+                        #hd_source_arm
                         #pp_source_arm
                         #(#nested_source_arms)*
                     }
@@ -687,6 +789,8 @@ impl<'a> ErrorTypes<'a> {
                     matches!(self, Self::ConstraintViolation { column: Some(c), .. } if *c == column)
                 }
 
+                #was_duplicate_composite
+
                 pub fn duplicate_value(&self) -> Option<&str> {
                     match self {
                         Self::ConstraintViolation { value: Some(v), .. } => Some(v.as_str()),
@@ -709,6 +813,7 @@ impl<'a> ErrorTypes<'a> {
         let find_error = &self.find_error;
         let query_error = &self.query_error;
         let column_enum = &self.column_enum;
+        let id = self.id;
         let entity = self.entity;
         let entity_name = entity.to_string();
 
@@ -735,7 +840,7 @@ impl<'a> ErrorTypes<'a> {
             #[derive(Debug)]
             pub enum #find_error {
                 Sqlx(sqlx::Error),
-                NotFound { entity: &'static str, column: Option<#column_enum>, value: String },
+                NotFound { entity: &'static str, column: Option<#column_enum>, value: String, id: Option<#id> },
                 HydrationError(es_entity::EntityHydrationError),
                 #ph_variant
             }
@@ -744,8 +849,8 @@ impl<'a> ErrorTypes<'a> {
                 fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                     match self {
                         Self::Sqlx(e) => write!(f, "{}FindError - Sqlx: {}", #entity_name, e),
-                        Self::NotFound { entity, column: Some(column), value } => write!(f, "{}FindError - NotFound({column}={value})", entity),
-                        Self::NotFound { entity, column: None, value } => write!(f, "{}FindError - NotFound({})", entity, value),
+                        Self::NotFound { entity, column: Some(column), value, .. } => write!(f, "{}FindError - NotFound({column}={value})", entity),
+                        Self::NotFound { entity, column: None, value, .. } => write!(f, "{}FindError - NotFound({})", entity, value),
                         Self::HydrationError(e) => write!(f, "{}FindError - HydrationError: {}", #entity_name, e),
                         #ph_display_arm
                     }
@@ -781,6 +886,7 @@ impl<'a> ErrorTypes<'a> {
                         #query_error::Sqlx(e) => Self::Sqlx(e),
                         #query_error::HydrationError(e) => Self::HydrationError(e),
                         #query_error::CursorDestructureError(_) => unreachable!("CursorDestructureError cannot occur in find operations"),
+                        #query_error::InvalidFilters(_) => unreachable!("InvalidFilters cannot occur in find operations"),
                         #ph_from_arm
                     }
                 }
@@ -802,6 +908,14 @@ impl<'a> ErrorTypes<'a> {
                     }
                 }
 
+                /// The id that was looked up if the entity was not found by its id.
+                pub fn not_found_id(&self) -> Option<&#id> {
+                    match self {
+                        Self::NotFound { id, .. } => id.as_ref(),
+                        _ => None,
+                    }
+                }
+
                 pub fn was_post_hydrate_error(&self) -> bool {
                     match self {
                         #find_ph_self_check
@@ -841,6 +955,7 @@ impl<'a> ErrorTypes<'a> {
                 Sqlx(sqlx::Error),
                 HydrationError(es_entity::EntityHydrationError),
                 CursorDestructureError(es_entity::CursorDestructureError),
+                InvalidFilters(es_entity::FilterError),
                 #ph_variant
             }
 
@@ -850,6 +965,7 @@ impl<'a> ErrorTypes<'a> {
                         Self::Sqlx(e) => write!(f, "{}QueryError - Sqlx: {}", #entity_name, e),
                         Self::HydrationError(e) => write!(f, "{}QueryError - HydrationError: {}", #entity_name, e),
                         Self::CursorDestructureError(e) => write!(f, "{}QueryError - CursorDestructureError: {}", #entity_name, e),
+                        Self::InvalidFilters(e) => write!(f, "{}QueryError - InvalidFilters: {}", #entity_name, e),
                         #ph_display_arm
                     }
                 }
@@ -861,6 +977,7 @@ impl<'a> ErrorTypes<'a> {
                         Self::Sqlx(e) => Some(e),
                         Self::HydrationError(e) => Some(e),
                         Self::CursorDestructureError(e) => Some(e),
+                        Self::InvalidFilters(e) => Some(e),
                         #ph_source_arm
                     }
                 }
@@ -884,6 +1001,12 @@ impl<'a> ErrorTypes<'a> {
                 }
             }
 
+            impl From<es_entity::FilterError> for #query_error {
+                fn from(e: es_entity::FilterError) -> Self {
+                    Self::InvalidFilters(e)
+                }
+            }
+
             impl #query_error {
                 pub fn was_post_hydrate_error(&self) -> bool {
                     match self {
@@ -905,15 +1028,11 @@ fn type_uses_any_generic(ty: &syn::Type, idents: &[&syn::Ident]) -> bool {
 fn token_stream_contains_any(ts: proc_macro2::TokenStream, idents: &[&syn::Ident]) -> bool {
     for tt in ts {
         match tt {
-            proc_macro2::TokenTree::Ident(ref i) => {
-                if idents.iter().any(|id| *i == **id) {
-                    return true;
-                }
+            proc_macro2::TokenTree::Ident(ref i) if idents.iter().any(|id| *i == **id) => {
+                return true;
             }
-            proc_macro2::TokenTree::Group(g) => {
-                if token_stream_contains_any(g.stream(), idents) {
-                    return true;
-                }
+            proc_macro2::TokenTree::Group(g) if token_stream_contains_any(g.stream(), idents) => {
+                return true;
             }
             _ => {}
         }
@@ -958,10 +1077,12 @@ mod tests {
         // Leak entity ident to get a 'static reference for tests
         let entity: &'static syn::Ident =
             Box::leak(Box::new(Ident::new("Order", Span::call_site())));
+        let id: &'static syn::Ident = Box::leak(Box::new(Ident::new("OrderId", Span::call_site())));
         let post_hydrate_hook: &'static Option<PostHydrateHookConfig> = Box::leak(Box::new(None));
         let post_persist_hook: &'static Option<PostPersistHookConfig> = Box::leak(Box::new(None));
         ErrorTypes {
             entity,
+            id,
             column_enum: Ident::new("OrderColumn", Span::call_site()),
             create_error: Ident::new("OrderCreateError", Span::call_site()),
             modify_error: Ident::new("OrderModifyError", Span::call_site()),
@@ -969,6 +1090,8 @@ mod tests {
             query_error: Ident::new("OrderQueryError", Span::call_site()),
             forget_error: Ident::new("OrderForgetError", Span::call_site()),
             forgettable: false,
+            hard_delete: false,
+            ignores_create_conflicts: false,
             column_variants: vec![],
             nested,
             post_hydrate_hook,
@@ -1180,8 +1303,10 @@ mod tests {
             Box::leak(Box::new(Ident::new("Order", Span::call_site())));
         let ph: &'static Option<PostHydrateHookConfig> = Box::leak(Box::new(post_hydrate_hook));
         let pp: &'static Option<PostPersistHookConfig> = Box::leak(Box::new(post_persist_hook));
+        let id: &'static syn::Ident = Box::leak(Box::new(Ident::new("OrderId", Span::call_site())));
         ErrorTypes {
             entity,
+            id,
             column_enum: Ident::new("OrderColumn", Span::call_site()),
             create_error: Ident::new("OrderCreateError", Span::call_site()),
             modify_error: Ident::new("OrderModifyError", Span::call_site()),
@@ -1189,6 +1314,8 @@ mod tests {
             query_error: Ident::new("OrderQueryError", Span::call_site()),
             forget_error: Ident::new("OrderForgetError", Span::call_site()),
             forgettable: false,
+            hard_delete: false,
+            ignores_create_conflicts: false,
             column_variants: vec![],
             nested,
             post_hydrate_hook: ph,
@@ -1412,4 +1539,51 @@ mod tests {
             "should not contain PostHydrateError variant without hook: {output}"
         );
     }
+
+    #[test]
+    fn composite_unique_constraint() {
+        let mut et = make_error_types(vec![]);
+        et.column_variants = vec![ColumnVariant {
+            variant_name: Ident::new("TenantIdEmail", Span::call_site()),
+            column_name: "tenant_id, email".to_string(),
+            constraint_names: vec!["orders_tenant_id_email_key".to_string()],
+            composite_of: vec![
+                Ident::new("TenantId", Span::call_site()),
+                Ident::new("Email", Span::call_site()),
+            ],
+        }];
+
+        let map_fn = et.generate_map_constraint_fn().to_string();
+        assert!(
+            map_fn.contains(
+                "Some (\"orders_tenant_id_email_key\") => Some (OrderColumn :: TenantIdEmail)"
+            ),
+            "composite constraint should be mapped: {map_fn}"
+        );
+
+        let expected = quote! {
+            /// Returns `true` if a composite unique constraint covering exactly `columns` (in any order) was violated.
+            pub fn was_duplicate_composite(&self, columns: &[OrderColumn]) -> bool {
+                match self {
+                    Self::ConstraintViolation { column, .. } => match column {
+                        Some(OrderColumn::TenantIdEmail) => {
+                            columns.len() == 2usize && [OrderColumn::TenantId, OrderColumn::Email].iter().all(|c| columns.contains(c))
+                        }
+                        _ => false,
+                    },
+                    _ => false,
+                }
+            }
+        };
+        assert_eq!(
+            et.generate_was_duplicate_composite().to_string(),
+            expected.to_string()
+        );
+    }
+
+    #[test]
+    fn was_duplicate_composite_not_generated_without_composites() {
+        let et = make_error_types(vec![]);
+        assert!(et.generate_was_duplicate_composite().is_empty());
+    }
 }
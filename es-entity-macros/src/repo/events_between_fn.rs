@@ -0,0 +1,191 @@
+use darling::ToTokens;
+use proc_macro2::TokenStream;
+use quote::{TokenStreamExt, quote};
+
+use super::options::*;
+
+pub struct EventsBetweenFn<'a> {
+    id: &'a syn::Ident,
+    event: &'a syn::Ident,
+    events_table_name: &'a str,
+    events_discriminator: Option<&'a EventsDiscriminator>,
+    forgettable_table_name: Option<&'a str>,
+    query_error: syn::Ident,
+    has_read_pool: bool,
+}
+
+impl<'a> From<&'a RepositoryOptions> for EventsBetweenFn<'a> {
+    fn from(opts: &'a RepositoryOptions) -> Self {
+        Self {
+            id: opts.id(),
+            event: opts.event(),
+            events_table_name: opts.events_table_name(),
+            events_discriminator: opts.events_discriminator(),
+            forgettable_table_name: opts.forgettable_table_name(),
+            query_error: opts.query_error(),
+            has_read_pool: opts.read_pool_accessor().is_some(),
+        }
+    }
+}
+
+impl ToTokens for EventsBetweenFn<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let id = self.id;
+        let event = self.event;
+        let query_error = &self.query_error;
+        let read_pool = RepositoryOptions::read_pool(self.has_read_pool);
+
+        let (query, load_event) = if let Some(forgettable_tbl) = self.forgettable_table_name {
+            (
+                format!(
+                    "SELECT e.sequence, e.event, p.payload as \"forgettable_payload?\" FROM {} e LEFT JOIN {} p ON e.id = p.entity_id AND e.sequence = p.sequence WHERE e.id = $1{} AND e.sequence BETWEEN $2 AND $3 ORDER BY e.sequence",
+                    self.events_table_name,
+                    forgettable_tbl,
+                    self.events_discriminator
+                        .map(|d| d.condition(Some("e")))
+                        .unwrap_or_default(),
+                ),
+                quote! {
+                    let mut event = row.event;
+                    <#event as es_entity::EsEvent>::strip_entity_id(&mut event);
+                    if let Some(payload) = row.forgettable_payload {
+                        es_entity::forgettable::inject_forgettable_payload(&mut event, payload);
+                    }
+                },
+            )
+        } else {
+            (
+                format!(
+                    "SELECT sequence, event FROM {} WHERE id = $1{} AND sequence BETWEEN $2 AND $3 ORDER BY sequence",
+                    self.events_table_name,
+                    self.events_discriminator
+                        .map(|d| d.condition(None))
+                        .unwrap_or_default(),
+                ),
+                quote! {
+                    let mut event = row.event;
+                    <#event as es_entity::EsEvent>::strip_entity_id(&mut event);
+                },
+            )
+        };
+
+        tokens.append_all(quote! {
+            /// Loads the events of entity `id` with a sequence in `from..=to` (ordered by sequence).
+            ///
+            /// The events are deserialized but not projected onto the entity.
+            pub async fn events_between(
+                &self,
+                id: &#id,
+                from: usize,
+                to: usize,
+            ) -> Result<Vec<(usize, #event)>, #query_error> {
+                self.events_between_in_op(#read_pool, id, from, to).await
+            }
+
+            pub async fn events_between_in_op<'a, OP>(
+                &self,
+                op: OP,
+                id: &#id,
+                from: usize,
+                to: usize,
+            ) -> Result<Vec<(usize, #event)>, #query_error>
+            where
+                OP: es_entity::IntoOneTimeExecutor<'a>
+            {
+                // Sequences are stored as INT4 - nothing lies beyond i32::MAX
+                let Ok(from) = i32::try_from(from) else {
+                    return Ok(Vec::new());
+                };
+                let to = i32::try_from(to).unwrap_or(i32::MAX);
+                let rows = op
+                    .into_executor()
+                    .fetch_all(sqlx::query!(#query, id as &#id, from, to))
+                    .await?;
+                rows.into_iter()
+                    .map(|row| {
+                        #load_event
+                        let event = es_entity::prelude::serde_json::from_value(event)
+                            .map_err(es_entity::EntityHydrationError::from)?;
+                        Ok((row.sequence as usize, event))
+                    })
+                    .collect()
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proc_macro2::Span;
+    use syn::Ident;
+
+    #[test]
+    fn events_between_fn() {
+        let id = Ident::new("EntityId", Span::call_site());
+        let event = Ident::new("EntityEvent", Span::call_site());
+        let events_between_fn = EventsBetweenFn {
+            id: &id,
+            event: &event,
+            events_table_name: "entity_events",
+            events_discriminator: None,
+            forgettable_table_name: None,
+            query_error: Ident::new("EntityQueryError", Span::call_site()),
+            has_read_pool: false,
+        };
+
+        let mut tokens = TokenStream::new();
+        events_between_fn.to_tokens(&mut tokens);
+
+        let expected = quote! {
+            /// Loads the events of entity `id` with a sequence in `from..=to` (ordered by sequence).
+            ///
+            /// The events are deserialized but not projected onto the entity.
+            pub async fn events_between(
+                &self,
+                id: &EntityId,
+                from: usize,
+                to: usize,
+            ) -> Result<Vec<(usize, EntityEvent)>, EntityQueryError> {
+                self.events_between_in_op(self.pool(), id, from, to).await
+            }
+
+            pub async fn events_between_in_op<'a, OP>(
+                &self,
+                op: OP,
+                id: &EntityId,
+                from: usize,
+                to: usize,
+            ) -> Result<Vec<(usize, EntityEvent)>, EntityQueryError>
+            where
+                OP: es_entity::IntoOneTimeExecutor<'a>
+            {
+                // Sequences are stored as INT4 - nothing lies beyond i32::MAX
+                let Ok(from) = i32::try_from(from) else {
+                    return Ok(Vec::new());
+                };
+                let to = i32::try_from(to).unwrap_or(i32::MAX);
+                let rows = op
+                    .into_executor()
+                    .fetch_all(sqlx::query!(
+                        "SELECT sequence, event FROM entity_events WHERE id = $1 AND sequence BETWEEN $2 AND $3 ORDER BY sequence",
+                        id as &EntityId,
+                        from,
+                        to
+                    ))
+                    .await?;
+                rows.into_iter()
+                    .map(|row| {
+                        let mut event = row.event;
+                        <EntityEvent as es_entity::EsEvent>::strip_entity_id(&mut event);
+                        let event = es_entity::prelude::serde_json::from_value(event)
+                            .map_err(es_entity::EntityHydrationError::from)?;
+                        Ok((row.sequence as usize, event))
+                    })
+                    .collect()
+            }
+        };
+
+        assert_eq!(tokens.to_string(), expected.to_string());
+    }
+}
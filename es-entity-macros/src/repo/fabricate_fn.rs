@@ -0,0 +1,86 @@
+use darling::ToTokens;
+use proc_macro2::TokenStream;
+use quote::{TokenStreamExt, quote};
+
+use super::options::*;
+
+pub struct FabricateFn<'a> {
+    id: &'a syn::Ident,
+    entity: &'a syn::Ident,
+}
+
+impl<'a> From<&'a RepositoryOptions> for FabricateFn<'a> {
+    fn from(opts: &'a RepositoryOptions) -> Self {
+        Self {
+            id: opts.id(),
+            entity: opts.entity(),
+        }
+    }
+}
+
+impl ToTokens for FabricateFn<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let id = self.id;
+        let entity = self.entity;
+
+        tokens.append_all(quote! {
+            /// Hydrates an entity from `events` in memory without touching the database.
+            ///
+            /// Only available with the `testing` feature.
+            pub fn __fabricate(
+                id: #id,
+                events: impl IntoIterator<Item = <#entity as es_entity::EsEntity>::Event>,
+            ) -> Result<#entity, es_entity::EntityHydrationError> {
+                Self::__fabricator(id).events(events).build()
+            }
+
+            /// Returns a builder to assemble an entity in memory by applying events.
+            ///
+            /// Only available with the `testing` feature.
+            pub fn __fabricator(id: #id) -> es_entity::EntityFabricator<#entity> {
+                es_entity::EntityFabricator::new(id)
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proc_macro2::Span;
+    use syn::Ident;
+
+    #[test]
+    fn fabricate_fn() {
+        let id = Ident::new("EntityId", Span::call_site());
+        let entity = Ident::new("Entity", Span::call_site());
+        let fabricate_fn = FabricateFn {
+            id: &id,
+            entity: &entity,
+        };
+
+        let mut tokens = TokenStream::new();
+        fabricate_fn.to_tokens(&mut tokens);
+
+        let expected = quote! {
+            /// Hydrates an entity from `events` in memory without touching the database.
+            ///
+            /// Only available with the `testing` feature.
+            pub fn __fabricate(
+                id: EntityId,
+                events: impl IntoIterator<Item = <Entity as es_entity::EsEntity>::Event>,
+            ) -> Result<Entity, es_entity::EntityHydrationError> {
+                Self::__fabricator(id).events(events).build()
+            }
+
+            /// Returns a builder to assemble an entity in memory by applying events.
+            ///
+            /// Only available with the `testing` feature.
+            pub fn __fabricator(id: EntityId) -> es_entity::EntityFabricator<Entity> {
+                es_entity::EntityFabricator::new(id)
+            }
+        };
+
+        assert_eq!(tokens.to_string(), expected.to_string());
+    }
+}
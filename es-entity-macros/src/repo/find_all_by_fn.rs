@@ -0,0 +1,304 @@
+use darling::ToTokens;
+use proc_macro2::{Span, TokenStream};
+use quote::{TokenStreamExt, quote};
+
+use super::options::*;
+
+pub struct FindAllByFn<'a> {
+    prefix: Option<&'a syn::LitStr>,
+    entity: &'a syn::Ident,
+    column: &'a Column,
+    table_name: &'a str,
+    query_error: syn::Ident,
+    delete: DeleteOption,
+    any_nested: bool,
+    has_read_pool: bool,
+    post_hydrate_error: Option<&'a syn::Type>,
+    forgettable_table_name: Option<&'a str>,
+    event_context_column: Option<&'a str>,
+    events_discriminator_args: TokenStream,
+    #[cfg(feature = "instrument")]
+    repo_name_snake: String,
+}
+
+impl<'a> FindAllByFn<'a> {
+    pub fn new(column: &'a Column, opts: &'a RepositoryOptions) -> Self {
+        Self {
+            prefix: opts.table_prefix(),
+            column,
+            entity: opts.entity(),
+            table_name: opts.table_name(),
+            query_error: opts.query_error(),
+            delete: opts.delete,
+            any_nested: opts.any_nested(),
+            has_read_pool: opts.read_pool_accessor().is_some(),
+            post_hydrate_error: opts.post_hydrate_hook.as_ref().map(|h| &h.error),
+            forgettable_table_name: opts.forgettable_table_name(),
+            event_context_column: opts.custom_event_context_column(),
+            events_discriminator_args: opts.es_query_events_discriminator_args(),
+            #[cfg(feature = "instrument")]
+            repo_name_snake: opts.repo_name_snake_case(),
+        }
+    }
+}
+
+impl ToTokens for FindAllByFn<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let entity = self.entity;
+        let error = &self.query_error;
+        let column_name = &self.column.name();
+        let (column_type, impl_expr, access_expr) = &self.column.ty_for_find_by();
+        let query_fn_generics = RepositoryOptions::query_fn_generics(self.any_nested);
+        let query_fn_op_arg = RepositoryOptions::query_fn_op_arg(self.any_nested);
+        let query_fn_op_traits = RepositoryOptions::query_fn_op_traits(self.any_nested);
+        let query_fn_get_op =
+            RepositoryOptions::query_fn_get_op(self.any_nested, self.has_read_pool);
+
+        for delete in [DeleteOption::No, DeleteOption::Soft] {
+            let fn_name = syn::Ident::new(
+                &format!(
+                    "find_all_by_{}{}",
+                    column_name,
+                    delete.include_deletion_fn_postfix()
+                ),
+                Span::call_site(),
+            );
+            let fn_in_op = syn::Ident::new(
+                &format!(
+                    "find_all_by_{}{}_in_op",
+                    column_name,
+                    delete.include_deletion_fn_postfix()
+                ),
+                Span::call_site(),
+            );
+
+            let filter_op = if self.column.is_optional() {
+                "IS NOT DISTINCT FROM"
+            } else {
+                "="
+            };
+            let query = format!(
+                r#"SELECT id FROM {} WHERE {} {} $1{}"#,
+                self.table_name,
+                column_name,
+                filter_op,
+                if delete == DeleteOption::No {
+                    self.delete.not_deleted_condition()
+                } else {
+                    ""
+                }
+            );
+
+            let forgettable_tbl_arg = if let Some(tbl) = self.forgettable_table_name {
+                quote! { forgettable_tbl = #tbl, }
+            } else {
+                quote! {}
+            };
+            let events_discriminator_args = &self.events_discriminator_args;
+            let event_context_column_arg = if let Some(column) = self.event_context_column {
+                quote! { event_context_column = #column, }
+            } else {
+                quote! {}
+            };
+
+            let es_query_call = if let Some(prefix) = self.prefix {
+                quote! {
+                    es_entity::es_query!(
+                        #events_discriminator_args
+                        tbl_prefix = #prefix,
+                        #forgettable_tbl_arg
+                        #event_context_column_arg
+                        #query,
+                        #column_name as &#column_type,
+                    )
+                }
+            } else {
+                quote! {
+                    es_entity::es_query!(
+                        #events_discriminator_args
+                        entity = #entity,
+                        #forgettable_tbl_arg
+                        #event_context_column_arg
+                        #query,
+                        #column_name as &#column_type,
+                    )
+                }
+            };
+
+            let fetch_all_call = if delete == DeleteOption::Soft && self.any_nested {
+                quote! { #es_query_call.fetch_all_include_deleted(op).await? }
+            } else {
+                quote! { #es_query_call.fetch_all(op).await? }
+            };
+
+            let post_hydrate_check = if self.post_hydrate_error.is_some() {
+                quote! {
+                    for __entity in &__entities {
+                        self.execute_post_hydrate_hook(__entity).map_err(#error::PostHydrateError)?;
+                    }
+                }
+            } else {
+                quote! {}
+            };
+
+            #[cfg(feature = "instrument")]
+            let (instrument_attr_in_op, record_field, error_recording) = {
+                let entity_name = entity.to_string();
+                let repo_name = &self.repo_name_snake;
+                let span_name = format!("{}.find_all_by_{}", repo_name, column_name);
+                let field_name = format!("query_{}", column_name);
+                let field_ident = syn::Ident::new(&field_name, proc_macro2::Span::call_site());
+                (
+                    quote! {
+                        #[tracing::instrument(name = #span_name, skip_all, fields(entity = #entity_name, #field_ident = tracing::field::Empty, error = tracing::field::Empty, exception.message = tracing::field::Empty, exception.type = tracing::field::Empty))]
+                    },
+                    quote! {
+                        tracing::Span::current().record(#field_name, tracing::field::debug(&#column_name));
+                    },
+                    quote! {
+                        if let Err(ref e) = __result {
+                            tracing::Span::current().record("error", true);
+                            tracing::Span::current().record("exception.message", tracing::field::display(e));
+                            tracing::Span::current().record("exception.type", std::any::type_name_of_val(e));
+                        }
+                    },
+                )
+            };
+            #[cfg(not(feature = "instrument"))]
+            let (instrument_attr_in_op, record_field, error_recording) =
+                (quote! {}, quote! {}, quote! {});
+
+            tokens.append_all(quote! {
+                pub async fn #fn_name(
+                    &self,
+                    #column_name: #impl_expr
+                ) -> Result<Vec<#entity>, #error> {
+                    self.#fn_in_op(#query_fn_get_op, #column_name).await
+                }
+
+                #instrument_attr_in_op
+                pub async fn #fn_in_op #query_fn_generics(
+                    &self,
+                    #query_fn_op_arg,
+                    #column_name: #impl_expr
+                ) -> Result<Vec<#entity>, #error>
+                    where
+                        OP: #query_fn_op_traits
+                {
+                    let __result: Result<Vec<#entity>, #error> = async {
+                        let #column_name = #column_name.#access_expr;
+                        #record_field
+                        let __entities = #fetch_all_call;
+                        #post_hydrate_check
+                        Ok(__entities)
+                    }.await;
+
+                    #error_recording
+                    __result
+                }
+            });
+
+            if delete == self.delete || self.delete == DeleteOption::SoftWithoutQueries {
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proc_macro2::Span;
+    use syn::Ident;
+
+    #[test]
+    fn find_all_by_fn() {
+        let column = Column::new(
+            Ident::new("batch_id", Span::call_site()),
+            syn::parse_str("BatchId").unwrap(),
+        );
+        let entity = Ident::new("Entity", Span::call_site());
+
+        let find_all_by_fn = FindAllByFn {
+            prefix: None,
+            column: &column,
+            entity: &entity,
+            table_name: "entities",
+            query_error: syn::Ident::new("EntityQueryError", Span::call_site()),
+            delete: DeleteOption::Soft,
+            any_nested: false,
+            has_read_pool: false,
+            post_hydrate_error: None,
+            forgettable_table_name: None,
+            event_context_column: None,
+            events_discriminator_args: TokenStream::new(),
+            #[cfg(feature = "instrument")]
+            repo_name_snake: "test_repo".to_string(),
+        };
+
+        let mut tokens = TokenStream::new();
+        find_all_by_fn.to_tokens(&mut tokens);
+
+        let expected = quote! {
+            pub async fn find_all_by_batch_id(
+                &self,
+                batch_id: impl std::borrow::Borrow<BatchId>
+            ) -> Result<Vec<Entity>, EntityQueryError> {
+                self.find_all_by_batch_id_in_op(self.pool(), batch_id).await
+            }
+
+            pub async fn find_all_by_batch_id_in_op<'a, OP>(
+                &self,
+                op: OP,
+                batch_id: impl std::borrow::Borrow<BatchId>
+            ) -> Result<Vec<Entity>, EntityQueryError>
+                where
+                    OP: es_entity::IntoOneTimeExecutor<'a>
+            {
+                let __result: Result<Vec<Entity>, EntityQueryError> = async {
+                    let batch_id = batch_id.borrow();
+                    let __entities = es_entity::es_query!(
+                        entity = Entity,
+                        "SELECT id FROM entities WHERE batch_id = $1 AND deleted = FALSE",
+                        batch_id as &BatchId,
+                    )
+                    .fetch_all(op).await?;
+                    Ok(__entities)
+                }.await;
+
+                __result
+            }
+
+            pub async fn find_all_by_batch_id_include_deleted(
+                &self,
+                batch_id: impl std::borrow::Borrow<BatchId>
+            ) -> Result<Vec<Entity>, EntityQueryError> {
+                self.find_all_by_batch_id_include_deleted_in_op(self.pool(), batch_id).await
+            }
+
+            pub async fn find_all_by_batch_id_include_deleted_in_op<'a, OP>(
+                &self,
+                op: OP,
+                batch_id: impl std::borrow::Borrow<BatchId>
+            ) -> Result<Vec<Entity>, EntityQueryError>
+                where
+                    OP: es_entity::IntoOneTimeExecutor<'a>
+            {
+                let __result: Result<Vec<Entity>, EntityQueryError> = async {
+                    let batch_id = batch_id.borrow();
+                    let __entities = es_entity::es_query!(
+                        entity = Entity,
+                        "SELECT id FROM entities WHERE batch_id = $1",
+                        batch_id as &BatchId,
+                    )
+                    .fetch_all(op).await?;
+                    Ok(__entities)
+                }.await;
+
+                __result
+            }
+        };
+
+        assert_eq!(tokens.to_string(), expected.to_string());
+    }
+}
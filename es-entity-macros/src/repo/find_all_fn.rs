@@ -11,8 +11,11 @@ pub struct FindAllFn<'a> {
     table_name: &'a str,
     query_error: syn::Ident,
     any_nested: bool,
+    has_read_pool: bool,
     post_hydrate_error: Option<&'a syn::Type>,
     forgettable_table_name: Option<&'a str>,
+    event_context_column: Option<&'a str>,
+    events_discriminator_args: TokenStream,
     #[cfg(feature = "instrument")]
     repo_name_snake: String,
 }
@@ -26,8 +29,11 @@ impl<'a> From<&'a RepositoryOptions> for FindAllFn<'a> {
             table_name: opts.table_name(),
             query_error: opts.query_error(),
             any_nested: opts.any_nested(),
+            has_read_pool: opts.read_pool_accessor().is_some(),
             post_hydrate_error: opts.post_hydrate_hook.as_ref().map(|h| &h.error),
             forgettable_table_name: opts.forgettable_table_name(),
+            event_context_column: opts.custom_event_context_column(),
+            events_discriminator_args: opts.es_query_events_discriminator_args(),
             #[cfg(feature = "instrument")]
             repo_name_snake: opts.repo_name_snake_case(),
         }
@@ -40,13 +46,19 @@ impl ToTokens for FindAllFn<'_> {
         let entity = self.entity;
         let query_error = &self.query_error;
         let query_fn_op_traits = RepositoryOptions::query_fn_op_traits(self.any_nested);
-        let query_fn_get_op = RepositoryOptions::query_fn_get_op(self.any_nested);
+        let query_fn_get_op =
+            RepositoryOptions::query_fn_get_op(self.any_nested, self.has_read_pool);
 
         let generics = if self.any_nested {
             quote! { <Out: From<#entity>> }
         } else {
             quote! { <'a, Out: From<#entity>> }
         };
+        let by_ids_generics = if self.any_nested {
+            quote! {}
+        } else {
+            quote! { <'a> }
+        };
 
         let query = format!("SELECT id FROM {} WHERE id = ANY($1)", self.table_name);
 
@@ -55,12 +67,20 @@ impl ToTokens for FindAllFn<'_> {
         } else {
             quote! {}
         };
+        let events_discriminator_args = &self.events_discriminator_args;
+        let event_context_column_arg = if let Some(column) = self.event_context_column {
+            quote! { event_context_column = #column, }
+        } else {
+            quote! {}
+        };
 
         let es_query_call = if let Some(prefix) = self.prefix {
             quote! {
                 es_entity::es_query!(
+                    #events_discriminator_args
                     tbl_prefix = #prefix,
                     #forgettable_tbl_arg
+                    #event_context_column_arg
                     #query,
                     ids as &[#id],
                 )
@@ -68,8 +88,10 @@ impl ToTokens for FindAllFn<'_> {
         } else {
             quote! {
                 es_entity::es_query!(
+                    #events_discriminator_args
                     entity = #entity,
                     #forgettable_tbl_arg
+                    #event_context_column_arg
                     #query,
                     ids as &[#id],
                 )
@@ -122,6 +144,41 @@ impl ToTokens for FindAllFn<'_> {
                  #post_hydrate_check
                  Ok(entities.into_iter().map(|u| (u.id.clone(), Out::from(u))).collect())
             }
+
+            /// Loads the entities for `ids` in a single query and returns them in the order of `ids`.
+            ///
+            /// Ids that don't exist are skipped and duplicate ids are only returned once.
+            pub async fn find_all_by_ids(
+                &self,
+                ids: &[#id]
+            ) -> Result<Vec<#entity>, #query_error> {
+                self.find_all_by_ids_in_op(#query_fn_get_op, ids).await
+            }
+
+            pub async fn find_all_by_ids_in_op #by_ids_generics(
+                &self,
+                #op_param,
+                ids: &[#id]
+            ) -> Result<Vec<#entity>, #query_error> {
+                let mut __entities = self.find_all_in_op::<#entity>(op, ids).await?;
+                Ok(ids.iter().filter_map(|id| __entities.remove(id)).collect())
+            }
+
+            /// Like [`find_all_by_ids`](Self::find_all_by_ids) but keyed by id for callers that prefer lookup.
+            pub async fn find_all_by_ids_map(
+                &self,
+                ids: &[#id]
+            ) -> Result<std::collections::HashMap<#id, #entity>, #query_error> {
+                self.find_all_in_op(#query_fn_get_op, ids).await
+            }
+
+            pub async fn find_all_by_ids_map_in_op #by_ids_generics(
+                &self,
+                #op_param,
+                ids: &[#id]
+            ) -> Result<std::collections::HashMap<#id, #entity>, #query_error> {
+                self.find_all_in_op(op, ids).await
+            }
         });
     }
 }
@@ -145,8 +202,11 @@ mod tests {
             table_name: "entities",
             query_error,
             any_nested: false,
+            has_read_pool: false,
             post_hydrate_error: None,
             forgettable_table_name: None,
+            event_context_column: None,
+            events_discriminator_args: TokenStream::new(),
             #[cfg(feature = "instrument")]
             repo_name_snake: "test_repo".to_string(),
         };
@@ -176,6 +236,41 @@ mod tests {
                     .await?;
                 Ok(entities.into_iter().map(|u| (u.id.clone(), Out::from(u))).collect())
             }
+
+            /// Loads the entities for `ids` in a single query and returns them in the order of `ids`.
+            ///
+            /// Ids that don't exist are skipped and duplicate ids are only returned once.
+            pub async fn find_all_by_ids(
+                &self,
+                ids: &[EntityId]
+            ) -> Result<Vec<Entity>, EntityQueryError> {
+                self.find_all_by_ids_in_op(self.pool(), ids).await
+            }
+
+            pub async fn find_all_by_ids_in_op<'a>(
+                &self,
+                op: impl es_entity::IntoOneTimeExecutor<'a>,
+                ids: &[EntityId]
+            ) -> Result<Vec<Entity>, EntityQueryError> {
+                let mut __entities = self.find_all_in_op::<Entity>(op, ids).await?;
+                Ok(ids.iter().filter_map(|id| __entities.remove(id)).collect())
+            }
+
+            /// Like [`find_all_by_ids`](Self::find_all_by_ids) but keyed by id for callers that prefer lookup.
+            pub async fn find_all_by_ids_map(
+                &self,
+                ids: &[EntityId]
+            ) -> Result<std::collections::HashMap<EntityId, Entity>, EntityQueryError> {
+                self.find_all_in_op(self.pool(), ids).await
+            }
+
+            pub async fn find_all_by_ids_map_in_op<'a>(
+                &self,
+                op: impl es_entity::IntoOneTimeExecutor<'a>,
+                ids: &[EntityId]
+            ) -> Result<std::collections::HashMap<EntityId, Entity>, EntityQueryError> {
+                self.find_all_in_op(op, ids).await
+            }
         };
 
         assert_eq!(tokens.to_string(), expected.to_string());
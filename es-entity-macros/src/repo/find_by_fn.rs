@@ -15,8 +15,11 @@ pub struct FindByFn<'a> {
     query_error: syn::Ident,
     delete: DeleteOption,
     any_nested: bool,
+    has_read_pool: bool,
     post_hydrate_error: Option<&'a syn::Type>,
     forgettable_table_name: Option<&'a str>,
+    event_context_column: Option<&'a str>,
+    events_discriminator_args: TokenStream,
     #[cfg(feature = "instrument")]
     repo_name_snake: String,
 }
@@ -33,8 +36,11 @@ impl<'a> FindByFn<'a> {
             query_error: opts.query_error(),
             delete: opts.delete,
             any_nested: opts.any_nested(),
+            has_read_pool: opts.read_pool_accessor().is_some(),
             post_hydrate_error: opts.post_hydrate_hook.as_ref().map(|h| &h.error),
             forgettable_table_name: opts.forgettable_table_name(),
+            event_context_column: opts.custom_event_context_column(),
+            events_discriminator_args: opts.es_query_events_discriminator_args(),
             #[cfg(feature = "instrument")]
             repo_name_snake: opts.repo_name_snake_case(),
         }
@@ -49,7 +55,8 @@ impl ToTokens for FindByFn<'_> {
         let query_fn_generics = RepositoryOptions::query_fn_generics(self.any_nested);
         let query_fn_op_arg = RepositoryOptions::query_fn_op_arg(self.any_nested);
         let query_fn_op_traits = RepositoryOptions::query_fn_op_traits(self.any_nested);
-        let query_fn_get_op = RepositoryOptions::query_fn_get_op(self.any_nested);
+        let query_fn_get_op =
+            RepositoryOptions::query_fn_get_op(self.any_nested, self.has_read_pool);
 
         for maybe in ["", "maybe_"] {
             let error = if maybe.is_empty() {
@@ -106,12 +113,20 @@ impl ToTokens for FindByFn<'_> {
                 } else {
                     quote! {}
                 };
+                let events_discriminator_args = &self.events_discriminator_args;
+                let event_context_column_arg = if let Some(column) = self.event_context_column {
+                    quote! { event_context_column = #column, }
+                } else {
+                    quote! {}
+                };
 
                 let es_query_call = if let Some(prefix) = self.prefix {
                     quote! {
                         es_entity::es_query!(
+                            #events_discriminator_args
                             tbl_prefix = #prefix,
                             #forgettable_tbl_arg
+                            #event_context_column_arg
                             #query,
                             #column_name as &#column_type,
                         )
@@ -119,8 +134,10 @@ impl ToTokens for FindByFn<'_> {
                 } else {
                     quote! {
                         es_entity::es_query!(
+                            #events_discriminator_args
                             entity = #entity,
                             #forgettable_tbl_arg
+                            #event_context_column_arg
                             #query,
                             #column_name as &#column_type,
                         )
@@ -147,6 +164,11 @@ impl ToTokens for FindByFn<'_> {
                     } else {
                         quote! {}
                     };
+                    let not_found_id = if self.column.is_id() {
+                        quote! { Some(#column_name.clone()) }
+                    } else {
+                        quote! { None }
+                    };
                     quote! {
                         let __entity = #fetch_optional_call.ok_or_else(|| #error::NotFound {
                             entity: #entity_name_str,
@@ -155,6 +177,7 @@ impl ToTokens for FindByFn<'_> {
                                 use es_entity::ToNotFoundValueFallback;
                                 es_entity::NotFoundValue(#column_name).to_not_found_value()
                             },
+                            id: #not_found_id,
                         })?;
                         #post_hydrate_check
                         Ok(__entity)
@@ -239,6 +262,79 @@ impl ToTokens for FindByFn<'_> {
     }
 }
 
+pub struct ExistsByFn<'a> {
+    column: &'a Column,
+    table_name: &'a str,
+    query_error: syn::Ident,
+    delete: DeleteOption,
+    has_read_pool: bool,
+}
+
+impl<'a> ExistsByFn<'a> {
+    pub fn new(column: &'a Column, opts: &'a RepositoryOptions) -> Self {
+        Self {
+            column,
+            table_name: opts.table_name(),
+            query_error: opts.query_error(),
+            delete: opts.delete,
+            has_read_pool: opts.read_pool_accessor().is_some(),
+        }
+    }
+}
+
+impl ToTokens for ExistsByFn<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let read_pool = RepositoryOptions::read_pool(self.has_read_pool);
+        let column_name = &self.column.name();
+        let (column_type, impl_expr, access_expr) = &self.column.ty_for_find_by();
+        let query_error = &self.query_error;
+
+        let fn_name = syn::Ident::new(&format!("exists_by_{}", column_name), Span::call_site());
+        let fn_in_op = syn::Ident::new(
+            &format!("exists_by_{}_in_op", column_name),
+            Span::call_site(),
+        );
+
+        let filter_op = if self.column.is_optional() {
+            "IS NOT DISTINCT FROM"
+        } else {
+            "="
+        };
+        let query = format!(
+            r#"SELECT EXISTS(SELECT 1 FROM {} WHERE {} {} $1{}) AS "exists!""#,
+            self.table_name,
+            column_name,
+            filter_op,
+            self.delete.not_deleted_condition()
+        );
+
+        tokens.append_all(quote! {
+            pub async fn #fn_name(
+                &self,
+                #column_name: #impl_expr
+            ) -> Result<bool, #query_error> {
+                self.#fn_in_op(#read_pool, #column_name).await
+            }
+
+            pub async fn #fn_in_op<'a, OP>(
+                &self,
+                op: OP,
+                #column_name: #impl_expr
+            ) -> Result<bool, #query_error>
+                where
+                    OP: es_entity::IntoOneTimeExecutor<'a>
+            {
+                let #column_name = #column_name.#access_expr;
+                let row = op
+                    .into_executor()
+                    .fetch_one(sqlx::query!(#query, #column_name as &#column_type))
+                    .await?;
+                Ok(row.exists)
+            }
+        });
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -260,8 +356,11 @@ mod tests {
             query_error: syn::Ident::new("EntityQueryError", Span::call_site()),
             delete: DeleteOption::No,
             any_nested: false,
+            has_read_pool: false,
             post_hydrate_error: None,
             forgettable_table_name: None,
+            event_context_column: None,
+            events_discriminator_args: TokenStream::new(),
             #[cfg(feature = "instrument")]
             repo_name_snake: "test_repo".to_string(),
         };
@@ -299,6 +398,7 @@ mod tests {
                                 use es_entity::ToNotFoundValueFallback;
                                 es_entity::NotFoundValue(id).to_not_found_value()
                             },
+                            id: Some(id.clone()),
                     })?;
                     Ok(__entity)
                 }.await;
@@ -357,8 +457,11 @@ mod tests {
             query_error: syn::Ident::new("EntityQueryError", Span::call_site()),
             delete: DeleteOption::No,
             any_nested: false,
+            has_read_pool: false,
             post_hydrate_error: None,
             forgettable_table_name: None,
+            event_context_column: None,
+            events_discriminator_args: TokenStream::new(),
             #[cfg(feature = "instrument")]
             repo_name_snake: "test_repo".to_string(),
         };
@@ -396,6 +499,7 @@ mod tests {
                                 use es_entity::ToNotFoundValueFallback;
                                 es_entity::NotFoundValue(email).to_not_found_value()
                             },
+                            id: None,
                     })?;
                     Ok(__entity)
                 }.await;
@@ -451,8 +555,11 @@ mod tests {
             query_error: syn::Ident::new("EntityQueryError", Span::call_site()),
             delete: DeleteOption::SoftWithoutQueries,
             any_nested: false,
+            has_read_pool: false,
             post_hydrate_error: None,
             forgettable_table_name: None,
+            event_context_column: None,
+            events_discriminator_args: TokenStream::new(),
             #[cfg(feature = "instrument")]
             repo_name_snake: "test_repo".to_string(),
         };
@@ -490,6 +597,7 @@ mod tests {
                                 use es_entity::ToNotFoundValueFallback;
                                 es_entity::NotFoundValue(id).to_not_found_value()
                             },
+                            id: Some(id.clone()),
                     })?;
                     Ok(__entity)
                 }.await;
@@ -545,8 +653,11 @@ mod tests {
             query_error: syn::Ident::new("EntityQueryError", Span::call_site()),
             delete: DeleteOption::Soft,
             any_nested: false,
+            has_read_pool: false,
             post_hydrate_error: None,
             forgettable_table_name: None,
+            event_context_column: None,
+            events_discriminator_args: TokenStream::new(),
             #[cfg(feature = "instrument")]
             repo_name_snake: "test_repo".to_string(),
         };
@@ -574,8 +685,11 @@ mod tests {
             query_error: syn::Ident::new("EntityQueryError", Span::call_site()),
             delete: DeleteOption::Soft,
             any_nested: true,
+            has_read_pool: false,
             post_hydrate_error: None,
             forgettable_table_name: None,
+            event_context_column: None,
+            events_discriminator_args: TokenStream::new(),
             #[cfg(feature = "instrument")]
             repo_name_snake: "test_repo".to_string(),
         };
@@ -590,6 +704,47 @@ mod tests {
         assert!(token_str.contains("fetch_optional (op)"));
     }
 
+    #[test]
+    fn find_by_fn_optional_column_matches_null() {
+        let column = Column::new(
+            syn::Ident::new("external_ref", proc_macro2::Span::call_site()),
+            syn::parse_str("Option<ExternalRef>").unwrap(),
+        );
+        let entity = Ident::new("Entity", Span::call_site());
+
+        let find_by_fn = FindByFn {
+            prefix: None,
+            column: &column,
+            entity: &entity,
+            table_name: "entities",
+            column_enum: syn::Ident::new("EntityColumn", Span::call_site()),
+            find_error: syn::Ident::new("EntityFindError", Span::call_site()),
+            query_error: syn::Ident::new("EntityQueryError", Span::call_site()),
+            delete: DeleteOption::No,
+            any_nested: false,
+            has_read_pool: false,
+            post_hydrate_error: None,
+            forgettable_table_name: None,
+            event_context_column: None,
+            events_discriminator_args: TokenStream::new(),
+            #[cfg(feature = "instrument")]
+            repo_name_snake: "test_repo".to_string(),
+        };
+
+        let mut tokens = TokenStream::new();
+        find_by_fn.to_tokens(&mut tokens);
+
+        let token_str = tokens.to_string();
+        assert!(
+            token_str
+                .contains("\"SELECT id FROM entities WHERE external_ref IS NOT DISTINCT FROM $1\"")
+        );
+        assert!(token_str.contains(
+            &quote! { external_ref: impl std::borrow::Borrow<Option<ExternalRef> > }.to_string()
+        ));
+        assert!(token_str.contains(&quote! { external_ref as &Option<ExternalRef>, }.to_string()));
+    }
+
     #[test]
     fn find_by_fn_nested() {
         let column = Column::for_id(syn::parse_str("EntityId").unwrap());
@@ -605,8 +760,11 @@ mod tests {
             query_error: syn::Ident::new("EntityQueryError", Span::call_site()),
             delete: DeleteOption::No,
             any_nested: true,
+            has_read_pool: false,
             post_hydrate_error: None,
             forgettable_table_name: None,
+            event_context_column: None,
+            events_discriminator_args: TokenStream::new(),
             #[cfg(feature = "instrument")]
             repo_name_snake: "test_repo".to_string(),
         };
@@ -644,6 +802,7 @@ mod tests {
                                 use es_entity::ToNotFoundValueFallback;
                                 es_entity::NotFoundValue(id).to_not_found_value()
                             },
+                            id: Some(id.clone()),
                     })?;
                     Ok(__entity)
                 }.await;
@@ -683,4 +842,53 @@ mod tests {
 
         assert_eq!(tokens.to_string(), expected.to_string());
     }
+
+    #[test]
+    fn exists_by_fn() {
+        let column = Column::new(
+            syn::Ident::new("email", proc_macro2::Span::call_site()),
+            syn::parse_str("String").unwrap(),
+        );
+
+        let exists_fn = ExistsByFn {
+            column: &column,
+            table_name: "entities",
+            query_error: syn::Ident::new("EntityQueryError", Span::call_site()),
+            delete: DeleteOption::Soft,
+            has_read_pool: false,
+        };
+
+        let mut tokens = TokenStream::new();
+        exists_fn.to_tokens(&mut tokens);
+
+        let expected = quote! {
+            pub async fn exists_by_email(
+                &self,
+                email: impl std::convert::AsRef<str>
+            ) -> Result<bool, EntityQueryError> {
+                self.exists_by_email_in_op(self.pool(), email).await
+            }
+
+            pub async fn exists_by_email_in_op<'a, OP>(
+                &self,
+                op: OP,
+                email: impl std::convert::AsRef<str>
+            ) -> Result<bool, EntityQueryError>
+                where
+                    OP: es_entity::IntoOneTimeExecutor<'a>
+            {
+                let email = email.as_ref();
+                let row = op
+                    .into_executor()
+                    .fetch_one(sqlx::query!(
+                        "SELECT EXISTS(SELECT 1 FROM entities WHERE email = $1 AND deleted = FALSE) AS \"exists!\"",
+                        email as &str
+                    ))
+                    .await?;
+                Ok(row.exists)
+            }
+        };
+
+        assert_eq!(tokens.to_string(), expected.to_string());
+    }
 }
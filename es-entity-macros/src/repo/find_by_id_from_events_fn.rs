@@ -0,0 +1,225 @@
+use darling::ToTokens;
+use proc_macro2::TokenStream;
+use quote::{TokenStreamExt, quote};
+
+use super::options::*;
+
+pub struct FindByIdFromEventsFn<'a> {
+    id: &'a syn::Ident,
+    entity: &'a syn::Ident,
+    events_table_name: &'a str,
+    events_discriminator_condition: String,
+    forgettable_table_name: Option<&'a str>,
+    event_context_column: Option<&'a str>,
+    repo_types_mod: syn::Ident,
+    column_enum: syn::Ident,
+    find_error: syn::Ident,
+    any_nested: bool,
+    has_read_pool: bool,
+    post_hydrate_error: Option<&'a syn::Type>,
+}
+
+impl<'a> From<&'a RepositoryOptions> for FindByIdFromEventsFn<'a> {
+    fn from(opts: &'a RepositoryOptions) -> Self {
+        Self {
+            id: opts.id(),
+            entity: opts.entity(),
+            events_table_name: opts.events_table_name(),
+            events_discriminator_condition: opts
+                .events_discriminator()
+                .map(|d| d.condition(Some("e")))
+                .unwrap_or_default(),
+            forgettable_table_name: opts.forgettable_table_name(),
+            event_context_column: opts.custom_event_context_column(),
+            repo_types_mod: opts.repo_types_mod(),
+            column_enum: opts.column_enum(),
+            find_error: opts.find_error(),
+            any_nested: opts.any_nested(),
+            has_read_pool: opts.read_pool_accessor().is_some(),
+            post_hydrate_error: opts.post_hydrate_hook.as_ref().map(|h| &h.error),
+        }
+    }
+}
+
+impl ToTokens for FindByIdFromEventsFn<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let id = self.id;
+        let entity = self.entity;
+        let repo_types_mod = &self.repo_types_mod;
+        let column_enum = &self.column_enum;
+        let find_error = &self.find_error;
+        let entity_name_str = entity.to_string();
+        let query_fn_generics = RepositoryOptions::query_fn_generics(self.any_nested);
+        let query_fn_op_arg = RepositoryOptions::query_fn_op_arg(self.any_nested);
+        let query_fn_op_traits = RepositoryOptions::query_fn_op_traits(self.any_nested);
+        let query_fn_get_op =
+            RepositoryOptions::query_fn_get_op(self.any_nested, self.has_read_pool);
+
+        let (payload_column, forgettable_join) =
+            if let Some(forgettable_tbl) = self.forgettable_table_name {
+                (
+                    "p.payload as \"forgettable_payload?\"".to_string(),
+                    format!(
+                        " LEFT JOIN {} p ON e.id = p.entity_id AND e.sequence = p.sequence",
+                        forgettable_tbl
+                    ),
+                )
+            } else {
+                (
+                    "NULL::jsonb as \"forgettable_payload?\"".to_string(),
+                    String::new(),
+                )
+            };
+        let query = format!(
+            "SELECT e.id AS \"entity_id: {}\", e.sequence, e.event, CASE WHEN $2 THEN e.{} ELSE NULL::jsonb END as \"context: es_entity::ContextData\", e.recorded_at, {} FROM {} e{} WHERE e.id = $1{} ORDER BY e.sequence",
+            id,
+            self.event_context_column.unwrap_or("context"),
+            payload_column,
+            self.events_table_name,
+            forgettable_join,
+            self.events_discriminator_condition,
+        );
+
+        let executor = if self.any_nested {
+            quote! { op.as_executor() }
+        } else {
+            quote! { op.into_executor() }
+        };
+        let load_nested = if self.any_nested {
+            quote! {
+                let mut __entities = [__entity];
+                <Self as es_entity::EsRepo>::load_all_nested_in_op::<_, #find_error>(op, &mut __entities).await?;
+                let [__entity] = __entities;
+            }
+        } else {
+            quote! {}
+        };
+        let post_hydrate_check = if self.post_hydrate_error.is_some() {
+            quote! {
+                self.execute_post_hydrate_hook(&__entity).map_err(#find_error::PostHydrateError)?;
+            }
+        } else {
+            quote! {}
+        };
+
+        tokens.append_all(quote! {
+            /// Disaster-recovery lookup that hydrates the entity purely from its events.
+            ///
+            /// Unlike `find_by_id` the projection table is not consulted at all so an
+            /// aggregate whose projection row was lost can be recovered (and its
+            /// projection rebuilt). Not intended for regular reads.
+            pub async fn find_by_id_from_events(
+                &self,
+                id: impl std::borrow::Borrow<#id>
+            ) -> Result<#entity, #find_error> {
+                self.find_by_id_from_events_in_op(#query_fn_get_op, id).await
+            }
+
+            pub async fn find_by_id_from_events_in_op #query_fn_generics(
+                &self,
+                #query_fn_op_arg,
+                id: impl std::borrow::Borrow<#id>
+            ) -> Result<#entity, #find_error>
+                where
+                    OP: #query_fn_op_traits
+            {
+                let id = id.borrow();
+                let rows = #executor
+                    .fetch_all(sqlx::query_as!(
+                        #repo_types_mod::Repo__DbEvent,
+                        #query,
+                        id as &#id,
+                        <#repo_types_mod::Repo__Event as es_entity::EsEvent>::event_context(),
+                    ))
+                    .await?;
+                let __entity = es_entity::EntityEvents::load_first::<#entity>(rows)?.ok_or_else(|| #find_error::NotFound {
+                    entity: #entity_name_str,
+                    column: Some(#column_enum::Id),
+                    value: {
+                        use es_entity::ToNotFoundValueFallback;
+                        es_entity::NotFoundValue(id).to_not_found_value()
+                    },
+                    id: Some(id.clone()),
+                })?;
+                #load_nested
+                #post_hydrate_check
+                Ok(__entity)
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proc_macro2::Span;
+    use syn::Ident;
+
+    #[test]
+    fn find_by_id_from_events_fn() {
+        let id = Ident::new("EntityId", Span::call_site());
+        let entity = Ident::new("Entity", Span::call_site());
+        let find_fn = FindByIdFromEventsFn {
+            id: &id,
+            entity: &entity,
+            events_table_name: "entity_events",
+            events_discriminator_condition: String::new(),
+            forgettable_table_name: None,
+            event_context_column: None,
+            repo_types_mod: Ident::new("entity_repo_types", Span::call_site()),
+            column_enum: Ident::new("EntityColumn", Span::call_site()),
+            find_error: Ident::new("EntityFindError", Span::call_site()),
+            any_nested: false,
+            has_read_pool: false,
+            post_hydrate_error: None,
+        };
+
+        let mut tokens = TokenStream::new();
+        find_fn.to_tokens(&mut tokens);
+
+        let expected = quote! {
+            /// Disaster-recovery lookup that hydrates the entity purely from its events.
+            ///
+            /// Unlike `find_by_id` the projection table is not consulted at all so an
+            /// aggregate whose projection row was lost can be recovered (and its
+            /// projection rebuilt). Not intended for regular reads.
+            pub async fn find_by_id_from_events(
+                &self,
+                id: impl std::borrow::Borrow<EntityId>
+            ) -> Result<Entity, EntityFindError> {
+                self.find_by_id_from_events_in_op(self.pool(), id).await
+            }
+
+            pub async fn find_by_id_from_events_in_op<'a, OP>(
+                &self,
+                op: OP,
+                id: impl std::borrow::Borrow<EntityId>
+            ) -> Result<Entity, EntityFindError>
+                where
+                    OP: es_entity::IntoOneTimeExecutor<'a>
+            {
+                let id = id.borrow();
+                let rows = op.into_executor()
+                    .fetch_all(sqlx::query_as!(
+                        entity_repo_types::Repo__DbEvent,
+                        "SELECT e.id AS \"entity_id: EntityId\", e.sequence, e.event, CASE WHEN $2 THEN e.context ELSE NULL::jsonb END as \"context: es_entity::ContextData\", e.recorded_at, NULL::jsonb as \"forgettable_payload?\" FROM entity_events e WHERE e.id = $1 ORDER BY e.sequence",
+                        id as &EntityId,
+                        <entity_repo_types::Repo__Event as es_entity::EsEvent>::event_context(),
+                    ))
+                    .await?;
+                let __entity = es_entity::EntityEvents::load_first::<Entity>(rows)?.ok_or_else(|| EntityFindError::NotFound {
+                    entity: "Entity",
+                    column: Some(EntityColumn::Id),
+                    value: {
+                        use es_entity::ToNotFoundValueFallback;
+                        es_entity::NotFoundValue(id).to_not_found_value()
+                    },
+                    id: Some(id.clone()),
+                })?;
+                Ok(__entity)
+            }
+        };
+
+        assert_eq!(tokens.to_string(), expected.to_string());
+    }
+}
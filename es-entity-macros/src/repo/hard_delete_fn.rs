@@ -0,0 +1,200 @@
+use darling::ToTokens;
+use proc_macro2::TokenStream;
+use quote::{TokenStreamExt, quote};
+
+use super::options::*;
+
+pub struct HardDeleteFn<'a> {
+    id: &'a syn::Ident,
+    entity: &'a syn::Ident,
+    modify_error: syn::Ident,
+    table_name: &'a str,
+    events_table_name: &'a str,
+    events_discriminator_condition: String,
+    forgettable_table_name: Option<&'a str>,
+    #[cfg(feature = "instrument")]
+    repo_name_snake: String,
+}
+
+impl<'a> From<&'a RepositoryOptions> for HardDeleteFn<'a> {
+    fn from(opts: &'a RepositoryOptions) -> Self {
+        Self {
+            id: opts.id(),
+            entity: opts.entity(),
+            modify_error: opts.modify_error(),
+            table_name: opts.table_name(),
+            events_table_name: opts.events_table_name(),
+            events_discriminator_condition: opts
+                .events_discriminator()
+                .map(|d| d.condition(None))
+                .unwrap_or_default(),
+            forgettable_table_name: opts.forgettable_table_name(),
+            #[cfg(feature = "instrument")]
+            repo_name_snake: opts.repo_name_snake_case(),
+        }
+    }
+}
+
+impl ToTokens for HardDeleteFn<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let id_type = self.id;
+        let entity = self.entity;
+        let modify_error = &self.modify_error;
+
+        let events_query = format!(
+            "DELETE FROM {} WHERE id = $1{}",
+            self.events_table_name, self.events_discriminator_condition
+        );
+        let index_query = format!("DELETE FROM {} WHERE id = $1", self.table_name);
+        let delete_payloads = if let Some(forgettable_tbl) = self.forgettable_table_name {
+            let payloads_query = format!("DELETE FROM {} WHERE entity_id = $1", forgettable_tbl);
+            quote! {
+                sqlx::query!(
+                    #payloads_query,
+                    id as &#id_type
+                )
+                .execute(op.as_executor())
+                .await?;
+            }
+        } else {
+            quote! {}
+        };
+
+        #[cfg(feature = "instrument")]
+        let instrument_attr = {
+            let entity_name = entity.to_string();
+            let repo_name = &self.repo_name_snake;
+            let span_name = format!("{}.hard_delete", repo_name);
+            quote! {
+                #[tracing::instrument(name = #span_name, skip_all, fields(entity = #entity_name, id = tracing::field::debug(&entity.id)), err)]
+            }
+        };
+        #[cfg(not(feature = "instrument"))]
+        let instrument_attr = quote! {};
+
+        tokens.append_all(quote! {
+            /// Permanently removes the entity's row and all of its events (eg. for data-subject erasure).
+            ///
+            /// This bypasses the event-sourcing guarantees: the history of the entity is gone and
+            /// can't be replayed or audited afterwards. Fails with `UnpersistedEvents` if the
+            /// entity has new events that were never persisted.
+            pub async fn hard_delete(
+                &self,
+                entity: #entity
+            ) -> Result<(), #modify_error> {
+                let mut op = self.begin_op().await?;
+                self.hard_delete_in_op(&mut op, entity).await?;
+                op.commit().await?;
+                Ok(())
+            }
+
+            #instrument_attr
+            pub async fn hard_delete_in_op<OP>(
+                &self,
+                op: &mut OP,
+                entity: #entity
+            ) -> Result<(), #modify_error>
+            where
+                OP: es_entity::AtomicOperation
+            {
+                if es_entity::EsEntity::events(&entity).any_new() {
+                    return Err(#modify_error::UnpersistedEvents);
+                }
+                let id = &entity.id;
+                #delete_payloads
+                sqlx::query!(
+                    #events_query,
+                    id as &#id_type
+                )
+                .execute(op.as_executor())
+                .await?;
+                sqlx::query!(
+                    #index_query,
+                    id as &#id_type
+                )
+                .execute(op.as_executor())
+                .await?;
+                Ok(())
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proc_macro2::Span;
+    use syn::Ident;
+
+    #[test]
+    fn hard_delete_fn() {
+        let id = Ident::new("EntityId", Span::call_site());
+        let entity = Ident::new("Entity", Span::call_site());
+        let hard_delete_fn = HardDeleteFn {
+            id: &id,
+            entity: &entity,
+            modify_error: Ident::new("EntityModifyError", Span::call_site()),
+            table_name: "entities",
+            events_table_name: "entity_events",
+            events_discriminator_condition: String::new(),
+            forgettable_table_name: Some("entity_forgettable_payloads"),
+            #[cfg(feature = "instrument")]
+            repo_name_snake: "test_repo".to_string(),
+        };
+
+        let mut tokens = TokenStream::new();
+        hard_delete_fn.to_tokens(&mut tokens);
+
+        let expected = quote! {
+            /// Permanently removes the entity's row and all of its events (eg. for data-subject erasure).
+            ///
+            /// This bypasses the event-sourcing guarantees: the history of the entity is gone and
+            /// can't be replayed or audited afterwards. Fails with `UnpersistedEvents` if the
+            /// entity has new events that were never persisted.
+            pub async fn hard_delete(
+                &self,
+                entity: Entity
+            ) -> Result<(), EntityModifyError> {
+                let mut op = self.begin_op().await?;
+                self.hard_delete_in_op(&mut op, entity).await?;
+                op.commit().await?;
+                Ok(())
+            }
+
+            pub async fn hard_delete_in_op<OP>(
+                &self,
+                op: &mut OP,
+                entity: Entity
+            ) -> Result<(), EntityModifyError>
+            where
+                OP: es_entity::AtomicOperation
+            {
+                if es_entity::EsEntity::events(&entity).any_new() {
+                    return Err(EntityModifyError::UnpersistedEvents);
+                }
+                let id = &entity.id;
+                sqlx::query!(
+                    "DELETE FROM entity_forgettable_payloads WHERE entity_id = $1",
+                    id as &EntityId
+                )
+                .execute(op.as_executor())
+                .await?;
+                sqlx::query!(
+                    "DELETE FROM entity_events WHERE id = $1",
+                    id as &EntityId
+                )
+                .execute(op.as_executor())
+                .await?;
+                sqlx::query!(
+                    "DELETE FROM entities WHERE id = $1",
+                    id as &EntityId
+                )
+                .execute(op.as_executor())
+                .await?;
+                Ok(())
+            }
+        };
+
+        assert_eq!(tokens.to_string(), expected.to_string());
+    }
+}
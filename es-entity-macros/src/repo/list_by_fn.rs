@@ -35,6 +35,13 @@ impl CursorStruct<'_> {
         }
         if self.column.is_id() {
             format!("{for_column_str}id")
+        } else if let Some(then) = self.column.list_by_then() {
+            let then_str = if for_column == Some(then.name()) {
+                String::new()
+            } else {
+                format!("{}, ", then.name())
+            };
+            format!("{}{}, {}id", for_column_str, self.column.name(), then_str)
         } else {
             format!("{}{}, id", for_column_str, self.column.name())
         }
@@ -47,6 +54,12 @@ impl CursorStruct<'_> {
             format!("id {dir}")
         } else if self.column.is_nullable_column() {
             format!("{0} {dir} NULLS {nulls}, id {dir}", self.column.name())
+        } else if let Some(then) = self.column.list_by_then() {
+            format!(
+                "{} {dir}, {} {dir}, id {dir}",
+                self.column.name(),
+                then.name()
+            )
         } else {
             format!("{} {dir}, id {dir}", self.column.name())
         }
@@ -56,6 +69,7 @@ impl CursorStruct<'_> {
         let comp = if ascending { ">" } else { "<" };
         let id_offset = offset + 2;
         let column_offset = offset + 3;
+        let then_offset = offset + 4;
 
         if self.column.is_id() {
             format!("COALESCE(id {comp} ${id_offset}, true)")
@@ -101,6 +115,12 @@ impl CursorStruct<'_> {
                 "({0} IS NOT DISTINCT FROM ${column_offset}) AND COALESCE(id {comp} ${id_offset}, true) OR COALESCE({0} {comp} ${column_offset}, {null_handling})",
                 self.column.name(),
             )
+        } else if let Some(then) = self.column.list_by_then() {
+            format!(
+                "COALESCE(({0}, {1}, id) {comp} (${column_offset}, ${then_offset}, ${id_offset}), ${id_offset} IS NULL)",
+                self.column.name(),
+                then.name(),
+            )
         } else {
             format!(
                 "COALESCE(({0}, id) {comp} (${column_offset}, ${id_offset}), ${id_offset} IS NULL)",
@@ -125,6 +145,17 @@ impl CursorStruct<'_> {
                 id as Option<#id>,
                 #column_name as #column_type,
             }
+        } else if let Some(then) = self.column.list_by_then() {
+            let column_name = self.column.name();
+            let column_type = self.column.ty();
+            let then_name = then.name();
+            let then_type = then.ty();
+            quote! {
+                (first + 1) as i64,
+                id as Option<#id>,
+                #column_name as Option<#column_type>,
+                #then_name as Option<#then_type>,
+            }
         } else {
             let column_name = self.column.name();
             let column_type = self.column.ty();
@@ -163,6 +194,17 @@ impl CursorStruct<'_> {
             after_destruction = quote! {
                 (Some(after.id), after.#column_name)
             };
+        } else if let Some(then) = self.column.list_by_then() {
+            let then_name = then.name();
+            after_args = quote! {
+                (id, #column_name, #then_name)
+            };
+            after_destruction = quote! {
+                (Some(after.id), Some(after.#column_name), Some(after.#then_name))
+            };
+            after_default = quote! {
+                (None, None, None)
+            };
         }
 
         quote! {
@@ -183,18 +225,11 @@ impl CursorStruct<'_> {
                 type Error = String;
 
                 fn encode_cursor(&self) -> String {
-                    use es_entity::graphql::base64::{engine::general_purpose, Engine as _};
-                    let json = es_entity::prelude::serde_json::to_string(&self).expect("could not serialize token");
-                    general_purpose::STANDARD_NO_PAD.encode(json.as_bytes())
+                    es_entity::graphql::encode_cursor(self)
                 }
 
                 fn decode_cursor(s: &str) -> Result<Self, Self::Error> {
-                    use es_entity::graphql::base64::{engine::general_purpose, Engine as _};
-                    let bytes = general_purpose::STANDARD_NO_PAD
-                        .decode(s.as_bytes())
-                        .map_err(|e| e.to_string())?;
-                    let json = String::from_utf8(bytes).map_err(|e| e.to_string())?;
-                    es_entity::prelude::serde_json::from_str(&json).map_err(|e| e.to_string())
+                    es_entity::graphql::decode_cursor(s)
                 }
             }
         }
@@ -208,7 +243,7 @@ impl ToTokens for CursorStruct<'_> {
         let ident = self.ident();
         let id = &self.id;
 
-        let (field, from_impl) = if self.column.is_id() {
+        let (mut field, mut from_impl) = if self.column.is_id() {
             (quote! {}, quote! {})
         } else {
             let column_name = self.column.name();
@@ -222,6 +257,17 @@ impl ToTokens for CursorStruct<'_> {
                 },
             )
         };
+        if let Some(then) = self.column.list_by_then() {
+            let then_name = then.name();
+            let then_type = then.ty();
+            let then_accessor = then.accessor();
+            field.append_all(quote! {
+                pub #then_name: #then_type,
+            });
+            from_impl.append_all(quote! {
+                #then_name: entity.#then_accessor.clone(),
+            });
+        }
 
         tokens.append_all(quote! {
             #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -252,8 +298,12 @@ pub struct ListByFn<'a> {
     delete: DeleteOption,
     cursor_mod: syn::Ident,
     any_nested: bool,
+    has_read_pool: bool,
     post_hydrate_error: Option<&'a syn::Type>,
     forgettable_table_name: Option<&'a str>,
+    event_context_column: Option<&'a str>,
+    events_discriminator_args: TokenStream,
+    page_probe: bool,
     #[cfg(feature = "instrument")]
     repo_name_snake: String,
 }
@@ -270,13 +320,21 @@ impl<'a> ListByFn<'a> {
             delete: opts.delete,
             cursor_mod: opts.cursor_mod(),
             any_nested: opts.any_nested(),
+            has_read_pool: opts.read_pool_accessor().is_some(),
             post_hydrate_error: opts.post_hydrate_hook.as_ref().map(|h| &h.error),
             forgettable_table_name: opts.forgettable_table_name(),
+            event_context_column: opts.custom_event_context_column(),
+            events_discriminator_args: opts.es_query_events_discriminator_args(),
+            page_probe: opts.page_probe_enabled(),
             #[cfg(feature = "instrument")]
             repo_name_snake: opts.repo_name_snake_case(),
         }
     }
 
+    pub fn is_by_id(&self) -> bool {
+        self.column.is_id()
+    }
+
     pub fn cursor(&'a self) -> CursorStruct<'a> {
         CursorStruct {
             column: self.column,
@@ -298,7 +356,8 @@ impl ToTokens for ListByFn<'_> {
         let query_fn_generics = RepositoryOptions::query_fn_generics(self.any_nested);
         let query_fn_op_arg = RepositoryOptions::query_fn_op_arg(self.any_nested);
         let query_fn_op_traits = RepositoryOptions::query_fn_op_traits(self.any_nested);
-        let query_fn_get_op = RepositoryOptions::query_fn_get_op(self.any_nested);
+        let query_fn_get_op =
+            RepositoryOptions::query_fn_get_op(self.any_nested, self.has_read_pool);
 
         let destructure_tokens = self.cursor().destructure_tokens();
         let select_columns = cursor.select_columns(None);
@@ -352,12 +411,23 @@ impl ToTokens for ListByFn<'_> {
             } else {
                 quote! {}
             };
+            let events_discriminator_args = &self.events_discriminator_args;
+            let probe_limit_arg =
+                RepositoryOptions::es_query_probe_limit_arg(self.page_probe, "$1");
+            let event_context_column_arg = if let Some(column) = self.event_context_column {
+                quote! { event_context_column = #column, }
+            } else {
+                quote! {}
+            };
 
             let es_query_asc_call = if let Some(prefix) = self.ignore_prefix {
                 quote! {
                     es_entity::es_query!(
+                        #events_discriminator_args
+                        #probe_limit_arg
                         tbl_prefix = #prefix,
                         #forgettable_tbl_arg
+                        #event_context_column_arg
                         #asc_query,
                         #arg_tokens
                     )
@@ -365,8 +435,11 @@ impl ToTokens for ListByFn<'_> {
             } else {
                 quote! {
                     es_entity::es_query!(
+                        #events_discriminator_args
+                        #probe_limit_arg
                         entity = #entity,
                         #forgettable_tbl_arg
+                        #event_context_column_arg
                         #asc_query,
                         #arg_tokens
                     )
@@ -376,8 +449,11 @@ impl ToTokens for ListByFn<'_> {
             let es_query_desc_call = if let Some(prefix) = self.ignore_prefix {
                 quote! {
                     es_entity::es_query!(
+                        #events_discriminator_args
+                        #probe_limit_arg
                         tbl_prefix = #prefix,
                         #forgettable_tbl_arg
+                        #event_context_column_arg
                         #desc_query,
                         #arg_tokens
                     )
@@ -385,8 +461,11 @@ impl ToTokens for ListByFn<'_> {
             } else {
                 quote! {
                     es_entity::es_query!(
+                        #events_discriminator_args
+                        #probe_limit_arg
                         entity = #entity,
                         #forgettable_tbl_arg
+                        #event_context_column_arg
                         #desc_query,
                         #arg_tokens
                     )
@@ -587,6 +666,77 @@ mod tests {
         assert_eq!(tokens.to_string(), expected.to_string());
     }
 
+    #[test]
+    fn cursor_struct_with_then_column() {
+        let id_type = Ident::new("EntityId", Span::call_site());
+        let entity = Ident::new("Entity", Span::call_site());
+        let cursor_mod = Ident::new("cursor_mod", Span::call_site());
+        let mut columns: Columns = darling::FromMeta::from_list(
+            &darling::ast::NestedMeta::parse_meta_list(quote! {
+                rank(ty = "i32", list_by(then = name)),
+                name = "String",
+            })
+            .unwrap(),
+        )
+        .unwrap();
+        columns.set_id_column(&id_type);
+        let by_column = columns.all_list_by().find(|c| c.name() == "rank").unwrap();
+
+        let cursor = CursorStruct {
+            column: by_column,
+            id: &id_type,
+            entity: &entity,
+            cursor_mod: &cursor_mod,
+        };
+
+        assert_eq!(cursor.select_columns(None), "rank, name, id");
+        assert_eq!(
+            cursor.select_columns(Some(&Ident::new("name", Span::call_site()))),
+            "name, rank, id"
+        );
+        assert_eq!(cursor.order_by(false), "rank DESC, name DESC, id DESC");
+        assert_eq!(
+            cursor.condition(1, true),
+            "COALESCE((rank, name, id) > ($4, $5, $3), $3 IS NULL)"
+        );
+        assert_eq!(
+            cursor.destructure_tokens().to_string(),
+            quote! {
+                let es_entity::PaginatedQueryArgs { first, after } = cursor;
+                let (id, rank, name) = if let Some(after) = after {
+                    (Some(after.id), Some(after.rank), Some(after.name))
+                } else {
+                    (None, None, None)
+                };
+            }
+            .to_string()
+        );
+
+        let mut tokens = TokenStream::new();
+        cursor.to_tokens(&mut tokens);
+
+        let expected = quote! {
+            #[derive(Debug, serde::Serialize, serde::Deserialize)]
+            pub struct EntityByRankCursor {
+                pub id: EntityId,
+                pub rank: i32,
+                pub name: String,
+            }
+
+            impl From<&Entity> for EntityByRankCursor {
+                fn from(entity: &Entity) -> Self {
+                    Self {
+                        id: entity.id.clone(),
+                        rank: entity.rank.clone(),
+                        name: entity.name.clone(),
+                    }
+                }
+            }
+        };
+
+        assert_eq!(tokens.to_string(), expected.to_string());
+    }
+
     #[test]
     fn list_by_fn() {
         let id_type = Ident::new("EntityId", Span::call_site());
@@ -605,8 +755,12 @@ mod tests {
             delete: DeleteOption::SoftWithoutQueries,
             cursor_mod,
             any_nested: false,
+            has_read_pool: false,
             post_hydrate_error: None,
             forgettable_table_name: None,
+            event_context_column: None,
+            events_discriminator_args: TokenStream::new(),
+            page_probe: false,
             #[cfg(feature = "instrument")]
             repo_name_snake: "test_repo".to_string(),
         };
@@ -696,8 +850,12 @@ mod tests {
             delete: DeleteOption::Soft,
             cursor_mod,
             any_nested: false,
+            has_read_pool: false,
             post_hydrate_error: None,
             forgettable_table_name: None,
+            event_context_column: None,
+            events_discriminator_args: TokenStream::new(),
+            page_probe: false,
             #[cfg(feature = "instrument")]
             repo_name_snake: "test_repo".to_string(),
         };
@@ -730,8 +888,12 @@ mod tests {
             delete: DeleteOption::No,
             cursor_mod,
             any_nested: false,
+            has_read_pool: false,
             post_hydrate_error: None,
             forgettable_table_name: None,
+            event_context_column: None,
+            events_discriminator_args: TokenStream::new(),
+            page_probe: false,
             #[cfg(feature = "instrument")]
             repo_name_snake: "test_repo".to_string(),
         };
@@ -827,8 +989,12 @@ mod tests {
             delete: DeleteOption::No,
             cursor_mod,
             any_nested: false,
+            has_read_pool: false,
             post_hydrate_error: None,
             forgettable_table_name: None,
+            event_context_column: None,
+            events_discriminator_args: TokenStream::new(),
+            page_probe: false,
             #[cfg(feature = "instrument")]
             repo_name_snake: "test_repo".to_string(),
         };
@@ -936,8 +1102,12 @@ mod tests {
             delete: DeleteOption::No,
             cursor_mod,
             any_nested: false,
+            has_read_pool: false,
             post_hydrate_error: None,
             forgettable_table_name: None,
+            event_context_column: None,
+            events_discriminator_args: TokenStream::new(),
+            page_probe: false,
             #[cfg(feature = "instrument")]
             repo_name_snake: "test_repo".to_string(),
         };
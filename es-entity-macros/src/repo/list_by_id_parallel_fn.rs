@@ -0,0 +1,197 @@
+use darling::ToTokens;
+use proc_macro2::{Span, TokenStream};
+use quote::{TokenStreamExt, quote};
+
+use super::options::*;
+
+pub struct ListByIdParallelFn<'a> {
+    id: &'a syn::Ident,
+    entity: &'a syn::Ident,
+    table_name: &'a str,
+    query_error: syn::Ident,
+    delete: DeleteOption,
+    cursor_mod: syn::Ident,
+    has_read_pool: bool,
+}
+
+impl<'a> From<&'a RepositoryOptions> for ListByIdParallelFn<'a> {
+    fn from(opts: &'a RepositoryOptions) -> Self {
+        Self {
+            id: opts.id(),
+            entity: opts.entity(),
+            table_name: opts.table_name(),
+            query_error: opts.query_error(),
+            delete: opts.delete,
+            cursor_mod: opts.cursor_mod(),
+            has_read_pool: opts.read_pool_accessor().is_some(),
+        }
+    }
+}
+
+impl ToTokens for ListByIdParallelFn<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let id = self.id;
+        let entity = self.entity;
+        let query_error = &self.query_error;
+        let cursor_mod = &self.cursor_mod;
+        let read_pool = RepositoryOptions::read_pool(self.has_read_pool);
+        let cursor_ident = syn::Ident::new(&format!("{entity}ByIdCursor"), Span::call_site());
+
+        let query = |comp: &str, dir: &str| {
+            format!(
+                r#"SELECT id as "id: {}" FROM {} WHERE (COALESCE(id {} $2, true)){} ORDER BY id {} LIMIT $1"#,
+                id,
+                self.table_name,
+                comp,
+                self.delete.not_deleted_condition(),
+                dir
+            )
+        };
+        let asc_query = query(">", "ASC");
+        let desc_query = query("<", "DESC");
+
+        tokens.append_all(quote! {
+            /// Like `list_by_id` but hydrates the page in sub-batches of `batch_size`
+            /// entities that are loaded concurrently.
+            ///
+            /// The ids of the page are queried first, then every batch is loaded via
+            /// `find_all` on its own connection from the pool.
+            pub async fn list_by_id_parallel(
+                &self,
+                cursor: es_entity::PaginatedQueryArgs<#cursor_mod::#cursor_ident>,
+                direction: es_entity::ListDirection,
+                batch_size: usize,
+            ) -> Result<es_entity::PaginatedQueryRet<#entity, #cursor_mod::#cursor_ident>, #query_error> {
+                let es_entity::PaginatedQueryArgs { first, after } = cursor;
+                let id = after.map(|after| after.id);
+
+                let rows = match direction {
+                    es_entity::ListDirection::Ascending => {
+                        sqlx::query!(#asc_query, (first + 1) as i64, id as Option<#id>)
+                            .fetch_all(#read_pool)
+                            .await?
+                            .into_iter()
+                            .map(|row| row.id)
+                            .collect::<Vec<_>>()
+                    }
+                    es_entity::ListDirection::Descending => {
+                        sqlx::query!(#desc_query, (first + 1) as i64, id as Option<#id>)
+                            .fetch_all(#read_pool)
+                            .await?
+                            .into_iter()
+                            .map(|row| row.id)
+                            .collect::<Vec<_>>()
+                    }
+                };
+                let has_next_page = rows.len() > first;
+                let ids = &rows[..rows.len().min(first)];
+
+                let batches = es_entity::prelude::futures_util::future::try_join_all(
+                    ids.chunks(batch_size.max(1)).map(|batch| self.find_all::<#entity>(batch)),
+                )
+                .await?;
+                let mut loaded: std::collections::HashMap<#id, #entity> =
+                    batches.into_iter().flatten().collect();
+                let entities: Vec<#entity> =
+                    ids.iter().filter_map(|id| loaded.remove(id)).collect();
+
+                let end_cursor = entities.last().map(#cursor_mod::#cursor_ident::from);
+
+                Ok(es_entity::PaginatedQueryRet {
+                    entities,
+                    has_next_page,
+                    end_cursor,
+                })
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::Ident;
+
+    #[test]
+    fn list_by_id_parallel_fn() {
+        let id = Ident::new("EntityId", Span::call_site());
+        let entity = Ident::new("Entity", Span::call_site());
+        let list_fn = ListByIdParallelFn {
+            id: &id,
+            entity: &entity,
+            table_name: "entities",
+            query_error: Ident::new("EntityQueryError", Span::call_site()),
+            delete: DeleteOption::Soft,
+            cursor_mod: Ident::new("cursor_mod", Span::call_site()),
+            has_read_pool: false,
+        };
+
+        let mut tokens = TokenStream::new();
+        list_fn.to_tokens(&mut tokens);
+
+        let expected = quote! {
+            /// Like `list_by_id` but hydrates the page in sub-batches of `batch_size`
+            /// entities that are loaded concurrently.
+            ///
+            /// The ids of the page are queried first, then every batch is loaded via
+            /// `find_all` on its own connection from the pool.
+            pub async fn list_by_id_parallel(
+                &self,
+                cursor: es_entity::PaginatedQueryArgs<cursor_mod::EntityByIdCursor>,
+                direction: es_entity::ListDirection,
+                batch_size: usize,
+            ) -> Result<es_entity::PaginatedQueryRet<Entity, cursor_mod::EntityByIdCursor>, EntityQueryError> {
+                let es_entity::PaginatedQueryArgs { first, after } = cursor;
+                let id = after.map(|after| after.id);
+
+                let rows = match direction {
+                    es_entity::ListDirection::Ascending => {
+                        sqlx::query!(
+                            "SELECT id as \"id: EntityId\" FROM entities WHERE (COALESCE(id > $2, true)) AND deleted = FALSE ORDER BY id ASC LIMIT $1",
+                            (first + 1) as i64,
+                            id as Option<EntityId>
+                        )
+                        .fetch_all(self.pool())
+                        .await?
+                        .into_iter()
+                        .map(|row| row.id)
+                        .collect::<Vec<_>>()
+                    }
+                    es_entity::ListDirection::Descending => {
+                        sqlx::query!(
+                            "SELECT id as \"id: EntityId\" FROM entities WHERE (COALESCE(id < $2, true)) AND deleted = FALSE ORDER BY id DESC LIMIT $1",
+                            (first + 1) as i64,
+                            id as Option<EntityId>
+                        )
+                        .fetch_all(self.pool())
+                        .await?
+                        .into_iter()
+                        .map(|row| row.id)
+                        .collect::<Vec<_>>()
+                    }
+                };
+                let has_next_page = rows.len() > first;
+                let ids = &rows[..rows.len().min(first)];
+
+                let batches = es_entity::prelude::futures_util::future::try_join_all(
+                    ids.chunks(batch_size.max(1)).map(|batch| self.find_all::<Entity>(batch)),
+                )
+                .await?;
+                let mut loaded: std::collections::HashMap<EntityId, Entity> =
+                    batches.into_iter().flatten().collect();
+                let entities: Vec<Entity> =
+                    ids.iter().filter_map(|id| loaded.remove(id)).collect();
+
+                let end_cursor = entities.last().map(cursor_mod::EntityByIdCursor::from);
+
+                Ok(es_entity::PaginatedQueryRet {
+                    entities,
+                    has_next_page,
+                    end_cursor,
+                })
+            }
+        };
+
+        assert_eq!(tokens.to_string(), expected.to_string());
+    }
+}
@@ -8,6 +8,9 @@ use super::{combo_cursor::ComboCursor, list_by_fn::CursorStruct, options::*};
 pub struct FiltersStruct<'a> {
     columns: Vec<&'a Column>,
     entity: &'a syn::Ident,
+    table_name: &'a str,
+    events_table_name: &'a str,
+    events_discriminator: Option<&'a EventsDiscriminator>,
 }
 
 impl<'a> FiltersStruct<'a> {
@@ -15,12 +18,21 @@ impl<'a> FiltersStruct<'a> {
         Self {
             entity: opts.entity(),
             columns,
+            table_name: opts.table_name(),
+            events_table_name: opts.events_table_name(),
+            events_discriminator: opts.events_discriminator(),
         }
     }
 
     #[cfg(test)]
     fn new_test(entity: &'a syn::Ident, columns: Vec<&'a Column>) -> Self {
-        Self { entity, columns }
+        Self {
+            entity,
+            columns,
+            table_name: "entities",
+            events_table_name: "entity_events",
+            events_discriminator: None,
+        }
     }
 
     pub fn ident(&self) -> syn::Ident {
@@ -31,22 +43,93 @@ impl<'a> FiltersStruct<'a> {
         )
     }
 
+    fn range_idents(column: &Column) -> (syn::Ident, syn::Ident) {
+        let name = column.name();
+        (
+            syn::Ident::new(&format!("{name}_from"), Span::call_site()),
+            syn::Ident::new(&format!("{name}_to"), Span::call_site()),
+        )
+    }
+
     fn fields(&self) -> TokenStream {
         self.columns
             .iter()
             .map(|column| {
                 let name = column.name();
                 let ty = column.ty();
+                if column.is_list_for_range() {
+                    let (from, to) = Self::range_idents(column);
+                    quote! {
+                        pub #from: Option<#ty>,
+                        pub #to: Option<#ty>,
+                    }
+                } else {
+                    quote! {
+                        pub #name: Option<#ty>,
+                    }
+                }
+            })
+            .collect()
+    }
+
+    /// Tokens evaluating to `true` when the filter for `column` is not set.
+    fn is_unset(column: &Column) -> TokenStream {
+        if column.is_list_for_range() {
+            let (from, to) = Self::range_idents(column);
+            quote! { (filters.#from.is_none() && filters.#to.is_none()) }
+        } else {
+            let name = column.name();
+            quote! { filters.#name.is_none() }
+        }
+    }
+
+    /// Checks emitted into `validate`. Equality filters can not contradict each
+    /// other so only range filters need checking.
+    fn validation_checks(&self) -> TokenStream {
+        self.columns
+            .iter()
+            .filter(|column| column.is_list_for_range())
+            .map(|column| {
+                let name = column.name().to_string();
+                let (from, to) = Self::range_idents(column);
                 quote! {
-                    pub #name: Option<#ty>,
+                    if let (Some(from), Some(to)) = (&self.#from, &self.#to) {
+                        if from > to {
+                            return Err(es_entity::FilterError::EmptyRange(#name));
+                        }
+                    }
                 }
             })
             .collect()
     }
 
-    fn where_clause_fragment(column: &Column, param_idx: &mut u32) -> String {
+    fn where_clause_fragment(&self, column: &Column, param_idx: &mut u32) -> String {
         let col_name = column.name();
-        if column.is_optional() {
+        if let Some(keys) = column.list_for_json_path() {
+            // Compares against the most recent event carrying the path so that
+            // entities without it (NULL) only match when the filter is unset.
+            let param = format!("${}", *param_idx);
+            *param_idx += 1;
+            let path = format!("'{{{}}}'", keys.join(","));
+            // No `ORDER BY` in the sub query as `es_query!` takes the first one as the page order.
+            let discriminator = self
+                .events_discriminator
+                .map(|d| d.condition(Some("ev")))
+                .unwrap_or_default();
+            format!(
+                "COALESCE((SELECT ev.event #> {path} FROM {events} ev WHERE ev.id = {table}.id{discriminator} AND ev.sequence = (SELECT MAX(ev.sequence) FROM {events} ev WHERE ev.id = {table}.id{discriminator} AND ev.event #> {path} IS NOT NULL)) = {param}, {param} IS NULL)",
+                events = self.events_table_name,
+                table = self.table_name,
+            )
+        } else if column.is_list_for_range() {
+            let from_param = format!("${}", *param_idx);
+            *param_idx += 1;
+            let to_param = format!("${}", *param_idx);
+            *param_idx += 1;
+            format!(
+                "COALESCE({col_name} >= {from_param}, {from_param} IS NULL) AND COALESCE({col_name} <= {to_param}, {to_param} IS NULL)"
+            )
+        } else if column.is_optional() {
             let apply_param = format!("${}", *param_idx);
             *param_idx += 1;
             let val_param = format!("${}", *param_idx);
@@ -63,7 +146,19 @@ impl<'a> FiltersStruct<'a> {
         let col_name = column.name();
         let filter_name = syn::Ident::new(&format!("filter_{}", col_name), Span::call_site());
         let ty = column.ty();
-        if column.is_optional() {
+        if column.list_for_json_path().is_some() {
+            quote! {
+                #filter_name as Option<es_entity::prelude::serde_json::Value>,
+            }
+        } else if column.is_list_for_range() {
+            let filter_from =
+                syn::Ident::new(&format!("filter_{}_from", col_name), Span::call_site());
+            let filter_to = syn::Ident::new(&format!("filter_{}_to", col_name), Span::call_site());
+            quote! {
+                #filter_from as Option<#ty>,
+                #filter_to as Option<#ty>,
+            }
+        } else if column.is_optional() {
             let apply_name = syn::Ident::new(&format!("apply_{}", col_name), Span::call_site());
             quote! {
                 #apply_name as bool,
@@ -88,11 +183,21 @@ impl ToTokens for FiltersStruct<'_> {
         let ident = self.ident();
         let fields = self.fields();
 
+        let checks = self.validation_checks();
         tokens.append_all(quote! {
             #[derive(Debug, Default)]
             pub struct #ident {
                 #fields
             }
+
+            impl #ident {
+                /// Rejects filter combinations that can never match (eg. an empty range)
+                /// without hitting the database. Called by `list_for_filters`.
+                pub fn validate(&self) -> Result<(), es_entity::FilterError> {
+                    #checks
+                    Ok(())
+                }
+            }
         });
     }
 }
@@ -110,8 +215,12 @@ pub struct ListForFiltersFn<'a> {
     ignore_prefix: Option<&'a syn::LitStr>,
     id: &'a syn::Ident,
     any_nested: bool,
+    has_read_pool: bool,
     post_hydrate_error: Option<&'a syn::Type>,
     forgettable_table_name: Option<&'a str>,
+    event_context_column: Option<&'a str>,
+    events_discriminator_args: TokenStream,
+    page_probe: bool,
     #[cfg(feature = "instrument")]
     repo_name_snake: String,
 }
@@ -136,8 +245,12 @@ impl<'a> ListForFiltersFn<'a> {
             ignore_prefix: opts.table_prefix(),
             id: opts.id(),
             any_nested: opts.any_nested(),
+            has_read_pool: opts.read_pool_accessor().is_some(),
             post_hydrate_error: opts.post_hydrate_hook.as_ref().map(|h| &h.error),
             forgettable_table_name: opts.forgettable_table_name(),
+            event_context_column: opts.custom_event_context_column(),
+            events_discriminator_args: opts.es_query_events_discriminator_args(),
+            page_probe: opts.page_probe_enabled(),
             #[cfg(feature = "instrument")]
             repo_name_snake: opts.repo_name_snake_case(),
         }
@@ -159,10 +272,7 @@ impl<'a> ListForFiltersFn<'a> {
         let all_none_checks: Vec<_> = self
             .for_columns
             .iter()
-            .map(|c| {
-                let name = c.name();
-                quote! { filters.#name.is_none() }
-            })
+            .map(|c| FiltersStruct::is_unset(c))
             .collect();
 
         // Determine which for_columns have individual methods for this by_col.
@@ -179,10 +289,7 @@ impl<'a> ListForFiltersFn<'a> {
                     .for_columns
                     .iter()
                     .filter(|c| c.name() != for_col.name())
-                    .map(|c| {
-                        let name = c.name();
-                        quote! { filters.#name.is_none() }
-                    })
+                    .map(|c| FiltersStruct::is_unset(c))
                     .collect();
 
                 let for_col_name = for_col.name();
@@ -239,6 +346,57 @@ impl<'a> ListForFiltersFn<'a> {
         }
     }
 
+    /// Body of `list_for_filters_by_*_in_op` when no filters have to be combined.
+    ///
+    /// Without filters (or with a single filter that has its own `list_for_*_by_*`)
+    /// the COALESCE query would just duplicate a query that is already generated -
+    /// forwarding instead saves two compile-time checked `es_query!`s per sort column.
+    /// Range and optional columns keep the COALESCE query as their filters don't map
+    /// 1:1 onto the argument of `list_for_*_by_*`.
+    fn generate_forwarding_body(
+        &self,
+        by_col: &Column,
+        delete: DeleteOption,
+    ) -> Option<TokenStream> {
+        let by_col_name = by_col.name();
+        let delete_postfix = delete.include_deletion_fn_postfix();
+        let list_by_fn_in_op = syn::Ident::new(
+            &format!("list_by_{}{}_in_op", by_col_name, delete_postfix),
+            Span::call_site(),
+        );
+
+        match self.for_columns.as_slice() {
+            [] => Some(quote! {
+                let _ = filters;
+                self.#list_by_fn_in_op(op, cursor, direction).await
+            }),
+            [for_col]
+                if !for_col.is_list_for_range()
+                    && !for_col.is_optional()
+                    && for_col
+                        .list_for_by_columns()
+                        .iter()
+                        .any(|n| n == by_col_name) =>
+            {
+                let for_col_name = for_col.name();
+                let list_for_fn_in_op = syn::Ident::new(
+                    &format!(
+                        "list_for_{}_by_{}{}_in_op",
+                        for_col_name, by_col_name, delete_postfix
+                    ),
+                    Span::call_site(),
+                );
+                Some(quote! {
+                    match filters.#for_col_name {
+                        Some(#for_col_name) => self.#list_for_fn_in_op(op, #for_col_name, cursor, direction).await,
+                        None => self.#list_by_fn_in_op(op, cursor, direction).await,
+                    }
+                })
+            }
+            _ => None,
+        }
+    }
+
     fn generate_by_fn(&self, by_column: &'a Column, delete: DeleteOption) -> TokenStream {
         let entity = self.entity;
         let error = &self.query_error;
@@ -246,7 +404,8 @@ impl<'a> ListForFiltersFn<'a> {
         let query_fn_generics = RepositoryOptions::query_fn_generics(self.any_nested);
         let query_fn_op_arg = RepositoryOptions::query_fn_op_arg(self.any_nested);
         let query_fn_op_traits = RepositoryOptions::query_fn_op_traits(self.any_nested);
-        let query_fn_get_op = RepositoryOptions::query_fn_get_op(self.any_nested);
+        let query_fn_get_op =
+            RepositoryOptions::query_fn_get_op(self.any_nested, self.has_read_pool);
 
         let by_column_name = by_column.name();
         let cursor_struct = CursorStruct {
@@ -257,16 +416,6 @@ impl<'a> ListForFiltersFn<'a> {
         };
         let cursor_ident = cursor_struct.ident();
 
-        let n_filters: u32 = self
-            .for_columns
-            .iter()
-            .map(|c| if c.is_optional() { 2u32 } else { 1u32 })
-            .sum();
-
-        let destructure_tokens = cursor_struct.destructure_tokens();
-        let select_columns = cursor_struct.select_columns(None);
-        let cursor_arg_tokens = cursor_struct.query_arg_tokens();
-
         let fn_name = syn::Ident::new(
             &format!(
                 "list_for_filters_by_{}{}",
@@ -286,6 +435,48 @@ impl<'a> ListForFiltersFn<'a> {
 
         let filters_ident = self.filters_struct.ident();
 
+        if let Some(body) = self.generate_forwarding_body(by_column, delete) {
+            return quote! {
+                pub async fn #fn_name(
+                    &self,
+                    filters: #filters_ident,
+                    cursor: es_entity::PaginatedQueryArgs<#cursor_mod::#cursor_ident>,
+                    direction: es_entity::ListDirection,
+                ) -> Result<es_entity::PaginatedQueryRet<#entity, #cursor_mod::#cursor_ident>, #error> {
+                    self.#fn_in_op(#query_fn_get_op, filters, cursor, direction).await
+                }
+
+                pub async fn #fn_in_op #query_fn_generics(
+                    &self,
+                    #query_fn_op_arg,
+                    filters: #filters_ident,
+                    cursor: es_entity::PaginatedQueryArgs<#cursor_mod::#cursor_ident>,
+                    direction: es_entity::ListDirection,
+                ) -> Result<es_entity::PaginatedQueryRet<#entity, #cursor_mod::#cursor_ident>, #error>
+                    where
+                        OP: #query_fn_op_traits
+                {
+                    #body
+                }
+            };
+        }
+
+        let n_filters: u32 = self
+            .for_columns
+            .iter()
+            .map(|c| {
+                if c.is_list_for_range() || c.is_optional() {
+                    2u32
+                } else {
+                    1u32
+                }
+            })
+            .sum();
+
+        let destructure_tokens = cursor_struct.destructure_tokens();
+        let select_columns = cursor_struct.select_columns(None);
+        let cursor_arg_tokens = cursor_struct.query_arg_tokens();
+
         // Generate filter destructuring
         let destructure_filters: TokenStream = self
             .for_columns
@@ -294,7 +485,23 @@ impl<'a> ListForFiltersFn<'a> {
                 let col_name = c.name();
                 let filter_name =
                     syn::Ident::new(&format!("filter_{}", col_name), Span::call_site());
-                if c.is_optional() {
+                if c.is_list_for_range() {
+                    let (from, to) = FiltersStruct::range_idents(c);
+                    let filter_from =
+                        syn::Ident::new(&format!("filter_{}", from), Span::call_site());
+                    let filter_to = syn::Ident::new(&format!("filter_{}", to), Span::call_site());
+                    quote! {
+                        let #filter_from = filters.#from;
+                        let #filter_to = filters.#to;
+                    }
+                } else if c.list_for_json_path().is_some() {
+                    quote! {
+                        let #filter_name = filters.#col_name.map(|v| {
+                            es_entity::prelude::serde_json::to_value(v)
+                                .expect("could not serialize json_path filter")
+                        });
+                    }
+                } else if c.is_optional() {
                     let apply_name =
                         syn::Ident::new(&format!("apply_{}", col_name), Span::call_site());
                     quote! {
@@ -314,7 +521,10 @@ impl<'a> ListForFiltersFn<'a> {
         let where_fragments: Vec<String> = self
             .for_columns
             .iter()
-            .map(|col| FiltersStruct::where_clause_fragment(col, &mut param_idx))
+            .map(|col| {
+                self.filters_struct
+                    .where_clause_fragment(col, &mut param_idx)
+            })
             .collect();
 
         let filter_where = if where_fragments.is_empty() {
@@ -364,12 +574,25 @@ impl<'a> ListForFiltersFn<'a> {
         } else {
             quote! {}
         };
+        let events_discriminator_args = &self.events_discriminator_args;
+        let probe_limit_arg = RepositoryOptions::es_query_probe_limit_arg(
+            self.page_probe,
+            &format!("${}", n_filters + 1),
+        );
+        let event_context_column_arg = if let Some(column) = self.event_context_column {
+            quote! { event_context_column = #column, }
+        } else {
+            quote! {}
+        };
 
         let es_query_asc_call = if let Some(prefix) = self.ignore_prefix {
             quote! {
                 es_entity::es_query!(
+                    #events_discriminator_args
+                    #probe_limit_arg
                     tbl_prefix = #prefix,
                     #forgettable_tbl_arg
+                    #event_context_column_arg
                     #asc_query,
                     #filter_arg_bindings
                     #cursor_arg_tokens
@@ -378,8 +601,11 @@ impl<'a> ListForFiltersFn<'a> {
         } else {
             quote! {
                 es_entity::es_query!(
+                    #events_discriminator_args
+                    #probe_limit_arg
                     entity = #entity,
                     #forgettable_tbl_arg
+                    #event_context_column_arg
                     #asc_query,
                     #filter_arg_bindings
                     #cursor_arg_tokens
@@ -390,8 +616,11 @@ impl<'a> ListForFiltersFn<'a> {
         let es_query_desc_call = if let Some(prefix) = self.ignore_prefix {
             quote! {
                 es_entity::es_query!(
+                    #events_discriminator_args
+                    #probe_limit_arg
                     tbl_prefix = #prefix,
                     #forgettable_tbl_arg
+                    #event_context_column_arg
                     #desc_query,
                     #filter_arg_bindings
                     #cursor_arg_tokens
@@ -400,8 +629,11 @@ impl<'a> ListForFiltersFn<'a> {
         } else {
             quote! {
                 es_entity::es_query!(
+                    #events_discriminator_args
+                    #probe_limit_arg
                     entity = #entity,
                     #forgettable_tbl_arg
+                    #event_context_column_arg
                     #desc_query,
                     #filter_arg_bindings
                     #cursor_arg_tokens
@@ -618,6 +850,22 @@ impl ToTokens for ListForFiltersFn<'_> {
                 error_recording,
             ) = (quote! {}, quote! {}, quote! {}, quote! {}, quote! {});
 
+            #[cfg(feature = "otel-metrics")]
+            let (start_timer, metrics_recording) = {
+                let entity_name = self.entity.to_string();
+                let operation = fn_name.to_string();
+                (
+                    quote! {
+                        let __started_at = std::time::Instant::now();
+                    },
+                    quote! {
+                        es_entity::metrics::record_query_duration(#entity_name, #operation, __started_at);
+                    },
+                )
+            };
+            #[cfg(not(feature = "otel-metrics"))]
+            let (start_timer, metrics_recording) = (quote! {}, quote! {});
+
             tokens.append_all(quote! {
                 #instrument_attr
                 pub async fn #fn_name(
@@ -627,8 +875,10 @@ impl ToTokens for ListForFiltersFn<'_> {
                     cursor: es_entity::PaginatedQueryArgs<#cursor_mod::#cursor_ident>,
                 ) -> Result<es_entity::PaginatedQueryRet<#entity, #cursor_mod::#cursor_ident>, #error>
                 {
+                    #start_timer
                     let __result: Result<es_entity::PaginatedQueryRet<#entity, #cursor_mod::#cursor_ident>, #error> = async {
                         #extract_has_cursor
+                        filters.validate()?;
                         let es_entity::Sort { by, direction } = sort;
                         let es_entity::PaginatedQueryArgs { first, after } = cursor;
                         #record_fields
@@ -644,6 +894,7 @@ impl ToTokens for ListForFiltersFn<'_> {
                     }.await;
 
                     #error_recording
+                    #metrics_recording
                     __result
                 }
             });
@@ -684,11 +935,87 @@ mod tests {
                 pub customer_id: Option<CustomerId>,
                 pub status: Option<OrderStatus>,
             }
+
+            impl OrderFilters {
+                /// Rejects filter combinations that can never match (eg. an empty range)
+                /// without hitting the database. Called by `list_for_filters`.
+                pub fn validate(&self) -> Result<(), es_entity::FilterError> {
+                    Ok(())
+                }
+            }
         };
 
         assert_eq!(tokens.to_string(), expected.to_string());
     }
 
+    #[test]
+    fn filters_struct_with_range() {
+        let entity = Ident::new("Order", Span::call_site());
+        let created_at_column = Column::new_list_for_range(
+            syn::Ident::new("created_at", proc_macro2::Span::call_site()),
+            syn::parse_str("Timestamp").unwrap(),
+        );
+
+        let filters = FiltersStruct::new_test(&entity, vec![&created_at_column]);
+
+        let mut tokens = TokenStream::new();
+        filters.to_tokens(&mut tokens);
+
+        let expected = quote! {
+            #[derive(Debug, Default)]
+            pub struct OrderFilters {
+                pub created_at_from: Option<Timestamp>,
+                pub created_at_to: Option<Timestamp>,
+            }
+
+            impl OrderFilters {
+                /// Rejects filter combinations that can never match (eg. an empty range)
+                /// without hitting the database. Called by `list_for_filters`.
+                pub fn validate(&self) -> Result<(), es_entity::FilterError> {
+                    if let (Some(from), Some(to)) = (&self.created_at_from, &self.created_at_to) {
+                        if from > to {
+                            return Err(es_entity::FilterError::EmptyRange("created_at"));
+                        }
+                    }
+                    Ok(())
+                }
+            }
+        };
+
+        assert_eq!(tokens.to_string(), expected.to_string());
+
+        let mut param_idx = 1;
+        assert_eq!(
+            filters.where_clause_fragment(&created_at_column, &mut param_idx),
+            "COALESCE(created_at >= $1, $1 IS NULL) AND COALESCE(created_at <= $2, $2 IS NULL)"
+        );
+        assert_eq!(param_idx, 3);
+    }
+
+    #[test]
+    fn json_path_filter_compares_latest_event_value() {
+        let entity = Ident::new("Entity", Span::call_site());
+        let input: syn::Meta = syn::parse_quote!(region(
+            ty = "String",
+            list_for(json_path = "metadata->>'region'")
+        ));
+        let region_column =
+            <Column as darling::FromMeta>::from_nested_meta(&darling::ast::NestedMeta::Meta(input))
+                .unwrap();
+
+        let filters = FiltersStruct::new_test(&entity, vec![&region_column]);
+        let mut param_idx = 2;
+        assert_eq!(
+            filters.where_clause_fragment(&region_column, &mut param_idx),
+            "COALESCE((SELECT ev.event #> '{metadata,region}' FROM entity_events ev WHERE ev.id = entities.id AND ev.sequence = (SELECT MAX(ev.sequence) FROM entity_events ev WHERE ev.id = entities.id AND ev.event #> '{metadata,region}' IS NOT NULL)) = $2, $2 IS NULL)"
+        );
+        assert_eq!(param_idx, 3);
+        assert_eq!(
+            FiltersStruct::filter_arg_tokens(&region_column).to_string(),
+            quote! { filter_region as Option<es_entity::prelude::serde_json::Value>, }.to_string()
+        );
+    }
+
     #[test]
     fn list_for_filters_function_generation() {
         let entity = Ident::new("Order", Span::call_site());
@@ -734,8 +1061,12 @@ mod tests {
             ignore_prefix: None,
             id: &id,
             any_nested: false,
+            has_read_pool: false,
             post_hydrate_error: None,
             forgettable_table_name: None,
+            event_context_column: None,
+            events_discriminator_args: TokenStream::new(),
+            page_probe: false,
             #[cfg(feature = "instrument")]
             repo_name_snake: "test_repo".to_string(),
         };
@@ -743,6 +1074,16 @@ mod tests {
         let mut tokens = TokenStream::new();
         list_for_filters_fn.to_tokens(&mut tokens);
 
+        #[cfg(feature = "otel-metrics")]
+        let (start_timer, metrics_recording) = (
+            quote! { let __started_at = std::time::Instant::now(); },
+            quote! {
+                es_entity::metrics::record_query_duration("Order", "list_for_filters", __started_at);
+            },
+        );
+        #[cfg(not(feature = "otel-metrics"))]
+        let (start_timer, metrics_recording) = (quote! {}, quote! {});
+
         let expected = quote! {
             pub async fn list_for_filters_by_id(
                 &self,
@@ -819,7 +1160,9 @@ mod tests {
                 cursor: es_entity::PaginatedQueryArgs<cursor_mod::OrderCursor>,
             ) -> Result<es_entity::PaginatedQueryRet<Order, cursor_mod::OrderCursor>, OrderQueryError>
             {
+                #start_timer
                 let __result: Result<es_entity::PaginatedQueryRet<Order, cursor_mod::OrderCursor>, OrderQueryError> = async {
+                    filters.validate()?;
                     let es_entity::Sort { by, direction } = sort;
                     let es_entity::PaginatedQueryArgs { first, after } = cursor;
 
@@ -853,6 +1196,7 @@ mod tests {
                     Ok(res)
                 }.await;
 
+                #metrics_recording
                 __result
             }
         };
@@ -906,8 +1250,12 @@ mod tests {
             ignore_prefix: None,
             id: &id,
             any_nested: false,
+            has_read_pool: false,
             post_hydrate_error: None,
             forgettable_table_name: None,
+            event_context_column: None,
+            events_discriminator_args: TokenStream::new(),
+            page_probe: false,
             #[cfg(feature = "instrument")]
             repo_name_snake: "test_repo".to_string(),
         };
@@ -974,8 +1322,12 @@ mod tests {
             ignore_prefix: None,
             id: &id,
             any_nested: false,
+            has_read_pool: false,
             post_hydrate_error: None,
             forgettable_table_name: None,
+            event_context_column: None,
+            events_discriminator_args: TokenStream::new(),
+            page_probe: false,
             #[cfg(feature = "instrument")]
             repo_name_snake: "test_repo".to_string(),
         };
@@ -993,6 +1345,175 @@ mod tests {
         assert!(token_str.contains("list_for_filters_by_id"));
     }
 
+    #[test]
+    fn list_for_filters_sorts_by_any_list_by_column() {
+        // status only pairs with created_at - sorting by created_at dispatches to the
+        // paired leaf while sorting by id falls back to the COALESCE query
+        let entity = Ident::new("Order", Span::call_site());
+        let query_error = syn::Ident::new("OrderQueryError", Span::call_site());
+        let id = syn::Ident::new("OrderId", proc_macro2::Span::call_site());
+        let cursor_mod = Ident::new("cursor_mod", Span::call_site());
+
+        let id_column = Column::for_id(syn::parse_str("OrderId").unwrap());
+        let created_at_column = Column::for_created_at();
+        let status_column = Column::new_list_for(
+            syn::Ident::new("status", proc_macro2::Span::call_site()),
+            syn::parse_str("OrderStatus").unwrap(),
+            vec![syn::Ident::new(
+                "created_at",
+                proc_macro2::Span::call_site(),
+            )],
+        );
+
+        let for_columns = vec![&status_column];
+        let by_columns = vec![&id_column, &created_at_column];
+
+        let cursors = by_columns
+            .iter()
+            .map(|column| CursorStruct {
+                column,
+                id: &id,
+                entity: &entity,
+                cursor_mod: &cursor_mod,
+            })
+            .collect();
+        let combo_cursor = ComboCursor::new_test(&entity, cursors);
+
+        let list_for_filters_fn = ListForFiltersFn {
+            filters_struct: FiltersStruct::new_test(&entity, for_columns.clone()),
+            entity: &entity,
+            query_error,
+            for_columns,
+            by_columns,
+            cursor: &combo_cursor,
+            delete: DeleteOption::No,
+            cursor_mod: cursor_mod.clone(),
+            table_name: "orders",
+            ignore_prefix: None,
+            id: &id,
+            any_nested: false,
+            has_read_pool: false,
+            post_hydrate_error: None,
+            forgettable_table_name: None,
+            event_context_column: None,
+            events_discriminator_args: TokenStream::new(),
+            page_probe: false,
+            #[cfg(feature = "instrument")]
+            repo_name_snake: "test_repo".to_string(),
+        };
+
+        let created_at_proxy = list_for_filters_fn
+            .generate_proxy_body(&created_at_column, DeleteOption::No)
+            .to_string();
+        assert!(created_at_proxy.contains("list_by_created_at"));
+        assert!(created_at_proxy.contains("list_for_status_by_created_at"));
+        assert!(!created_at_proxy.contains("list_for_filters_by_created_at"));
+
+        let id_proxy = list_for_filters_fn
+            .generate_proxy_body(&id_column, DeleteOption::No)
+            .to_string();
+        assert!(id_proxy.contains("list_by_id"));
+        assert!(!id_proxy.contains("list_for_status_by_id"));
+        assert!(id_proxy.contains("list_for_filters_by_id"));
+
+        let mut tokens = TokenStream::new();
+        list_for_filters_fn.to_tokens(&mut tokens);
+        let token_str = tokens.to_string();
+        assert!(token_str.contains("fn list_for_filters_by_id"));
+        assert!(token_str.contains("fn list_for_filters_by_created_at"));
+    }
+
+    #[test]
+    fn list_for_filters_forwards_when_no_filters_are_combined() {
+        let entity = Ident::new("Order", Span::call_site());
+        let id = syn::Ident::new("OrderId", proc_macro2::Span::call_site());
+        let cursor_mod = Ident::new("cursor_mod", Span::call_site());
+
+        let id_column = Column::for_id(syn::parse_str("OrderId").unwrap());
+        let created_at_column = Column::for_created_at();
+        let status_column = Column::new_list_for(
+            syn::Ident::new("status", proc_macro2::Span::call_site()),
+            syn::parse_str("OrderStatus").unwrap(),
+            vec![syn::Ident::new(
+                "created_at",
+                proc_macro2::Span::call_site(),
+            )],
+        );
+        let by_columns = vec![&id_column, &created_at_column];
+
+        let cursors = by_columns
+            .iter()
+            .map(|column| CursorStruct {
+                column,
+                id: &id,
+                entity: &entity,
+                cursor_mod: &cursor_mod,
+            })
+            .collect();
+        let combo_cursor = ComboCursor::new_test(&entity, cursors);
+
+        let list_for_filters_fn = |for_columns: Vec<&'static Column>| ListForFiltersFn {
+            filters_struct: FiltersStruct::new_test(&entity, for_columns.clone()),
+            entity: &entity,
+            query_error: syn::Ident::new("OrderQueryError", Span::call_site()),
+            for_columns,
+            by_columns: by_columns.clone(),
+            cursor: &combo_cursor,
+            delete: DeleteOption::No,
+            cursor_mod: cursor_mod.clone(),
+            table_name: "orders",
+            ignore_prefix: None,
+            id: &id,
+            any_nested: false,
+            has_read_pool: false,
+            post_hydrate_error: None,
+            forgettable_table_name: None,
+            event_context_column: None,
+            events_discriminator_args: TokenStream::new(),
+            page_probe: false,
+            #[cfg(feature = "instrument")]
+            repo_name_snake: "test_repo".to_string(),
+        };
+
+        // Without filters every sort column forwards to `list_by_*`
+        let no_filters = list_for_filters_fn(vec![]);
+        let by_id = no_filters
+            .generate_by_fn(&id_column, DeleteOption::No)
+            .to_string();
+        assert!(by_id.contains("list_by_id_in_op"));
+        assert!(!by_id.contains("es_query"));
+
+        // A single filter forwards to its paired `list_for_*_by_*`...
+        let status_column: &'static Column = Box::leak(Box::new(status_column));
+        let single_filter = list_for_filters_fn(vec![status_column]);
+        let by_created_at = single_filter
+            .generate_by_fn(&created_at_column, DeleteOption::No)
+            .to_string();
+        assert!(by_created_at.contains("list_for_status_by_created_at_in_op"));
+        assert!(by_created_at.contains("list_by_created_at_in_op"));
+        assert!(!by_created_at.contains("es_query"));
+
+        // ...but needs the COALESCE query for sort columns it isn't paired with
+        let by_id = single_filter
+            .generate_by_fn(&id_column, DeleteOption::No)
+            .to_string();
+        assert!(by_id.contains("es_query"));
+        assert!(by_id.contains("COALESCE(status = $1, $1 IS NULL)"));
+
+        // Optional columns always use the COALESCE query to tell `None` from `Some(None)`
+        let workspace_id_column: &'static Column = Box::leak(Box::new(Column::new_list_for(
+            syn::Ident::new("workspace_id", proc_macro2::Span::call_site()),
+            syn::parse_str("Option<WorkspaceId>").unwrap(),
+            vec![syn::Ident::new("id", proc_macro2::Span::call_site())],
+        )));
+        let optional_filter = list_for_filters_fn(vec![workspace_id_column]);
+        let by_id = optional_filter
+            .generate_by_fn(&id_column, DeleteOption::No)
+            .to_string();
+        assert!(by_id.contains("es_query"));
+        assert!(!by_id.contains("list_for_workspace_id_by_id_in_op"));
+    }
+
     #[test]
     fn list_for_filters_optional_column_uses_two_params() {
         let entity = Ident::new("Task", Span::call_site());
@@ -1040,8 +1561,12 @@ mod tests {
             ignore_prefix: None,
             id: &id,
             any_nested: false,
+            has_read_pool: false,
             post_hydrate_error: None,
             forgettable_table_name: None,
+            event_context_column: None,
+            events_discriminator_args: TokenStream::new(),
+            page_probe: false,
             #[cfg(feature = "instrument")]
             repo_name_snake: "test_repo".to_string(),
         };
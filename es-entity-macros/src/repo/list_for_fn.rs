@@ -15,8 +15,12 @@ pub struct ListForFn<'a> {
     delete: DeleteOption,
     cursor_mod: syn::Ident,
     any_nested: bool,
+    has_read_pool: bool,
     post_hydrate_error: Option<&'a syn::Type>,
     forgettable_table_name: Option<&'a str>,
+    event_context_column: Option<&'a str>,
+    events_discriminator_args: TokenStream,
+    page_probe: bool,
     #[cfg(feature = "instrument")]
     repo_name_snake: String,
 }
@@ -34,8 +38,12 @@ impl<'a> ListForFn<'a> {
             delete: opts.delete,
             cursor_mod: opts.cursor_mod(),
             any_nested: opts.any_nested(),
+            has_read_pool: opts.read_pool_accessor().is_some(),
             post_hydrate_error: opts.post_hydrate_hook.as_ref().map(|h| &h.error),
             forgettable_table_name: opts.forgettable_table_name(),
+            event_context_column: opts.custom_event_context_column(),
+            events_discriminator_args: opts.es_query_events_discriminator_args(),
+            page_probe: opts.page_probe_enabled(),
             #[cfg(feature = "instrument")]
             repo_name_snake: opts.repo_name_snake_case(),
         }
@@ -61,7 +69,8 @@ impl ToTokens for ListForFn<'_> {
         let query_fn_generics = RepositoryOptions::query_fn_generics(self.any_nested);
         let query_fn_op_arg = RepositoryOptions::query_fn_op_arg(self.any_nested);
         let query_fn_op_traits = RepositoryOptions::query_fn_op_traits(self.any_nested);
-        let query_fn_get_op = RepositoryOptions::query_fn_get_op(self.any_nested);
+        let query_fn_get_op =
+            RepositoryOptions::query_fn_get_op(self.any_nested, self.has_read_pool);
 
         let by_column_name = self.by_column.name();
 
@@ -135,12 +144,23 @@ impl ToTokens for ListForFn<'_> {
             } else {
                 quote! {}
             };
+            let events_discriminator_args = &self.events_discriminator_args;
+            let probe_limit_arg =
+                RepositoryOptions::es_query_probe_limit_arg(self.page_probe, "$2");
+            let event_context_column_arg = if let Some(column) = self.event_context_column {
+                quote! { event_context_column = #column, }
+            } else {
+                quote! {}
+            };
 
             let es_query_asc_call = if let Some(prefix) = self.ignore_prefix {
                 quote! {
                     es_entity::es_query!(
+                        #events_discriminator_args
+                        #probe_limit_arg
                         tbl_prefix = #prefix,
                         #forgettable_tbl_arg
+                        #event_context_column_arg
                         #asc_query,
                         #filter_arg_name as &#for_column_type,
                         #arg_tokens
@@ -149,8 +169,11 @@ impl ToTokens for ListForFn<'_> {
             } else {
                 quote! {
                     es_entity::es_query!(
+                        #events_discriminator_args
+                        #probe_limit_arg
                         entity = #entity,
                         #forgettable_tbl_arg
+                        #event_context_column_arg
                         #asc_query,
                         #filter_arg_name as &#for_column_type,
                         #arg_tokens
@@ -161,8 +184,11 @@ impl ToTokens for ListForFn<'_> {
             let es_query_desc_call = if let Some(prefix) = self.ignore_prefix {
                 quote! {
                     es_entity::es_query!(
+                        #events_discriminator_args
+                        #probe_limit_arg
                         tbl_prefix = #prefix,
                         #forgettable_tbl_arg
+                        #event_context_column_arg
                         #desc_query,
                         #filter_arg_name as &#for_column_type,
                         #arg_tokens
@@ -171,8 +197,11 @@ impl ToTokens for ListForFn<'_> {
             } else {
                 quote! {
                     es_entity::es_query!(
+                        #events_discriminator_args
+                        #probe_limit_arg
                         entity = #entity,
                         #forgettable_tbl_arg
+                        #event_context_column_arg
                         #desc_query,
                         #filter_arg_name as &#for_column_type,
                         #arg_tokens
@@ -332,8 +361,12 @@ mod tests {
             delete: DeleteOption::No,
             cursor_mod,
             any_nested: false,
+            has_read_pool: false,
             post_hydrate_error: None,
             forgettable_table_name: None,
+            event_context_column: None,
+            events_discriminator_args: TokenStream::new(),
+            page_probe: false,
             #[cfg(feature = "instrument")]
             repo_name_snake: "test_repo".to_string(),
         };
@@ -431,8 +464,12 @@ mod tests {
             delete: DeleteOption::No,
             cursor_mod,
             any_nested: false,
+            has_read_pool: false,
             post_hydrate_error: None,
             forgettable_table_name: None,
+            event_context_column: None,
+            events_discriminator_args: TokenStream::new(),
+            page_probe: false,
             #[cfg(feature = "instrument")]
             repo_name_snake: "test_repo".to_string(),
         };
@@ -0,0 +1,143 @@
+use darling::ToTokens;
+use proc_macro2::TokenStream;
+use quote::{TokenStreamExt, quote};
+
+use super::options::*;
+
+pub struct LoadEventsRawFn<'a> {
+    id: &'a syn::Ident,
+    events_table_name: &'a str,
+    event_context_column: &'a str,
+    events_discriminator: Option<&'a EventsDiscriminator>,
+    has_read_pool: bool,
+}
+
+impl<'a> From<&'a RepositoryOptions> for LoadEventsRawFn<'a> {
+    fn from(opts: &'a RepositoryOptions) -> Self {
+        Self {
+            id: opts.id(),
+            events_table_name: opts.events_table_name(),
+            event_context_column: opts.event_context_column(),
+            events_discriminator: opts.events_discriminator(),
+            has_read_pool: opts.read_pool_accessor().is_some(),
+        }
+    }
+}
+
+impl ToTokens for LoadEventsRawFn<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let id = self.id;
+        let read_pool = RepositoryOptions::read_pool(self.has_read_pool);
+        let query = format!(
+            "SELECT sequence, event_type, event, recorded_at, {} AS context FROM {} WHERE id = $1{} ORDER BY sequence",
+            self.event_context_column,
+            self.events_table_name,
+            self.events_discriminator
+                .map(|d| d.condition(None))
+                .unwrap_or_default(),
+        );
+
+        tokens.append_all(quote! {
+            /// Loads the events of entity `id` as stored (ordered by sequence) without deserializing them.
+            ///
+            /// Intended for inspecting streams that fail to hydrate.
+            pub async fn load_events_raw(
+                &self,
+                id: &#id,
+            ) -> Result<Vec<es_entity::RawEvent>, sqlx::Error> {
+                self.load_events_raw_in_op(#read_pool, id).await
+            }
+
+            pub async fn load_events_raw_in_op<'a, OP>(
+                &self,
+                op: OP,
+                id: &#id,
+            ) -> Result<Vec<es_entity::RawEvent>, sqlx::Error>
+            where
+                OP: es_entity::IntoOneTimeExecutor<'a>
+            {
+                let rows = op
+                    .into_executor()
+                    .fetch_all(sqlx::query!(#query, id as &#id))
+                    .await?;
+                Ok(rows
+                    .into_iter()
+                    .map(|row| es_entity::RawEvent {
+                        sequence: row.sequence as usize,
+                        event_type: row.event_type,
+                        event: row.event,
+                        recorded_at: row.recorded_at,
+                        context: row.context,
+                    })
+                    .collect())
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proc_macro2::Span;
+    use syn::Ident;
+
+    #[test]
+    fn load_events_raw_fn() {
+        let id = Ident::new("EntityId", Span::call_site());
+        let discriminator = EventsDiscriminator {
+            column: "entity_type".to_string(),
+            value: "entity".to_string(),
+        };
+        let load_events_raw_fn = LoadEventsRawFn {
+            id: &id,
+            events_table_name: "entity_events",
+            event_context_column: "metadata",
+            events_discriminator: Some(&discriminator),
+            has_read_pool: false,
+        };
+
+        let mut tokens = TokenStream::new();
+        load_events_raw_fn.to_tokens(&mut tokens);
+
+        let expected = quote! {
+            /// Loads the events of entity `id` as stored (ordered by sequence) without deserializing them.
+            ///
+            /// Intended for inspecting streams that fail to hydrate.
+            pub async fn load_events_raw(
+                &self,
+                id: &EntityId,
+            ) -> Result<Vec<es_entity::RawEvent>, sqlx::Error> {
+                self.load_events_raw_in_op(self.pool(), id).await
+            }
+
+            pub async fn load_events_raw_in_op<'a, OP>(
+                &self,
+                op: OP,
+                id: &EntityId,
+            ) -> Result<Vec<es_entity::RawEvent>, sqlx::Error>
+            where
+                OP: es_entity::IntoOneTimeExecutor<'a>
+            {
+                let rows = op
+                    .into_executor()
+                    .fetch_all(sqlx::query!(
+                        "SELECT sequence, event_type, event, recorded_at, metadata AS context FROM entity_events WHERE id = $1 AND entity_type = 'entity' ORDER BY sequence",
+                        id as &EntityId
+                    ))
+                    .await?;
+                Ok(rows
+                    .into_iter()
+                    .map(|row| es_entity::RawEvent {
+                        sequence: row.sequence as usize,
+                        event_type: row.event_type,
+                        event: row.event,
+                        recorded_at: row.recorded_at,
+                        context: row.context,
+                    })
+                    .collect())
+            }
+        };
+
+        assert_eq!(tokens.to_string(), expected.to_string());
+    }
+}
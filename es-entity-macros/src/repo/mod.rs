@@ -1,15 +1,24 @@
 mod begin;
 mod combo_cursor;
+mod count_by_fn;
 mod create_all_fn;
 mod create_fn;
+mod delete_all_fn;
 mod delete_fn;
 mod error_types;
+mod events_between_fn;
+mod fabricate_fn;
+mod find_all_by_fn;
 mod find_all_fn;
 mod find_by_fn;
+mod find_by_id_from_events_fn;
 mod forget_fn;
+mod hard_delete_fn;
 mod list_by_fn;
+mod list_by_id_parallel_fn;
 mod list_for_filters_fn;
 mod list_for_fn;
+mod load_events_raw_fn;
 mod nested;
 mod options;
 mod persist_events_batch_fn;
@@ -17,8 +26,12 @@ mod persist_events_fn;
 mod populate_nested;
 mod post_hydrate_hook;
 mod post_persist_hook;
+mod pre_persist_hook;
+mod stream_all_fn;
+mod truncate_fn;
 mod update_all_fn;
 mod update_fn;
+mod upsert_fn;
 
 use darling::{FromDeriveInput, ToTokens};
 use proc_macro2::TokenStream;
@@ -29,7 +42,14 @@ use options::RepositoryOptions;
 pub fn derive(ast: syn::DeriveInput) -> darling::Result<proc_macro2::TokenStream> {
     let opts = RepositoryOptions::from_derive_input(&ast)?;
     opts.columns.validate_list_for_by_columns()?;
+    opts.columns.validate_list_by_then()?;
     opts.validate_forgettable()?;
+    opts.validate_unique_constraints()?;
+    opts.validate_post_persist_hook()?;
+    opts.validate_hard_delete()?;
+    opts.validate_upsert()?;
+    opts.validate_clock()?;
+    opts.validate_batch_size()?;
     let repo = EsRepo::from(&opts);
     Ok(quote!(#repo))
 }
@@ -43,12 +63,26 @@ pub struct EsRepo<'a> {
     create_fn: create_fn::CreateFn<'a>,
     create_all_fn: create_all_fn::CreateAllFn<'a>,
     delete_fn: delete_fn::DeleteFn<'a>,
+    delete_all_fn: Option<delete_all_fn::DeleteAllFn<'a>>,
     forget_fn: Option<forget_fn::ForgetFn<'a>>,
+    hard_delete_fn: Option<hard_delete_fn::HardDeleteFn<'a>>,
+    upsert_fn: Option<upsert_fn::UpsertFn<'a>>,
     find_by_fns: Vec<find_by_fn::FindByFn<'a>>,
+    exists_by_fns: Vec<find_by_fn::ExistsByFn<'a>>,
+    find_all_by_fns: Vec<find_all_by_fn::FindAllByFn<'a>>,
+    count_by_fns: Vec<count_by_fn::CountByFn<'a>>,
     find_all_fn: find_all_fn::FindAllFn<'a>,
+    find_by_id_from_events_fn: Option<find_by_id_from_events_fn::FindByIdFromEventsFn<'a>>,
     post_hydrate_hook: post_hydrate_hook::PostHydrateHook<'a>,
     post_persist_hook: post_persist_hook::PostPersistHook<'a>,
+    pre_persist_hook: pre_persist_hook::PrePersistHook<'a>,
     begin: begin::Begin<'a>,
+    stream_all_fn: Option<stream_all_fn::StreamAllFn<'a>>,
+    list_by_id_parallel_fn: Option<list_by_id_parallel_fn::ListByIdParallelFn<'a>>,
+    events_between_fn: Option<events_between_fn::EventsBetweenFn<'a>>,
+    load_events_raw_fn: Option<load_events_raw_fn::LoadEventsRawFn<'a>>,
+    truncate_fn: truncate_fn::TruncateFn<'a>,
+    fabricate_fn: fabricate_fn::FabricateFn<'a>,
     list_by_fns: Vec<list_by_fn::ListByFn<'a>>,
     list_for_fns: Vec<list_for_fn::ListForFn<'a>>,
     nested_fns: Vec<syn::Ident>,
@@ -66,6 +100,23 @@ impl<'a> From<&'a RepositoryOptions> for EsRepo<'a> {
             .all_find_by()
             .map(|c| find_by_fn::FindByFn::new(c, opts))
             .collect();
+        // `exists_by_id` is kept for `upsert`
+        let exists_by_fns = opts
+            .columns
+            .all_find_by()
+            .filter(|c| opts.generate.exists_by() || (c.is_id() && opts.generate.upsert()))
+            .map(|c| find_by_fn::ExistsByFn::new(c, opts))
+            .collect();
+        let find_all_by_fns = opts
+            .columns
+            .all_find_all_by()
+            .map(|c| find_all_by_fn::FindAllByFn::new(c, opts))
+            .collect();
+        let count_by_fns = opts
+            .columns
+            .all_group_count()
+            .map(|c| count_by_fn::CountByFn::new(c, opts))
+            .collect();
         let list_by_fns = opts
             .columns
             .all_list_by()
@@ -104,6 +155,46 @@ impl<'a> From<&'a RepositoryOptions> for EsRepo<'a> {
         } else {
             None
         };
+        let hard_delete_fn = if opts.hard_delete_enabled() {
+            Some(hard_delete_fn::HardDeleteFn::from(opts))
+        } else {
+            None
+        };
+        let delete_all_fn = if opts.generate.delete_all() {
+            Some(delete_all_fn::DeleteAllFn::from(opts))
+        } else {
+            None
+        };
+        let stream_all_fn = if opts.generate.stream_all() {
+            Some(stream_all_fn::StreamAllFn::from(opts))
+        } else {
+            None
+        };
+        let list_by_id_parallel_fn = if opts.generate.list_by_id_parallel() {
+            Some(list_by_id_parallel_fn::ListByIdParallelFn::from(opts))
+        } else {
+            None
+        };
+        let events_between_fn = if opts.generate.events_between() {
+            Some(events_between_fn::EventsBetweenFn::from(opts))
+        } else {
+            None
+        };
+        let load_events_raw_fn = if opts.generate.load_events_raw() {
+            Some(load_events_raw_fn::LoadEventsRawFn::from(opts))
+        } else {
+            None
+        };
+        let find_by_id_from_events_fn = if opts.find_by_id_from_events_enabled() {
+            Some(find_by_id_from_events_fn::FindByIdFromEventsFn::from(opts))
+        } else {
+            None
+        };
+        let upsert_fn = if opts.generate.upsert() {
+            Some(upsert_fn::UpsertFn::from(opts))
+        } else {
+            None
+        };
 
         Self {
             repo: &opts.ident,
@@ -115,12 +206,26 @@ impl<'a> From<&'a RepositoryOptions> for EsRepo<'a> {
             create_fn: create_fn::CreateFn::from(opts),
             create_all_fn: create_all_fn::CreateAllFn::from(opts),
             delete_fn: delete_fn::DeleteFn::from(opts),
+            delete_all_fn,
             forget_fn,
+            hard_delete_fn,
+            upsert_fn,
             find_by_fns,
+            exists_by_fns,
+            find_all_by_fns,
+            count_by_fns,
             find_all_fn: find_all_fn::FindAllFn::from(opts),
+            find_by_id_from_events_fn,
             post_hydrate_hook: post_hydrate_hook::PostHydrateHook::from(opts),
             post_persist_hook: post_persist_hook::PostPersistHook::from(opts),
+            pre_persist_hook: pre_persist_hook::PrePersistHook::from(opts),
             begin: begin::Begin::from(opts),
+            stream_all_fn,
+            list_by_id_parallel_fn,
+            events_between_fn,
+            load_events_raw_fn,
+            truncate_fn: truncate_fn::TruncateFn::from(opts),
+            fabricate_fn: fabricate_fn::FabricateFn::from(opts),
             list_by_fns,
             list_for_fns,
             nested_fns,
@@ -143,12 +248,34 @@ impl ToTokens for EsRepo<'_> {
         let create_fn = &self.create_fn;
         let create_all_fn = &self.create_all_fn;
         let delete_fn = &self.delete_fn;
+        let delete_all_fn = &self.delete_all_fn;
         let forget_fn = &self.forget_fn;
+        let hard_delete_fn = &self.hard_delete_fn;
+        let upsert_fn = &self.upsert_fn;
         let find_by_fns = &self.find_by_fns;
+        let exists_by_fns = &self.exists_by_fns;
+        let find_all_by_fns = &self.find_all_by_fns;
+        let count_by_fns = &self.count_by_fns;
         let find_all_fn = &self.find_all_fn;
+        let find_by_id_from_events_fn = &self.find_by_id_from_events_fn;
         let post_hydrate_hook = &self.post_hydrate_hook;
         let post_persist_hook = &self.post_persist_hook;
+        let pre_persist_hook = &self.pre_persist_hook;
         let begin = &self.begin;
+        let stream_all_fn = &self.stream_all_fn;
+        let list_by_id_parallel_fn = &self.list_by_id_parallel_fn;
+        let events_between_fn = &self.events_between_fn;
+        let load_events_raw_fn = &self.load_events_raw_fn;
+        let truncate_fn = if cfg!(feature = "testing") {
+            Some(&self.truncate_fn)
+        } else {
+            None
+        };
+        let fabricate_fn = if cfg!(feature = "testing") {
+            Some(&self.fabricate_fn)
+        } else {
+            None
+        };
         let cursors = self.list_by_fns.iter().map(|l| l.cursor());
         let combo_cursor = combo_cursor::ComboCursor::new(
             self.opts,
@@ -162,6 +289,11 @@ impl ToTokens for EsRepo<'_> {
             &combo_cursor,
         );
         let list_for_filters_struct = &list_for_filters.filters_struct;
+        let list_for_filters = if self.opts.generate.list_for_filters() {
+            Some(&list_for_filters)
+        } else {
+            None
+        };
         #[cfg(feature = "graphql")]
         let gql_combo_cursor = combo_cursor.gql_cursor();
         #[cfg(not(feature = "graphql"))]
@@ -174,8 +306,20 @@ impl ToTokens for EsRepo<'_> {
             .collect();
         #[cfg(not(feature = "graphql"))]
         let gql_cursors: Vec<TokenStream> = Vec::new();
-        let list_by_fns = &self.list_by_fns;
-        let list_for_fns = &self.list_for_fns;
+        // The cursors are generated regardless as `list_for` / `list_for_filters` use them too
+        // and `list_by_id` is kept for `stream_all`
+        let list_by_fns: Vec<_> = self
+            .list_by_fns
+            .iter()
+            .filter(|f| {
+                self.opts.generate.list_by() || (f.is_by_id() && self.opts.generate.stream_all())
+            })
+            .collect();
+        let list_for_fns = if self.opts.generate.list_for() {
+            &self.list_for_fns[..]
+        } else {
+            &[]
+        };
 
         let entity = self.opts.entity();
         let event = self.opts.event();
@@ -190,7 +334,20 @@ impl ToTokens for EsRepo<'_> {
         let populate_nested = &self.populate_nested;
 
         let pool_field = self.opts.pool_field();
+        let table_name = self.opts.table_name();
+        let events_table_name = self.opts.events_table_name();
+        let read_pool_fn = self.opts.read_pool_accessor().map(|accessor| {
+            quote! {
+                /// Pool used by the convenience (non `_in_op`) read functions.
+                #[inline(always)]
+                pub fn read_pool(&self) -> &es_entity::db::Pool {
+                    self.#accessor
+                }
+            }
+        });
         let has_tbl_prefix = self.opts.table_prefix().is_some();
+        let has_custom_event_context_column = self.opts.custom_event_context_column().is_some();
+        let has_events_discriminator = self.opts.events_discriminator().is_some();
         let es_query_flavor = if nested_fns.is_empty() {
             quote! {
                 es_entity::EsQueryFlavorFlat
@@ -251,6 +408,10 @@ impl ToTokens for EsRepo<'_> {
                 pub(super) type Repo__DbEvent = es_entity::GenericEvent<#id>;
                 #[allow(dead_code)]
                 pub(super) const REPO__HAS_TBL_PREFIX: bool = #has_tbl_prefix;
+                #[allow(dead_code)]
+                pub(super) const REPO__HAS_CUSTOM_EVENT_CONTEXT_COLUMN: bool = #has_custom_event_context_column;
+                #[allow(dead_code)]
+                pub(super) const REPO__HAS_EVENTS_DISCRIMINATOR: bool = #has_events_discriminator;
 
                 #forgettable_event_guard
             }
@@ -266,24 +427,40 @@ impl ToTokens for EsRepo<'_> {
                     &self.#pool_field
                 }
 
+                #read_pool_fn
+
                 #map_constraint_fn
                 #begin
                 #post_hydrate_hook
                 #post_persist_hook
+                #pre_persist_hook
                 #persist_events_fn
                 #persist_events_batch_fn
                 #create_fn
                 #create_all_fn
                 #update_fn
                 #update_all_fn
+                #upsert_fn
                 #delete_fn
+                #delete_all_fn
                 #forget_fn
+                #hard_delete_fn
                 #(#find_by_fns)*
+                #(#exists_by_fns)*
+                #(#find_all_by_fns)*
+                #(#count_by_fns)*
                 #find_all_fn
+                #find_by_id_from_events_fn
                 #list_for_filters
                 #(#list_by_fns)*
                 #(#list_for_fns)*
+                #stream_all_fn
+                #list_by_id_parallel_fn
+                #events_between_fn
+                #load_events_raw_fn
                 #(#nested)*
+                #truncate_fn
+                #fabricate_fn
             }
 
             #populate_nested
@@ -296,6 +473,9 @@ impl ToTokens for EsRepo<'_> {
                 type QueryError = #query_error;
                 type EsQueryFlavor = #es_query_flavor;
 
+                const TABLE_NAME: &'static str = #table_name;
+                const EVENTS_TABLE_NAME: &'static str = #events_table_name;
+
                #[inline(always)]
                async fn load_all_nested_in_op<OP, __EsErr>(
                    op: &mut OP, entities: &mut [#entity]
@@ -368,6 +548,164 @@ mod tests {
     // covered by a compile_fail doctest on `Forgettable` rather than a brittle
     // token-string assertion here.
 
+    #[test]
+    fn generate_toggles_skip_list_fns() {
+        let input: syn::DeriveInput = parse_quote! {
+            #[es_repo(
+                entity = "User",
+                generate(list_for = false),
+                columns(name(ty = "String", list_by, list_for))
+            )]
+            struct Users {
+                pool: sqlx::PgPool,
+            }
+        };
+        let tokens = derive(input).unwrap().to_string();
+        assert!(tokens.contains("fn list_by_name "));
+        assert!(!tokens.contains("fn list_for_name_by_id "));
+        assert!(!tokens.contains("fn list_for_filters "));
+
+        let input: syn::DeriveInput = parse_quote! {
+            #[es_repo(
+                entity = "User",
+                generate(list = false),
+                columns(name(ty = "String", list_by, list_for))
+            )]
+            struct Users {
+                pool: sqlx::PgPool,
+            }
+        };
+        let tokens = derive(input).unwrap().to_string();
+        assert!(tokens.contains("fn find_by_name "));
+        assert!(tokens.contains("struct UserByNameCursor "));
+        assert!(!tokens.contains("fn list_by_id "));
+        assert!(!tokens.contains("fn list_by_name "));
+        assert!(!tokens.contains("fn list_for_"));
+
+        let input: syn::DeriveInput = parse_quote! {
+            #[es_repo(
+                entity = "User",
+                generate(list = false, stream_all),
+                columns(name(ty = "String", list_by))
+            )]
+            struct Users {
+                pool: sqlx::PgPool,
+            }
+        };
+        let tokens = derive(input).unwrap().to_string();
+        assert!(tokens.contains("fn list_by_id "));
+        assert!(!tokens.contains("fn list_by_name "));
+    }
+
+    #[test]
+    fn generate_opt_in_helpers() {
+        let input: syn::DeriveInput = parse_quote! {
+            #[es_repo(entity = "User", delete = "soft", columns(name(ty = "String")))]
+            struct Users {
+                pool: sqlx::PgPool,
+            }
+        };
+        let tokens = derive(input).unwrap().to_string();
+        assert!(!tokens.contains("fn delete_all"));
+        assert!(!tokens.contains("fn stream_all"));
+        assert!(!tokens.contains("fn events_between"));
+        assert!(!tokens.contains("fn exists_by_name"));
+        assert!(!tokens.contains("fn list_by_id_parallel"));
+        assert!(!tokens.contains("fn upsert_in_op"));
+        assert!(!tokens.contains("fn exists_by_id"));
+        assert!(!tokens.contains("fn load_events_raw"));
+
+        let input: syn::DeriveInput = parse_quote! {
+            #[es_repo(
+                entity = "User",
+                delete = "soft",
+                generate(
+                    delete_all,
+                    stream_all,
+                    events_between,
+                    exists_by,
+                    list_by_id_parallel,
+                    upsert,
+                    load_events_raw
+                ),
+                columns(name(ty = "String"))
+            )]
+            struct Users {
+                pool: sqlx::PgPool,
+            }
+        };
+        let tokens = derive(input).unwrap().to_string();
+        assert!(tokens.contains("fn delete_all "));
+        assert!(tokens.contains("fn stream_all "));
+        assert!(tokens.contains("fn events_between "));
+        assert!(tokens.contains("fn exists_by_name "));
+        assert!(tokens.contains("fn list_by_id_parallel "));
+        assert!(tokens.contains("fn upsert_in_op"));
+        assert!(tokens.contains("fn load_events_raw "));
+    }
+
+    #[test]
+    fn upsert_with_nested_is_error() {
+        let input: syn::DeriveInput = parse_quote! {
+            #[es_repo(entity = "Order", generate(upsert))]
+            struct Orders {
+                pool: sqlx::PgPool,
+                #[es_repo(nested)]
+                items: Items,
+            }
+        };
+        let err = derive(input).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("`generate(upsert)` is not supported"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn find_by_id_from_events_is_opt_in() {
+        let input: syn::DeriveInput = parse_quote! {
+            #[es_repo(entity = "User")]
+            struct Users {
+                pool: sqlx::PgPool,
+            }
+        };
+        let tokens = derive(input).unwrap().to_string();
+        assert!(!tokens.contains("fn find_by_id_from_events"));
+
+        let input: syn::DeriveInput = parse_quote! {
+            #[es_repo(entity = "User", allow_find_by_id_from_events)]
+            struct Users {
+                pool: sqlx::PgPool,
+            }
+        };
+        let tokens = derive(input).unwrap().to_string();
+        assert!(tokens.contains("fn find_by_id_from_events "));
+        assert!(tokens.contains("fn find_by_id_from_events_in_op "));
+    }
+
+    #[test]
+    fn read_pool_only_generated_with_accessor() {
+        let input: syn::DeriveInput = parse_quote! {
+            #[es_repo(entity = "User")]
+            struct Users {
+                pool: sqlx::PgPool,
+            }
+        };
+        let tokens = derive(input).unwrap().to_string();
+        assert!(!tokens.contains("fn read_pool "));
+        assert!(!tokens.contains("self . read_pool ()"));
+
+        let input: syn::DeriveInput = parse_quote! {
+            #[es_repo(entity = "User", read_pool_accessor = "replica_pool()")]
+            struct Users {
+                pool: sqlx::PgPool,
+            }
+        };
+        let tokens = derive(input).unwrap().to_string();
+        assert!(tokens.contains("fn read_pool "));
+    }
+
     #[test]
     fn plain_repo_is_ok() {
         let input: syn::DeriveInput = parse_quote! {
@@ -1,33 +1,85 @@
 use darling::FromMeta;
 use quote::quote;
 
+use super::RecordedAtPrecision;
+
 #[derive(Default)]
 pub struct Columns {
     all: Vec<Column>,
+    /// Options declared for the implicit `created_at` column via `created_at(...)`.
+    created_at_opts: CreatedAtOpts,
+    /// Set by a bare `updated_at` entry - the column is maintained by the framework.
+    updated_at: bool,
+    recorded_at_precision: Option<RecordedAtPrecision>,
 }
 
 impl Columns {
     #[cfg(test)]
     pub fn new(id: &syn::Ident, columns: impl IntoIterator<Item = Column>) -> Self {
         let all = columns.into_iter().collect();
-        let mut res = Columns { all };
+        let mut res = Columns {
+            all,
+            ..Default::default()
+        };
         res.set_id_column(id);
         res
     }
 
     pub fn set_id_column(&mut self, ty: &syn::Ident) {
+        let mut created_at = Column::for_created_at();
+        created_at.opts.list_for_opts = self.created_at_opts.list_for_opts.take();
         let mut all = vec![
-            Column::for_created_at(),
+            created_at,
             Column::for_id(syn::parse_str(&ty.to_string()).unwrap()),
         ];
         all.append(&mut self.all);
         self.all = all;
+        self.resolve_list_by_then();
+    }
+
+    /// Attach the secondary sort column of `list_by(then = ..)` to the primary column.
+    /// Unknown names are left unresolved and reported by [`Self::validate_list_by_then`].
+    fn resolve_list_by_then(&mut self) {
+        let resolved: Vec<_> = self
+            .all
+            .iter()
+            .map(|col| {
+                let then = col.opts.list_by.as_ref()?.then.as_ref()?;
+                let mut then_col = self.all.iter().find(|c| c.name() == then)?.clone();
+                then_col.list_by_then = None;
+                Some(Box::new(then_col))
+            })
+            .collect();
+        for (col, then) in self.all.iter_mut().zip(resolved) {
+            col.list_by_then = then;
+        }
+    }
+
+    pub fn set_recorded_at_precision(&mut self, precision: Option<RecordedAtPrecision>) {
+        self.recorded_at_precision = precision;
+    }
+
+    /// SQL expression for the framework maintained `updated_at` column (if declared).
+    ///
+    /// Evaluates to the same value as the `recorded_at` of the events persisted in the
+    /// same operation given the same bound `now_param`.
+    pub fn updated_at_expr(&self, now_param: &str) -> Option<String> {
+        self.updated_at
+            .then(|| RecordedAtPrecision::recorded_at_expr(self.recorded_at_precision, now_param))
     }
 
     pub fn all_find_by(&self) -> impl Iterator<Item = &Column> {
         self.all.iter().filter(|c| c.opts.find_by())
     }
 
+    pub fn all_find_all_by(&self) -> impl Iterator<Item = &Column> {
+        self.all.iter().filter(|c| c.opts.find_all())
+    }
+
+    pub fn all_group_count(&self) -> impl Iterator<Item = &Column> {
+        self.all.iter().filter(|c| c.opts.group_count())
+    }
+
     pub fn all_list_by(&self) -> impl Iterator<Item = &Column> {
         self.all.iter().filter(|c| c.opts.list_by())
     }
@@ -44,6 +96,35 @@ impl Columns {
 
     pub fn validate_list_for_by_columns(&self) -> darling::Result<()> {
         let mut errors = darling::Error::accumulator();
+        for col in self.all.iter().filter(|c| c.is_list_for_range()) {
+            if col.is_nullable_column() {
+                errors.push(darling::Error::custom(format!(
+                    "list_for(range) on '{}' is not supported for nullable columns",
+                    col.name(),
+                )));
+            }
+        }
+        for col in self.all.iter().filter(|c| c.list_for_json_path().is_some()) {
+            let opts = &col.opts;
+            if col.is_optional()
+                || opts.forgettable
+                || opts.find_by == Some(true)
+                || opts.find_all()
+                || opts.group_count()
+                || opts.list_by.is_some()
+                || opts.nullable.is_some()
+                || opts.parent_opts.is_some()
+                || opts.create_opts.is_some()
+                || opts.update_opts.is_some()
+                || opts.constraint.is_some()
+            {
+                errors.push(darling::Error::custom(format!(
+                    "list_for(json_path) on '{}' declares a filter that is not stored in the index table - \
+                     it needs a non-Option type and can not be combined with other column options",
+                    col.name(),
+                )));
+            }
+        }
         for col in self.all.iter().filter(|c| c.opts.list_for()) {
             for by_name in col.list_for_by_columns() {
                 if self.find_list_by(by_name).is_none() {
@@ -61,9 +142,48 @@ impl Columns {
         errors.finish()
     }
 
+    pub fn validate_list_by_then(&self) -> darling::Result<()> {
+        let mut errors = darling::Error::accumulator();
+        for col in &self.all {
+            let Some(then) = col.opts.list_by.as_ref().and_then(|o| o.then.as_ref()) else {
+                continue;
+            };
+            match col.list_by_then() {
+                None => errors.push(
+                    darling::Error::custom(format!(
+                        "column '{then}' in list_by(then = ..) on '{}' does not exist",
+                        col.name(),
+                    ))
+                    .with_span(then),
+                ),
+                Some(then_col) if then_col.name() == col.name() || then_col.is_id() => errors.push(
+                    darling::Error::custom(format!(
+                        "list_by(then = ..) on '{}' must name a column other than '{}' and 'id'",
+                        col.name(),
+                        col.name(),
+                    ))
+                    .with_span(then),
+                ),
+                Some(then_col) if col.is_nullable_column() || then_col.is_nullable_column() => {
+                    errors.push(
+                        darling::Error::custom(format!(
+                            "list_by(then = ..) on '{}' is not supported for nullable columns",
+                            col.name(),
+                        ))
+                        .with_span(then),
+                    )
+                }
+                Some(_) => (),
+            }
+        }
+        errors.finish()
+    }
+
     /// Returns columns for the Column enum (id + user columns, not created_at)
     pub fn column_enum_columns(&self) -> impl Iterator<Item = &Column> {
-        self.all.iter().filter(|c| *c.name() != "created_at")
+        self.all
+            .iter()
+            .filter(|c| *c.name() != "created_at" && c.list_for_json_path().is_none())
     }
 
     pub fn parent(&self) -> Option<&Column> {
@@ -71,7 +191,7 @@ impl Columns {
     }
 
     pub fn updates_needed(&self) -> bool {
-        self.all.iter().any(|c| c.opts.persist_on_update())
+        self.updated_at || self.all.iter().any(|c| c.opts.persist_on_update())
     }
 
     pub fn variable_assignments_for_update(&self, ident: syn::Ident) -> proc_macro2::TokenStream {
@@ -100,6 +220,15 @@ impl Columns {
         }
     }
 
+    /// Binds `id` to the id of the `New` entity in `ident` the same way `create` does.
+    pub fn id_assignment_for_create(&self, ident: syn::Ident) -> proc_macro2::TokenStream {
+        self.all
+            .iter()
+            .find(|c| c.name == "id")
+            .map(|c| c.variable_assignment_for_create(&ident))
+            .expect("id column is always present")
+    }
+
     pub fn variable_assignments_for_create_all(
         &self,
         ident: syn::Ident,
@@ -369,22 +498,130 @@ impl Columns {
             .map(|c| c.name.to_string())
             .collect()
     }
+
+    /// Like [`Self::update_all_arg_parts`] but for the batched soft-delete,
+    /// excluding forgettable columns (they are `NULL`ed, not bound).
+    pub fn delete_all_arg_parts(
+        &self,
+        ident: syn::Ident,
+    ) -> (
+        proc_macro2::TokenStream,
+        proc_macro2::TokenStream,
+        Vec<proc_macro2::TokenStream>,
+    ) {
+        let columns = || {
+            self.all
+                .iter()
+                .filter(|c| (c.opts.persist_on_update() && !c.opts.forgettable) || c.opts.is_id)
+        };
+        let assignments = columns().map(|c| c.variable_assignment_for_update_all(&ident));
+        let (vecs, pushes, bindings): (Vec<_>, Vec<_>, Vec<_>) = columns()
+            .map(|column| {
+                let vec_ident = syn::Ident::new(
+                    &format!("{}_collection", column.name),
+                    proc_macro2::Span::call_site(),
+                );
+                let ident = &column.name;
+                (
+                    quote! {
+                        let mut #vec_ident = Vec::new();
+                    },
+                    quote! {
+                        #vec_ident.push(#ident);
+                    },
+                    quote! {
+                        .bind(#vec_ident)
+                    },
+                )
+            })
+            .fold(
+                (Vec::new(), Vec::new(), Vec::new()),
+                |(mut v1, mut v2, mut v3), (a, b, c)| {
+                    v1.push(a);
+                    v2.push(b);
+                    v3.push(c);
+                    (v1, v2, v3)
+                },
+            );
+        (
+            quote! { #(#vecs)* },
+            quote! {
+                #(#assignments)*
+                #(#pushes)*
+            },
+            bindings,
+        )
+    }
+
+    /// `SET` clause of the batched soft-delete `UPDATE`. Forgettable columns
+    /// are set to `NULL` matching [`Self::sql_updates_for_delete`].
+    pub fn sql_bulk_updates_for_delete(&self) -> String {
+        self.all
+            .iter()
+            .filter(|c| c.opts.persist_on_update() && !c.opts.is_id)
+            .map(|column| {
+                if column.opts.forgettable {
+                    format!("{} = NULL", column.name)
+                } else {
+                    format!("{name} = unnested.{name}", name = column.name)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    pub fn delete_all_column_names(&self) -> Vec<String> {
+        self.all
+            .iter()
+            .filter(|c| (c.opts.persist_on_update() && !c.opts.forgettable) || c.opts.is_id)
+            .map(|c| c.name.to_string())
+            .collect()
+    }
 }
 
 impl FromMeta for Columns {
     fn from_list(items: &[darling::ast::NestedMeta]) -> darling::Result<Self> {
-        let all = items
-            .iter()
-            .map(Column::from_nested_meta)
-            .collect::<Result<Vec<_>, _>>()?;
-        Ok(Columns { all })
+        let mut all = Vec::new();
+        let mut created_at_opts = CreatedAtOpts::default();
+        let mut updated_at = false;
+        for item in items {
+            match item {
+                darling::ast::NestedMeta::Meta(syn::Meta::Path(path))
+                    if path.is_ident("updated_at") =>
+                {
+                    updated_at = true;
+                }
+                darling::ast::NestedMeta::Meta(meta @ syn::Meta::List(_))
+                    if meta.path().is_ident("created_at") =>
+                {
+                    created_at_opts = CreatedAtOpts::from_meta(meta)?;
+                }
+                _ => all.push(Column::from_nested_meta(item)?),
+            }
+        }
+        Ok(Columns {
+            all,
+            created_at_opts,
+            updated_at,
+            recorded_at_precision: None,
+        })
     }
 }
 
-#[derive(PartialEq)]
+/// The implicit `created_at` column can not be redeclared but it can opt into
+/// `list_for` (typically `created_at(list_for(range))`).
+#[derive(Default, FromMeta)]
+struct CreatedAtOpts {
+    #[darling(default, rename = "list_for")]
+    list_for_opts: Option<ListForOpts>,
+}
+
+#[derive(PartialEq, Clone)]
 pub struct Column {
     name: syn::Ident,
     opts: ColumnOpts,
+    /// Secondary sort column declared via `list_by(then = ..)`.
+    list_by_then: Option<Box<Column>>,
 }
 
 impl FromMeta for Column {
@@ -411,7 +648,11 @@ impl FromMeta for Column {
                 })?;
                 let mut opts = ColumnOpts::from_meta(meta)?;
                 opts.normalize_forgettable();
-                let column = Column { name, opts };
+                let column = Column {
+                    name,
+                    opts,
+                    list_by_then: None,
+                };
                 Ok(column)
             }
             _ => Err(
@@ -427,6 +668,7 @@ impl Column {
         Column {
             name,
             opts: ColumnOpts::new(ty),
+            list_by_then: None,
         }
     }
 
@@ -435,9 +677,30 @@ impl Column {
         Column {
             name,
             opts: ColumnOpts {
-                list_for_opts: Some(ListForOpts { by_columns }),
+                list_for_opts: Some(ListForOpts {
+                    by_columns,
+                    range: false,
+                    json_path: None,
+                }),
+                ..ColumnOpts::new(ty)
+            },
+            list_by_then: None,
+        }
+    }
+
+    #[cfg(test)]
+    pub fn new_list_for_range(name: syn::Ident, ty: syn::Type) -> Self {
+        Column {
+            name,
+            opts: ColumnOpts {
+                list_for_opts: Some(ListForOpts {
+                    by_columns: Vec::new(),
+                    range: true,
+                    json_path: None,
+                }),
                 ..ColumnOpts::new(ty)
             },
+            list_by_then: None,
         }
     }
 
@@ -449,6 +712,7 @@ impl Column {
                 nullable: Some(true),
                 ..ColumnOpts::new(ty)
             },
+            list_by_then: None,
         }
     }
 
@@ -459,14 +723,17 @@ impl Column {
                 ty,
                 is_id: true,
                 forgettable: false,
-                list_by: Some(true),
+                list_by: Some(ListByOpts::from_word().expect("list_by")),
                 find_by: Some(true),
+                find_all: None,
+                group_count: None,
                 nullable: None,
                 list_for_opts: None,
                 parent_opts: None,
                 create_opts: Some(CreateOpts {
                     persist: Some(true),
                     accessor: None,
+                    default: None,
                 }),
                 update_opts: Some(UpdateOpts {
                     persist: Some(false),
@@ -474,6 +741,7 @@ impl Column {
                 }),
                 constraint: None,
             },
+            list_by_then: None,
         }
     }
 
@@ -486,14 +754,17 @@ impl Column {
                 ),
                 is_id: false,
                 forgettable: false,
-                list_by: Some(true),
+                list_by: Some(ListByOpts::from_word().expect("list_by")),
                 find_by: Some(false),
+                find_all: None,
+                group_count: None,
                 nullable: None,
                 list_for_opts: None,
                 parent_opts: None,
                 create_opts: Some(CreateOpts {
                     persist: Some(false),
                     accessor: None,
+                    default: None,
                 }),
                 update_opts: Some(UpdateOpts {
                     persist: Some(false),
@@ -505,6 +776,7 @@ impl Column {
                 }),
                 constraint: None,
             },
+            list_by_then: None,
         }
     }
 
@@ -512,6 +784,23 @@ impl Column {
         self.opts.list_for_by_columns()
     }
 
+    /// The secondary sort column of `list_by(then = ..)`.
+    pub fn list_by_then(&self) -> Option<&Column> {
+        self.list_by_then.as_deref()
+    }
+
+    /// Keys of a `list_for(json_path = "..")` column. Such a column is not stored in
+    /// the index table, it only filters `list_for_filters` by the event payloads.
+    pub fn list_for_json_path(&self) -> Option<&[String]> {
+        self.opts.json_path()
+    }
+
+    /// True for `list_for(range)` columns which are filtered by an optional
+    /// `<column>_from` / `<column>_to` pair instead of by equality.
+    pub fn is_list_for_range(&self) -> bool {
+        self.opts.list_for_opts.as_ref().is_some_and(|o| o.range)
+    }
+
     pub fn custom_constraint(&self) -> Option<&str> {
         self.opts.constraint.as_deref()
     }
@@ -611,6 +900,11 @@ impl Column {
                 let #name = &Some(#ident.#name.clone());
             };
         }
+        if let Some(default) = self.opts.create_default() {
+            return quote! {
+                let #name = &(#default);
+            };
+        }
         let accessor = self.opts.create_accessor(name);
         quote! {
             let #name = &#ident.#accessor;
@@ -625,6 +919,11 @@ impl Column {
                 let #name: #ty = Some(#ident.#name.clone());
             };
         }
+        if let Some(default) = self.opts.create_default() {
+            return quote! {
+                let #name: #ty = #default;
+            };
+        }
         let accessor = self.opts.create_accessor(name);
         if self.opts.create_accessor_returns_owned() {
             quote! {
@@ -698,7 +997,7 @@ fn option_inner(ty: &syn::Type) -> Option<syn::Type> {
     None
 }
 
-#[derive(PartialEq, FromMeta)]
+#[derive(PartialEq, Clone, FromMeta)]
 struct ColumnOpts {
     ty: syn::Type,
     #[darling(default, skip)]
@@ -710,8 +1009,14 @@ struct ColumnOpts {
     forgettable: bool,
     #[darling(default)]
     find_by: Option<bool>,
+    /// Generates an unbounded `find_all_by_<column>` for indexed but non-unique columns.
+    #[darling(default)]
+    find_all: Option<bool>,
+    /// Generates `count_by_<column>` returning the number of entities per value.
+    #[darling(default)]
+    group_count: Option<bool>,
     #[darling(default)]
-    list_by: Option<bool>,
+    list_by: Option<ListByOpts>,
     /// Opt-in flag for columns whose Rust type is not syntactically `Option<T>`
     /// but whose underlying SQL column is nullable. When set, the macro emits
     /// the same nullable-aware cursor SQL (`IS NOT DISTINCT FROM`, `NULLS
@@ -741,6 +1046,8 @@ impl ColumnOpts {
             is_id: false,
             forgettable: false,
             find_by: None,
+            find_all: None,
+            group_count: None,
             list_by: None,
             nullable: None,
             list_for_opts: None,
@@ -763,11 +1070,19 @@ impl ColumnOpts {
     }
 
     fn find_by(&self) -> bool {
-        self.find_by.unwrap_or(true)
+        self.find_by.unwrap_or(self.json_path().is_none())
+    }
+
+    fn find_all(&self) -> bool {
+        self.find_all.unwrap_or(false)
+    }
+
+    fn group_count(&self) -> bool {
+        self.group_count.unwrap_or(false)
     }
 
     fn list_by(&self) -> bool {
-        self.list_by.unwrap_or(false)
+        self.list_by.as_ref().is_some_and(|o| o.enabled)
     }
 
     fn nullable(&self) -> bool {
@@ -778,6 +1093,12 @@ impl ColumnOpts {
         self.list_for_opts.is_some()
     }
 
+    fn json_path(&self) -> Option<&[String]> {
+        self.list_for_opts
+            .as_ref()
+            .and_then(|o| o.json_path.as_deref())
+    }
+
     fn list_for_by_columns(&self) -> &[syn::Ident] {
         self.list_for_opts
             .as_ref()
@@ -786,9 +1107,11 @@ impl ColumnOpts {
     }
 
     fn persist_on_create(&self) -> bool {
-        self.create_opts
-            .as_ref()
-            .is_none_or(|o| o.persist.unwrap_or(true))
+        self.json_path().is_none()
+            && self
+                .create_opts
+                .as_ref()
+                .is_none_or(|o| o.persist.unwrap_or(true))
     }
 
     fn create_accessor(&self, name: &syn::Ident) -> proc_macro2::TokenStream {
@@ -803,10 +1126,17 @@ impl ColumnOpts {
         }
     }
 
+    /// Expression bound on insert in place of reading the column off `New`.
+    fn create_default(&self) -> Option<&syn::Expr> {
+        self.create_opts.as_ref().and_then(|o| o.default.as_ref())
+    }
+
     fn persist_on_update(&self) -> bool {
-        self.update_opts
-            .as_ref()
-            .is_none_or(|o| o.persist.unwrap_or(true))
+        self.json_path().is_none()
+            && self
+                .update_opts
+                .as_ref()
+                .is_none_or(|o| o.persist.unwrap_or(true))
     }
 
     fn update_accessor(&self, name: &syn::Ident) -> proc_macro2::TokenStream {
@@ -853,27 +1183,46 @@ impl ColumnOpts {
     }
 }
 
-#[derive(Default, PartialEq, FromMeta)]
+#[derive(Default, PartialEq, Clone, FromMeta)]
+#[darling(and_then = Self::validate)]
 struct CreateOpts {
     persist: Option<bool>,
     accessor: Option<syn::Expr>,
+    default: Option<syn::Expr>,
+}
+
+impl CreateOpts {
+    fn validate(self) -> darling::Result<Self> {
+        if self.accessor.is_some() && self.default.is_some() {
+            return Err(darling::Error::custom(
+                "create(accessor) and create(default) are mutually exclusive",
+            ));
+        }
+        Ok(self)
+    }
 }
 
-#[derive(Default, PartialEq, FromMeta)]
+#[derive(Default, PartialEq, Clone, FromMeta)]
 struct UpdateOpts {
     persist: Option<bool>,
     accessor: Option<syn::Expr>,
 }
 
-#[derive(PartialEq, Debug, Default)]
+#[derive(PartialEq, Clone, Debug, Default)]
 struct ListForOpts {
     by_columns: Vec<syn::Ident>,
+    range: bool,
+    /// Keys of `list_for(json_path = "..")` - the column only exists as a filter
+    /// over the event payloads.
+    json_path: Option<Vec<String>>,
 }
 
 impl FromMeta for ListForOpts {
     fn from_word() -> darling::Result<Self> {
         Ok(ListForOpts {
             by_columns: vec![syn::Ident::new("id", proc_macro2::Span::call_site())],
+            range: false,
+            json_path: None,
         })
     }
 
@@ -889,6 +1238,8 @@ impl FromMeta for ListForOpts {
 
     fn from_list(items: &[darling::ast::NestedMeta]) -> darling::Result<Self> {
         let mut by_columns = Vec::new();
+        let mut range = false;
+        let mut json_path = None;
         for item in items {
             match item {
                 darling::ast::NestedMeta::Meta(syn::Meta::List(list))
@@ -898,18 +1249,105 @@ impl FromMeta for ListForOpts {
                         list.parse_args_with(syn::punctuated::Punctuated::parse_terminated)?;
                     by_columns.extend(inner);
                 }
+                darling::ast::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("range") => {
+                    range = true;
+                }
+                darling::ast::NestedMeta::Meta(meta @ syn::Meta::NameValue(_))
+                    if meta.path().is_ident("json_path") =>
+                {
+                    let path = String::from_meta(meta)?;
+                    json_path = Some(parse_json_path(&path).map_err(|e| e.with_span(meta))?);
+                }
                 _ => {
-                    return Err(
-                        darling::Error::custom("Expected `by(col1, col2, ...)`").with_span(item)
-                    );
+                    return Err(darling::Error::custom(
+                        "Expected `by(col1, col2, ...)`, `range` or `json_path = \"...\"`",
+                    )
+                    .with_span(item));
                 }
             }
         }
-        Ok(ListForOpts { by_columns })
+        if range && !by_columns.is_empty() {
+            return Err(darling::Error::custom(
+                "`by(...)` can not be combined with `range` - range filters are only applied via `list_for_filters`",
+            ));
+        }
+        if json_path.is_some() && (range || !by_columns.is_empty()) {
+            return Err(darling::Error::custom(
+                "`json_path` can not be combined with `by(...)` or `range` - json path filters are only applied via `list_for_filters`",
+            ));
+        }
+        Ok(ListForOpts {
+            by_columns,
+            range,
+            json_path,
+        })
+    }
+}
+
+/// Splits `metadata->>'region'` (or `metadata->'region'`) into its keys.
+///
+/// Both arrows are accepted as the filter compares the JSON values themselves.
+fn parse_json_path(path: &str) -> darling::Result<Vec<String>> {
+    path.split("->")
+        .map(|key| {
+            let key = key.strip_prefix('>').unwrap_or(key).trim();
+            let key = key
+                .strip_prefix('\'')
+                .and_then(|k| k.strip_suffix('\''))
+                .unwrap_or(key);
+            if key.is_empty()
+                || key
+                    .chars()
+                    .any(|c| c.is_whitespace() || matches!(c, '\'' | '"' | '{' | '}' | ',' | '\\'))
+            {
+                Err(darling::Error::custom(format!(
+                    "invalid key '{key}' in json_path '{path}' - expected eg. `metadata->>'region'`"
+                )))
+            } else {
+                Ok(key.to_string())
+            }
+        })
+        .collect()
+}
+
+/// `list_by` is either a flag or `list_by(then = other_column)` which orders by
+/// `other_column` before falling back to `id` for rows with the same value.
+#[derive(PartialEq, Clone, Debug, Default)]
+struct ListByOpts {
+    enabled: bool,
+    then: Option<syn::Ident>,
+}
+
+impl FromMeta for ListByOpts {
+    fn from_word() -> darling::Result<Self> {
+        Ok(ListByOpts {
+            enabled: true,
+            then: None,
+        })
+    }
+
+    fn from_bool(enabled: bool) -> darling::Result<Self> {
+        Ok(ListByOpts {
+            enabled,
+            then: None,
+        })
+    }
+
+    fn from_list(items: &[darling::ast::NestedMeta]) -> darling::Result<Self> {
+        #[derive(FromMeta)]
+        struct Inner {
+            then: syn::Ident,
+        }
+
+        let inner = Inner::from_list(items)?;
+        Ok(ListByOpts {
+            enabled: true,
+            then: Some(inner.then),
+        })
     }
 }
 
-#[derive(PartialEq, Debug, Default)]
+#[derive(PartialEq, Clone, Debug, Default)]
 struct ParentOpts {
     accessor: Option<syn::Expr>,
 }
@@ -986,6 +1424,61 @@ mod tests {
         assert!(!columns.all[1].opts.persist_on_update());
     }
 
+    #[test]
+    fn find_all_flag() {
+        let input: syn::Meta = parse_quote!(batch_id(ty = "BatchId", find_all));
+        let values = ColumnOpts::from_meta(&input).expect("Failed to parse Field");
+        assert!(values.find_all());
+        assert!(values.find_by());
+
+        let input: syn::Meta = parse_quote!(batch_id(ty = "BatchId"));
+        let values = ColumnOpts::from_meta(&input).expect("Failed to parse Field");
+        assert!(!values.find_all());
+    }
+
+    #[test]
+    fn group_count_flag() {
+        let input: syn::Meta = parse_quote!(status(ty = "Status", group_count));
+        let values = ColumnOpts::from_meta(&input).expect("Failed to parse Field");
+        assert!(values.group_count());
+
+        let input: syn::Meta = parse_quote!(status(ty = "Status"));
+        let values = ColumnOpts::from_meta(&input).expect("Failed to parse Field");
+        assert!(!values.group_count());
+    }
+
+    #[test]
+    fn create_default() {
+        let input: syn::Meta = parse_quote!(status(
+            ty = "String",
+            create(default = "String::from(\"pending\")")
+        ));
+        let values = ColumnOpts::from_meta(&input).expect("Failed to parse Field");
+        assert!(values.persist_on_create());
+        assert_eq!(
+            values.create_default().unwrap(),
+            &parse_quote!(String::from("pending"))
+        );
+
+        let column = Column {
+            name: parse_quote!(status),
+            opts: values,
+            list_by_then: None,
+        };
+        assert_eq!(
+            column
+                .variable_assignment_for_create(&parse_quote!(new_entity))
+                .to_string(),
+            quote!(let status = &(String::from("pending"));).to_string()
+        );
+
+        let input: syn::Meta = parse_quote!(status(
+            ty = "String",
+            create(accessor = "status()", default = "String::new()")
+        ));
+        assert!(ColumnOpts::from_meta(&input).is_err());
+    }
+
     #[test]
     fn parent_opts_from_list() {
         let input: syn::Meta = parse_quote!(thing(ty = "String", parent));
@@ -1056,6 +1549,126 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn list_by_then() {
+        let columns = Columns::new(
+            &parse_quote!(TestId),
+            [
+                "rank(ty = \"i32\", list_by(then = name))",
+                "name(ty = \"String\")",
+            ]
+            .into_iter()
+            .map(|c| {
+                let meta: syn::Meta = syn::parse_str(c).unwrap();
+                Column::from_nested_meta(&darling::ast::NestedMeta::Meta(meta)).unwrap()
+            }),
+        );
+        let rank = columns.find_list_by(&parse_quote!(rank)).unwrap();
+        assert_eq!(rank.list_by_then().unwrap().name().to_string(), "name");
+        assert!(columns.validate_list_by_then().is_ok());
+
+        let input: syn::Meta = parse_quote!(rank(ty = "i32", list_by(then = missing)));
+        let column = Column::from_nested_meta(&darling::ast::NestedMeta::Meta(input)).unwrap();
+        let columns = Columns::new(&parse_quote!(TestId), vec![column]);
+        let err = columns.validate_list_by_then().unwrap_err().to_string();
+        assert!(
+            err.contains("missing"),
+            "error should mention the column: {err}"
+        );
+
+        let input: syn::Meta = parse_quote!(rank(ty = "Option<i32>", list_by(then = created_at)));
+        let column = Column::from_nested_meta(&darling::ast::NestedMeta::Meta(input)).unwrap();
+        let columns = Columns::new(&parse_quote!(TestId), vec![column]);
+        assert!(columns.validate_list_by_then().is_err());
+    }
+
+    #[test]
+    fn list_for_range() {
+        let input: syn::Meta = parse_quote!(thing(ty = "String", list_for(range)));
+        let column = Column::from_nested_meta(&darling::ast::NestedMeta::Meta(input))
+            .expect("Failed to parse Column");
+        assert!(column.is_list_for_range());
+        assert!(column.list_for_by_columns().is_empty());
+
+        let input: syn::Meta = parse_quote!(thing(ty = "String", list_for(range, by(id))));
+        assert!(ColumnOpts::from_meta(&input).is_err());
+    }
+
+    #[test]
+    fn list_for_json_path() {
+        let input: syn::Meta = parse_quote!(region(
+            ty = "String",
+            list_for(json_path = "metadata->>'region'")
+        ));
+        let column = Column::from_nested_meta(&darling::ast::NestedMeta::Meta(input))
+            .expect("Failed to parse Column");
+        assert_eq!(
+            column.list_for_json_path(),
+            Some(&["metadata".to_string(), "region".to_string()][..])
+        );
+        assert!(column.list_for_by_columns().is_empty());
+        assert!(!column.opts.find_by());
+        assert!(!column.opts.persist_on_create());
+        assert!(!column.opts.persist_on_update());
+
+        let columns = Columns::new(&parse_quote!(TestId), vec![column]);
+        assert_eq!(columns.column_enum_columns().count(), 1);
+        assert!(columns.validate_list_for_by_columns().is_ok());
+
+        let input: syn::Meta = parse_quote!(region(
+            ty = "String",
+            list_for(json_path = "metadata", range)
+        ));
+        assert!(ColumnOpts::from_meta(&input).is_err());
+        let input: syn::Meta =
+            parse_quote!(region(ty = "String", list_for(json_path = "a->'b c'")));
+        assert!(ColumnOpts::from_meta(&input).is_err());
+
+        let input: syn::Meta = parse_quote!(region(
+            ty = "String",
+            find_by = true,
+            list_for(json_path = "region")
+        ));
+        let column = Column::from_nested_meta(&darling::ast::NestedMeta::Meta(input)).unwrap();
+        let columns = Columns::new(&parse_quote!(TestId), vec![column]);
+        assert!(columns.validate_list_for_by_columns().is_err());
+    }
+
+    #[test]
+    fn created_at_list_for_range() {
+        let input: syn::Meta =
+            parse_quote!(columns(created_at(list_for(range)), name(ty = "String")));
+        let mut columns = Columns::from_meta(&input).expect("Failed to parse Columns");
+        columns.set_id_column(&parse_quote!(TestId));
+        let created_at = columns
+            .all_list_for()
+            .next()
+            .expect("created_at should be list_for");
+        assert_eq!(created_at.name().to_string(), "created_at");
+        assert!(created_at.is_list_for_range());
+    }
+
+    #[test]
+    fn updated_at_is_maintained_by_framework() {
+        let input: syn::Meta = parse_quote!(columns(updated_at, name(ty = "String")));
+        let mut columns = Columns::from_meta(&input).expect("Failed to parse Columns");
+        columns.set_id_column(&parse_quote!(TestId));
+        columns.set_recorded_at_precision(Some(RecordedAtPrecision::Milliseconds));
+        assert!(
+            columns
+                .column_enum_columns()
+                .all(|c| c.name() != "updated_at")
+        );
+        assert_eq!(
+            columns.updated_at_expr("$3").as_deref(),
+            Some("date_trunc('milliseconds', COALESCE($3, NOW()))")
+        );
+
+        let input: syn::Meta = parse_quote!(columns(name(ty = "String")));
+        let columns = Columns::from_meta(&input).expect("Failed to parse Columns");
+        assert!(columns.updated_at_expr("$3").is_none());
+    }
+
     #[test]
     fn list_for_with_multiple_by_columns() {
         let input: syn::Meta = parse_quote!(thing(ty = "String", list_for(by(created_at, id))));
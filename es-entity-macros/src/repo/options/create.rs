@@ -0,0 +1,40 @@
+use darling::FromMeta;
+
+/// Repo level `create(...)` options.
+#[derive(Debug, Default, Clone, Copy, FromMeta)]
+pub struct CreateOption {
+    #[darling(default)]
+    pub on_conflict: OnConflict,
+}
+
+impl CreateOption {
+    pub fn ignores_conflicts(&self) -> bool {
+        self.on_conflict == OnConflict::Ignore
+    }
+}
+
+/// What `create` does when a row with the same id already exists.
+#[derive(Debug, Default, Clone, Copy, FromMeta, PartialEq)]
+pub enum OnConflict {
+    /// Raise the unique violation as `ConstraintViolation`.
+    #[default]
+    Error,
+    /// `ON CONFLICT (id) DO NOTHING` and return `AlreadyExists`.
+    Ignore,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_on_conflict() {
+        let meta: syn::Meta = syn::parse_quote!(create(on_conflict = "ignore"));
+        let opts = CreateOption::from_meta(&meta).unwrap();
+        assert!(opts.ignores_conflicts());
+
+        let meta: syn::Meta = syn::parse_quote!(create());
+        let opts = CreateOption::from_meta(&meta).unwrap();
+        assert!(!opts.ignores_conflicts());
+    }
+}
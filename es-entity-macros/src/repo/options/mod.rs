@@ -1,4 +1,5 @@
 mod columns;
+mod create;
 mod delete;
 
 use convert_case::{Case, Casing};
@@ -7,8 +8,58 @@ use proc_macro2::Span;
 use quote::quote;
 
 pub use columns::*;
+pub use create::*;
 pub use delete::*;
 
+/// Number of entities persisted per statement by `create_all` / `update_all`
+/// when the repo does not set `batch_size`.
+pub const DEFAULT_BATCH_SIZE: usize = 1000;
+
+/// Precision `recorded_at` is truncated to when persisting events
+/// (`recorded_at_precision = "ms"` / `"us"`).
+#[derive(Debug, Clone, Copy, FromMeta, PartialEq)]
+pub enum RecordedAtPrecision {
+    #[darling(rename = "ms")]
+    Milliseconds,
+    #[darling(rename = "us")]
+    Microseconds,
+}
+
+impl RecordedAtPrecision {
+    /// SQL expression stamping `recorded_at` from the bound `now` parameter.
+    pub fn recorded_at_expr(precision: Option<Self>, now_param: &str) -> String {
+        let expr = format!("COALESCE({now_param}, NOW())");
+        match precision {
+            None => expr,
+            Some(Self::Milliseconds) => format!("date_trunc('milliseconds', {expr})"),
+            Some(Self::Microseconds) => format!("date_trunc('microseconds', {expr})"),
+        }
+    }
+}
+
+/// Tags the rows of an events table shared by several repos
+/// (`events_discriminator(column = "...", value = "...")`).
+#[derive(Debug, Clone, FromMeta)]
+pub struct EventsDiscriminator {
+    pub column: String,
+    pub value: String,
+}
+
+impl EventsDiscriminator {
+    /// The discriminator value as an SQL string literal.
+    pub fn value_literal(&self) -> String {
+        format!("'{}'", self.value.replace('\'', "''"))
+    }
+
+    /// Predicate restricting the events (optionally qualified by `alias`) to this discriminator.
+    pub fn condition(&self, alias: Option<&str>) -> String {
+        match alias {
+            Some(alias) => format!(" AND {}.{} = {}", alias, self.column, self.value_literal()),
+            None => format!(" AND {} = {}", self.column, self.value_literal()),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct PostPersistHookConfig {
     pub method: syn::Ident,
@@ -109,11 +160,125 @@ impl FromMeta for PostHydrateHookConfig {
     }
 }
 
+/// A unique constraint spanning multiple columns:
+/// `unique(columns = "tenant_id, email", constraint = "accounts_tenant_id_email_key")`.
+///
+/// `constraint` defaults to the Postgres naming convention `{table}_{col1}_{col2}_key`.
+#[derive(Debug, Clone, FromMeta)]
+pub struct UniqueConstraint {
+    columns: String,
+    #[darling(default)]
+    constraint: Option<String>,
+}
+
+impl UniqueConstraint {
+    pub fn column_names(&self) -> Vec<String> {
+        self.columns
+            .split(',')
+            .map(|c| c.trim().to_string())
+            .filter(|c| !c.is_empty())
+            .collect()
+    }
+
+    pub fn constraint_name(&self, table_name: &str) -> String {
+        self.constraint
+            .clone()
+            .unwrap_or_else(|| format!("{table_name}_{}_key", self.column_names().join("_")))
+    }
+}
+
+/// Toggles for the optional generated functions. The list functions are generated by
+/// default and can be skipped (`generate(list = false)`), the remaining helpers are
+/// opt-in (`generate(delete_all)`).
+#[derive(Debug, Clone, Default, FromMeta)]
+pub struct GenerateOptions {
+    /// Shorthand for disabling all of the toggles below.
+    #[darling(default)]
+    list: Option<bool>,
+    /// `list_by_<column>`. `list_by_id` is kept if `stream_all` is generated as
+    /// it pages through it.
+    #[darling(default)]
+    list_by: Option<bool>,
+    /// `list_for_<column>_by_<column>`.
+    #[darling(default)]
+    list_for: Option<bool>,
+    /// `list_for_filters` and `list_for_filters_by_<column>`.
+    #[darling(default)]
+    list_for_filters: Option<bool>,
+    /// `delete_all` for soft-delete repos.
+    #[darling(default)]
+    delete_all: bool,
+    /// `stream_all`.
+    #[darling(default)]
+    stream_all: bool,
+    /// `events_between`.
+    #[darling(default)]
+    events_between: bool,
+    /// `exists_by_<column>` for every `find_by` column.
+    #[darling(default)]
+    exists_by: bool,
+    /// `list_by_id_parallel`.
+    #[darling(default)]
+    list_by_id_parallel: bool,
+    /// `upsert_in_op`, not supported on repos with nested entities.
+    #[darling(default)]
+    upsert: bool,
+    /// `load_events_raw`.
+    #[darling(default)]
+    load_events_raw: bool,
+}
+
+impl GenerateOptions {
+    pub fn list_by(&self) -> bool {
+        self.list.unwrap_or(true) && self.list_by.unwrap_or(true)
+    }
+
+    pub fn list_for(&self) -> bool {
+        self.list.unwrap_or(true) && self.list_for.unwrap_or(true)
+    }
+
+    /// `list_for_filters` dispatches to the `list_by_*` / `list_for_*` fns so it
+    /// is only generated together with them.
+    pub fn list_for_filters(&self) -> bool {
+        self.list_by() && self.list_for() && self.list_for_filters.unwrap_or(true)
+    }
+
+    pub fn delete_all(&self) -> bool {
+        self.delete_all
+    }
+
+    pub fn stream_all(&self) -> bool {
+        self.stream_all
+    }
+
+    pub fn events_between(&self) -> bool {
+        self.events_between
+    }
+
+    pub fn exists_by(&self) -> bool {
+        self.exists_by
+    }
+
+    pub fn list_by_id_parallel(&self) -> bool {
+        self.list_by_id_parallel
+    }
+
+    pub fn upsert(&self) -> bool {
+        self.upsert
+    }
+
+    pub fn load_events_raw(&self) -> bool {
+        self.load_events_raw
+    }
+}
+
 /// Information about the clock field in a repository
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 pub enum ClockFieldInfo<'a> {
     /// No clock field present
     None,
+    /// Clock configured via `clock = "..."` - always use it
+    Accessor(&'a syn::Expr),
     /// Clock field is `Option<ClockHandle>` - use if Some, fallback to global
     Optional(&'a syn::Ident),
     /// Clock field is `ClockHandle` - always use it
@@ -216,7 +381,14 @@ pub struct RepositoryOptions {
     #[darling(default)]
     pub post_persist_hook: Option<PostPersistHookConfig>,
     #[darling(default)]
+    pub post_persist_async_spawn: bool,
+    #[darling(default)]
     pub post_hydrate_hook: Option<PostHydrateHookConfig>,
+    /// Method receiving the serialized events right before they are inserted.
+    #[darling(default)]
+    pub pre_persist_hook: Option<syn::Ident>,
+    #[darling(default)]
+    pub create: CreateOption,
     #[darling(default)]
     pub delete: DeleteOption,
 
@@ -238,9 +410,38 @@ pub struct RepositoryOptions {
     #[darling(default)]
     persist_event_context: Option<bool>,
     #[darling(default)]
+    event_context_column: Option<String>,
+    #[darling(default)]
+    events_discriminator: Option<EventsDiscriminator>,
+    #[darling(default)]
     forgettable: bool,
     #[darling(default, rename = "forgettable_tbl")]
     forgettable_table_name: Option<String>,
+    #[darling(default)]
+    batch_size: Option<darling::util::SpannedValue<usize>>,
+    #[darling(default)]
+    page_probe: bool,
+    #[darling(default)]
+    pub generate: GenerateOptions,
+    /// Generates `hard_delete` which removes the rows instead of marking them deleted.
+    #[darling(default)]
+    allow_hard_delete: bool,
+    /// Generates `find_by_id_from_events` which rebuilds an entity from its events alone.
+    #[darling(default)]
+    allow_find_by_id_from_events: bool,
+    /// Channel that receives a `pg_notify` with the entity id whenever events are persisted.
+    #[darling(default)]
+    notify_channel: Option<String>,
+    /// Accessor (eg. `replica_pool()`) for the pool used by the convenience read functions.
+    #[darling(default)]
+    read_pool_accessor: Option<syn::Expr>,
+    /// Clock (eg. `self.clock()`) whose `now()` is persisted when the op has no cached time.
+    #[darling(default)]
+    clock: Option<syn::Expr>,
+    #[darling(default)]
+    recorded_at_precision: Option<RecordedAtPrecision>,
+    #[darling(default, multiple, rename = "unique")]
+    unique_constraints: Vec<UniqueConstraint>,
 }
 
 impl RepositoryOptions {
@@ -283,6 +484,8 @@ impl RepositoryOptions {
 
         self.columns
             .set_id_column(self.id_ty.as_ref().expect("Id not set"));
+        self.columns
+            .set_recorded_at_precision(self.recorded_at_precision);
 
         self
     }
@@ -295,6 +498,21 @@ impl RepositoryOptions {
         self.table_name.as_ref().expect("Table name is not set")
     }
 
+    pub fn batch_size(&self) -> usize {
+        self.batch_size
+            .as_ref()
+            .map(|size| **size)
+            .unwrap_or(DEFAULT_BATCH_SIZE)
+    }
+
+    pub fn unique_constraints(&self) -> &[UniqueConstraint] {
+        &self.unique_constraints
+    }
+
+    pub fn recorded_at_precision(&self) -> Option<RecordedAtPrecision> {
+        self.recorded_at_precision
+    }
+
     pub fn table_prefix(&self) -> Option<&syn::LitStr> {
         self.prefix.as_ref()
     }
@@ -320,6 +538,36 @@ impl RepositoryOptions {
         }
     }
 
+    /// The column holding the serialized `ContextData` if it is not `context`.
+    pub fn custom_event_context_column(&self) -> Option<&str> {
+        self.event_context_column.as_deref()
+    }
+
+    pub fn event_context_column(&self) -> &str {
+        self.custom_event_context_column().unwrap_or("context")
+    }
+
+    /// Set when the events table is shared with other repos.
+    pub fn events_discriminator(&self) -> Option<&EventsDiscriminator> {
+        self.events_discriminator.as_ref()
+    }
+
+    /// Extra `es_query!` args pointing at a shared events table and its discriminator.
+    pub fn es_query_events_discriminator_args(&self) -> proc_macro2::TokenStream {
+        if let Some(discriminator) = &self.events_discriminator {
+            let events_tbl = self.events_table_name();
+            let column = &discriminator.column;
+            let value = &discriminator.value;
+            quote! {
+                events_tbl = #events_tbl,
+                discriminator_column = #column,
+                discriminator = #value,
+            }
+        } else {
+            quote! {}
+        }
+    }
+
     pub fn events_table_name(&self) -> &str {
         self.events_table_name
             .as_ref()
@@ -341,6 +589,10 @@ impl RepositoryOptions {
         self.ident.to_string().to_case(Case::Snake)
     }
 
+    pub fn read_pool_accessor(&self) -> Option<&syn::Expr> {
+        self.read_pool_accessor.as_ref()
+    }
+
     pub fn pool_field(&self) -> &syn::Ident {
         let field = match &self.data {
             darling::ast::Data::Struct(fields) => fields.iter().find_map(|field| {
@@ -356,6 +608,9 @@ impl RepositoryOptions {
     }
 
     pub fn clock_field(&self) -> ClockFieldInfo<'_> {
+        if let Some(clock) = &self.clock {
+            return ClockFieldInfo::Accessor(clock);
+        }
         match &self.data {
             darling::ast::Data::Struct(fields) => {
                 for field in fields.iter() {
@@ -465,14 +720,25 @@ impl RepositoryOptions {
         syn::Ident::new(&format!("{}Column", self.entity_ident), Span::call_site())
     }
 
-    pub fn query_fn_get_op(nested: bool) -> proc_macro2::TokenStream {
+    /// Pool of the convenience read functions - `read_pool()` is only generated
+    /// together with `read_pool_accessor`.
+    pub fn read_pool(has_read_pool: bool) -> proc_macro2::TokenStream {
+        if has_read_pool {
+            quote! { self.read_pool() }
+        } else {
+            quote! { self.pool() }
+        }
+    }
+
+    pub fn query_fn_get_op(nested: bool, has_read_pool: bool) -> proc_macro2::TokenStream {
+        let read_pool = Self::read_pool(has_read_pool);
         if nested {
             quote! {
-                &mut self.pool().begin().await?
+                &mut #read_pool.begin().await?
             }
         } else {
             quote! {
-                self.pool()
+                #read_pool
             }
         }
     }
@@ -481,6 +747,52 @@ impl RepositoryOptions {
         self.forgettable
     }
 
+    pub fn page_probe_enabled(&self) -> bool {
+        self.page_probe
+    }
+
+    pub fn hard_delete_enabled(&self) -> bool {
+        self.allow_hard_delete
+    }
+
+    pub fn find_by_id_from_events_enabled(&self) -> bool {
+        self.allow_find_by_id_from_events
+    }
+
+    pub fn notify_channel(&self) -> Option<&str> {
+        self.notify_channel.as_deref()
+    }
+
+    /// Timestamp bound in place of the database `NOW()` when persisting.
+    ///
+    /// The time cached on the op wins so that all writes of a transaction agree.
+    pub fn op_now_arg(clock: ClockFieldInfo) -> proc_macro2::TokenStream {
+        match clock {
+            ClockFieldInfo::None => quote! { op.maybe_now() },
+            ClockFieldInfo::Accessor(clock) => {
+                quote! { op.maybe_now().or_else(|| Some(#clock.now())) }
+            }
+            ClockFieldInfo::Optional(clock_field) => {
+                quote! { op.maybe_now().or_else(|| self.#clock_field.as_ref().map(|clock| clock.now())) }
+            }
+            ClockFieldInfo::Required(clock_field) => {
+                quote! { op.maybe_now().or_else(|| Some(self.#clock_field.now())) }
+            }
+        }
+    }
+
+    /// `es_query!` arg marking `limit_param` as the `first + 1` page probe.
+    pub fn es_query_probe_limit_arg(
+        page_probe: bool,
+        limit_param: &str,
+    ) -> proc_macro2::TokenStream {
+        if page_probe {
+            quote! { probe_limit = #limit_param, }
+        } else {
+            quote! {}
+        }
+    }
+
     /// Errors if the repo declares `Forgettable<T>` index columns but does not
     /// enable `forgettable`. Both facts are known at macro time (unlike event
     /// forgettable-ness, which the repo cannot see — that is guarded by a
@@ -495,6 +807,86 @@ impl RepositoryOptions {
         Ok(())
     }
 
+    /// `batch_size` chunks the inputs of the batched fns, an empty chunk would never make progress.
+    pub fn validate_batch_size(&self) -> darling::Result<()> {
+        if let Some(size) = &self.batch_size
+            && **size == 0
+        {
+            return Err(
+                darling::Error::custom("`batch_size` must be at least 1").with_span(&size.span())
+            );
+        }
+        Ok(())
+    }
+
+    /// The clock is either detected from a field or configured via `clock = "..."`, not both.
+    pub fn validate_clock(&self) -> darling::Result<()> {
+        if let (Some(clock), darling::ast::Data::Struct(fields)) = (&self.clock, &self.data)
+            && fields.iter().any(|f| f.is_clock_field())
+        {
+            return Err(darling::Error::custom(
+                "`clock = \"...\"` cannot be combined with a `clock` field; \
+                 remove one of them",
+            )
+            .with_span(clock));
+        }
+        Ok(())
+    }
+
+    /// Nested entities are not erased along with their parent.
+    pub fn validate_hard_delete(&self) -> darling::Result<()> {
+        if self.allow_hard_delete && self.any_nested() {
+            return Err(darling::Error::custom(
+                "`allow_hard_delete` is not supported on repos with nested entities",
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn validate_upsert(&self) -> darling::Result<()> {
+        if self.generate.upsert() && self.any_nested() {
+            return Err(darling::Error::custom(
+                "`generate(upsert)` is not supported on repos with nested entities",
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn validate_post_persist_hook(&self) -> darling::Result<()> {
+        if self.post_persist_async_spawn && self.post_persist_hook.is_none() {
+            return Err(darling::Error::custom(
+                "`post_persist_async_spawn` requires a `post_persist_hook`",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Errors if a composite `unique(...)` constraint names fewer than two
+    /// columns or a column that is not declared on the repo.
+    pub fn validate_unique_constraints(&self) -> darling::Result<()> {
+        for unique in &self.unique_constraints {
+            let names = unique.column_names();
+            if names.len() < 2 {
+                return Err(darling::Error::custom(
+                    "`unique(columns = ...)` needs at least two columns; \
+                     use `constraint = ...` on the column for single column constraints",
+                ));
+            }
+            for name in names {
+                if !self
+                    .columns
+                    .column_enum_columns()
+                    .any(|c| *c.name() == name)
+                {
+                    return Err(darling::Error::custom(format!(
+                        "`unique(columns = ...)` references unknown column `{name}`"
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
     pub fn forgettable_table_name(&self) -> Option<&str> {
         if self.forgettable {
             Some(self.forgettable_table_name.as_deref().unwrap_or_else(|| {
@@ -506,3 +898,97 @@ impl RepositoryOptions {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recorded_at_precision() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[es_repo(entity = "Entity", recorded_at_precision = "ms")]
+            pub struct Entities {
+                pool: sqlx::PgPool,
+            }
+        };
+        let opts = RepositoryOptions::from_derive_input(&input).unwrap();
+        assert_eq!(
+            opts.recorded_at_precision(),
+            Some(RecordedAtPrecision::Milliseconds)
+        );
+
+        assert_eq!(
+            RecordedAtPrecision::recorded_at_expr(None, "$2"),
+            "COALESCE($2, NOW())"
+        );
+        assert_eq!(
+            RecordedAtPrecision::recorded_at_expr(Some(RecordedAtPrecision::Milliseconds), "$2"),
+            "date_trunc('milliseconds', COALESCE($2, NOW()))"
+        );
+    }
+
+    #[test]
+    fn batch_size() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[es_repo(entity = "Entity", batch_size = 500)]
+            pub struct Entities {
+                pool: sqlx::PgPool,
+            }
+        };
+        let opts = RepositoryOptions::from_derive_input(&input).unwrap();
+        assert!(opts.validate_batch_size().is_ok());
+        assert_eq!(opts.batch_size(), 500);
+
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[es_repo(entity = "Entity", batch_size = 0)]
+            pub struct Entities {
+                pool: sqlx::PgPool,
+            }
+        };
+        let opts = RepositoryOptions::from_derive_input(&input).unwrap();
+        let err = opts.validate_batch_size().unwrap_err();
+        assert!(err.to_string().contains("`batch_size` must be at least 1"));
+
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[es_repo(entity = "Entity", batch_size = -1)]
+            pub struct Entities {
+                pool: sqlx::PgPool,
+            }
+        };
+        assert!(RepositoryOptions::from_derive_input(&input).is_err());
+    }
+
+    #[test]
+    fn clock_field_binds_timestamp() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[es_repo(entity = "Entity")]
+            pub struct Entities {
+                pool: sqlx::PgPool,
+                clock: es_entity::clock::ClockHandle,
+            }
+        };
+        let opts = RepositoryOptions::from_derive_input(&input).unwrap();
+        assert!(opts.validate_clock().is_ok());
+        assert_eq!(
+            RepositoryOptions::op_now_arg(opts.clock_field()).to_string(),
+            quote! { op.maybe_now().or_else(|| Some(self.clock.now())) }.to_string()
+        );
+    }
+
+    #[test]
+    fn clock_option_conflicts_with_clock_field() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[es_repo(entity = "Entity", clock = "self.sim_clock()")]
+            pub struct Entities {
+                pool: sqlx::PgPool,
+                clock: es_entity::clock::ClockHandle,
+            }
+        };
+        let opts = RepositoryOptions::from_derive_input(&input).unwrap();
+        let err = opts.validate_clock().unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("cannot be combined with a `clock` field")
+        );
+    }
+}
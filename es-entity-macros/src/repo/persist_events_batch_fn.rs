@@ -9,7 +9,12 @@ pub struct PersistEventsBatchFn<'a> {
     event: &'a syn::Ident,
     events_table_name: &'a str,
     event_ctx: bool,
+    event_context_column: &'a str,
+    events_discriminator: Option<&'a EventsDiscriminator>,
     forgettable_table_name: Option<&'a str>,
+    notify_channel: Option<&'a str>,
+    recorded_at_precision: Option<RecordedAtPrecision>,
+    clock: ClockFieldInfo<'a>,
 }
 
 impl<'a> From<&'a RepositoryOptions> for PersistEventsBatchFn<'a> {
@@ -19,28 +24,50 @@ impl<'a> From<&'a RepositoryOptions> for PersistEventsBatchFn<'a> {
             event: opts.event(),
             events_table_name: opts.events_table_name(),
             event_ctx: opts.event_context_enabled(),
+            event_context_column: opts.event_context_column(),
+            events_discriminator: opts.events_discriminator(),
             forgettable_table_name: opts.forgettable_table_name(),
+            notify_channel: opts.notify_channel(),
+            recorded_at_precision: opts.recorded_at_precision(),
+            clock: opts.clock_field(),
         }
     }
 }
 
 impl ToTokens for PersistEventsBatchFn<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
+        let op_now = RepositoryOptions::op_now_arg(self.clock);
         let id_type = &self.id;
         let event_type = &self.event;
 
+        let (discriminator_column, discriminator_value) = self
+            .events_discriminator
+            .map(|d| {
+                (
+                    format!(", {}", d.column),
+                    format!(", {}", d.value_literal()),
+                )
+            })
+            .unwrap_or_default();
         let query = format!(
-            "INSERT INTO {} (id, recorded_at, sequence, event_type, event{}) \
-             SELECT unnested.id, COALESCE($1, NOW()), unnested.sequence, unnested.event_type, unnested.event{} \
+            "INSERT INTO {} (id, recorded_at, sequence, event_type, event{}{}) \
+             SELECT unnested.id, {}, unnested.sequence, unnested.event_type, unnested.event{}{} \
              FROM UNNEST($2, $3::INT[], $4::TEXT[], $5::JSONB[]{}) \
              AS unnested(id, sequence, event_type, event{}) RETURNING recorded_at",
             self.events_table_name,
-            if self.event_ctx { ", context" } else { "" },
+            if self.event_ctx {
+                format!(", {}", self.event_context_column)
+            } else {
+                String::new()
+            },
+            discriminator_column,
+            RecordedAtPrecision::recorded_at_expr(self.recorded_at_precision, "$1"),
             if self.event_ctx {
                 ", unnested.context"
             } else {
                 ""
             },
+            discriminator_value,
             if self.event_ctx { ", $6::JSONB[]" } else { "" },
             if self.event_ctx { ", context" } else { "" }
         );
@@ -48,10 +75,10 @@ impl ToTokens for PersistEventsBatchFn<'_> {
         let (ctx_var, ctx_extend, ctx_bind) = if self.event_ctx {
             (
                 quote! {
-                    let mut all_contexts: Vec<es_entity::ContextData> = Vec::new();
+                    let mut all_contexts: Vec<Option<es_entity::ContextData>> = Vec::new();
                 },
                 quote! {
-                    let contexts = events.serialize_new_event_contexts();
+                    let contexts = events.serialize_new_event_contexts(op.pinned_context());
                     if let Some(contexts) = contexts {
                         all_contexts.extend(contexts);
                     }
@@ -111,6 +138,19 @@ impl ToTokens for PersistEventsBatchFn<'_> {
             quote! {}
         };
 
+        let notify = if let Some(channel) = self.notify_channel {
+            quote! {
+                let notify_ids: Vec<&#id_type> = n_events_map.keys().collect();
+                sqlx::query("SELECT pg_notify($1, unnested.id::TEXT) FROM UNNEST($2) AS unnested(id)")
+                    .bind(#channel)
+                    .bind(&notify_ids)
+                    .execute(op.as_executor())
+                    .await?;
+            }
+        } else {
+            quote! {}
+        };
+
         tokens.append_all(quote! {
             async fn persist_events_batch<OP, B>(
                 &self,
@@ -129,7 +169,7 @@ impl ToTokens for PersistEventsBatchFn<'_> {
                 let mut all_types = Vec::new();
                 let mut all_ids: Vec<&#id_type> = Vec::new();
                 let mut all_sequences = Vec::new();
-                let now = op.maybe_now();
+                let now = #op_now;
 
                 let mut n_events_map = std::collections::HashMap::new();
                 for item in all_events.iter() {
@@ -148,6 +188,7 @@ impl ToTokens for PersistEventsBatchFn<'_> {
                     all_sequences.extend((offset..).take(n_events).map(|i| i as i32));
                     n_events_map.insert(id.clone(), n_events);
                 }
+                self.execute_pre_persist_hook(&mut all_serialized);
 
                 let rows = sqlx::query(#query)
                         .bind(now)
@@ -160,6 +201,7 @@ impl ToTokens for PersistEventsBatchFn<'_> {
                         .await?;
 
                 #forgettable_insert
+                #notify
 
                 let recorded_at = rows[0].try_get("recorded_at").expect("no recorded at");
 
@@ -187,7 +229,12 @@ mod tests {
             event: &event,
             events_table_name: "entity_events",
             event_ctx: true,
+            event_context_column: "context",
+            events_discriminator: None,
             forgettable_table_name: None,
+            notify_channel: None,
+            recorded_at_precision: None,
+            clock: ClockFieldInfo::None,
         };
 
         let mut tokens = TokenStream::new();
@@ -206,7 +253,7 @@ mod tests {
                 use es_entity::prelude::sqlx::Row;
 
                 let mut all_serialized = Vec::new();
-                let mut all_contexts: Vec<es_entity::ContextData> = Vec::new();
+                let mut all_contexts: Vec<Option<es_entity::ContextData>> = Vec::new();
                 let mut all_types = Vec::new();
                 let mut all_ids: Vec<&EntityId> = Vec::new();
                 let mut all_sequences = Vec::new();
@@ -219,7 +266,7 @@ mod tests {
                     let offset = events.len_persisted() + 1;
                     let types = events.new_event_types();
                     let serialized = events.serialize_new_events();
-                    let contexts = events.serialize_new_event_contexts();
+                    let contexts = events.serialize_new_event_contexts(op.pinned_context());
                     if let Some(contexts) = contexts {
                         all_contexts.extend(contexts);
                     }
@@ -231,6 +278,7 @@ mod tests {
                     all_sequences.extend((offset..).take(n_events).map(|i| i as i32));
                     n_events_map.insert(id.clone(), n_events);
                 }
+                self.execute_pre_persist_hook(&mut all_serialized);
 
                 let rows = sqlx::query("INSERT INTO entity_events (id, recorded_at, sequence, event_type, event, context) SELECT unnested.id, COALESCE($1, NOW()), unnested.sequence, unnested.event_type, unnested.event, unnested.context FROM UNNEST($2, $3::INT[], $4::TEXT[], $5::JSONB[], $6::JSONB[]) AS unnested(id, sequence, event_type, event, context) RETURNING recorded_at")
                         .bind(now)
@@ -269,7 +317,12 @@ mod tests {
             event: &event,
             events_table_name: "entity_events",
             event_ctx: false,
+            event_context_column: "context",
+            events_discriminator: None,
             forgettable_table_name: None,
+            notify_channel: None,
+            recorded_at_precision: None,
+            clock: ClockFieldInfo::None,
         };
 
         let mut tokens = TokenStream::new();
@@ -308,6 +361,7 @@ mod tests {
                     all_sequences.extend((offset..).take(n_events).map(|i| i as i32));
                     n_events_map.insert(id.clone(), n_events);
                 }
+                self.execute_pre_persist_hook(&mut all_serialized);
 
                 let rows = sqlx::query("INSERT INTO entity_events (id, recorded_at, sequence, event_type, event) SELECT unnested.id, COALESCE($1, NOW()), unnested.sequence, unnested.event_type, unnested.event FROM UNNEST($2, $3::INT[], $4::TEXT[], $5::JSONB[]) AS unnested(id, sequence, event_type, event) RETURNING recorded_at")
                         .bind(now)
@@ -331,4 +385,36 @@ mod tests {
 
         assert_eq!(tokens.to_string(), expected.to_string());
     }
+
+    #[test]
+    fn persist_events_fn_with_notify_channel() {
+        let id = syn::parse_str("EntityId").unwrap();
+        let event = syn::Ident::new("EntityEvent", proc_macro2::Span::call_site());
+        let persist_fn = PersistEventsBatchFn {
+            id: &id,
+            event: &event,
+            events_table_name: "entity_events",
+            event_ctx: false,
+            event_context_column: "context",
+            events_discriminator: None,
+            forgettable_table_name: None,
+            notify_channel: Some("entity_changes"),
+            recorded_at_precision: None,
+            clock: ClockFieldInfo::None,
+        };
+
+        let mut tokens = TokenStream::new();
+        persist_fn.to_tokens(&mut tokens);
+
+        let expected = quote! {
+            let notify_ids: Vec<&EntityId> = n_events_map.keys().collect();
+            sqlx::query("SELECT pg_notify($1, unnested.id::TEXT) FROM UNNEST($2) AS unnested(id)")
+                .bind("entity_changes")
+                .bind(&notify_ids)
+                .execute(op.as_executor())
+                .await?;
+        };
+
+        assert!(tokens.to_string().contains(&expected.to_string()));
+    }
 }
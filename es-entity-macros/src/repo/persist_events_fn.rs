@@ -9,7 +9,12 @@ pub struct PersistEventsFn<'a> {
     event: &'a syn::Ident,
     events_table_name: &'a str,
     event_ctx: bool,
+    event_context_column: &'a str,
+    events_discriminator: Option<&'a EventsDiscriminator>,
     forgettable_table_name: Option<&'a str>,
+    notify_channel: Option<&'a str>,
+    recorded_at_precision: Option<RecordedAtPrecision>,
+    clock: ClockFieldInfo<'a>,
 }
 
 impl<'a> From<&'a RepositoryOptions> for PersistEventsFn<'a> {
@@ -19,31 +24,53 @@ impl<'a> From<&'a RepositoryOptions> for PersistEventsFn<'a> {
             event: opts.event(),
             events_table_name: opts.events_table_name(),
             event_ctx: opts.event_context_enabled(),
+            event_context_column: opts.event_context_column(),
+            events_discriminator: opts.events_discriminator(),
             forgettable_table_name: opts.forgettable_table_name(),
+            notify_channel: opts.notify_channel(),
+            recorded_at_precision: opts.recorded_at_precision(),
+            clock: opts.clock_field(),
         }
     }
 }
 
 impl ToTokens for PersistEventsFn<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
+        let op_now = RepositoryOptions::op_now_arg(self.clock);
+        let (discriminator_column, discriminator_value) = self
+            .events_discriminator
+            .map(|d| {
+                (
+                    format!(", {}", d.column),
+                    format!(", {}", d.value_literal()),
+                )
+            })
+            .unwrap_or_default();
         let query = format!(
-            "INSERT INTO {} (id, recorded_at, sequence, event_type, event{}) SELECT $1, COALESCE($2, NOW()), ROW_NUMBER() OVER () + $3, unnested.event_type, unnested.event{} FROM UNNEST($4::TEXT[], $5::JSONB[]{}) AS unnested(event_type, event{}) RETURNING recorded_at",
+            "INSERT INTO {} (id, recorded_at, sequence, event_type, event{}{}) SELECT $1, {}, ROW_NUMBER() OVER () + $3, unnested.event_type, unnested.event{}{} FROM UNNEST($4::TEXT[], $5::JSONB[]{}) AS unnested(event_type, event{}) RETURNING recorded_at",
             self.events_table_name,
-            if self.event_ctx { ", context" } else { "" },
+            if self.event_ctx {
+                format!(", {}", self.event_context_column)
+            } else {
+                String::new()
+            },
+            discriminator_column,
+            RecordedAtPrecision::recorded_at_expr(self.recorded_at_precision, "$2"),
             if self.event_ctx {
                 ", unnested.context"
             } else {
                 ""
             },
+            discriminator_value,
             if self.event_ctx { ", $6::JSONB[]" } else { "" },
             if self.event_ctx { ", context" } else { "" }
         );
 
         let (ctx_var, ctx_arg) = if self.event_ctx {
             (
-                quote! { let contexts = events.serialize_new_event_contexts(); },
+                quote! { let contexts = events.serialize_new_event_contexts(op.pinned_context()); },
                 quote! {
-                    contexts.as_deref() as Option<&[es_entity::ContextData]>,
+                    contexts.as_deref() as Option<&[Option<es_entity::ContextData>]>,
                 },
             )
         } else {
@@ -84,6 +111,18 @@ impl ToTokens for PersistEventsFn<'_> {
             quote! {}
         };
 
+        let notify = if let Some(channel) = self.notify_channel {
+            quote! {
+                sqlx::query("SELECT pg_notify($1, $2::TEXT)")
+                    .bind(#channel)
+                    .bind(id)
+                    .execute(op.as_executor())
+                    .await?;
+            }
+        } else {
+            quote! {}
+        };
+
         tokens.append_all(quote! {
             fn extract_concurrent_modification<T, __EsErr: From<sqlx::Error>>(
                 res: Result<T, sqlx::Error>,
@@ -109,10 +148,11 @@ impl ToTokens for PersistEventsFn<'_> {
                 let id = events.id();
                 let offset = events.len_persisted();
                 let events_types = events.new_event_types();
-                let serialized_events = events.serialize_new_events();
+                let mut serialized_events = events.serialize_new_events();
+                self.execute_pre_persist_hook(&mut serialized_events);
                 #ctx_var
                 #forgettable_code
-                let now = op.maybe_now();
+                let now = #op_now;
 
                 let rows = sqlx::query!(
                         #query,
@@ -123,6 +163,7 @@ impl ToTokens for PersistEventsFn<'_> {
                         &serialized_events,
                         #ctx_arg
                     ).fetch_all(op.as_executor()).await?;
+                #notify
 
                 let recorded_at = rows[0].recorded_at;
                 let n_events = events.mark_new_events_persisted_at(recorded_at);
@@ -146,7 +187,12 @@ mod tests {
             event: &event,
             events_table_name: "entity_events",
             event_ctx: true,
+            event_context_column: "context",
+            events_discriminator: None,
             forgettable_table_name: None,
+            notify_channel: None,
+            recorded_at_precision: None,
+            clock: ClockFieldInfo::None,
         };
 
         let mut tokens = TokenStream::new();
@@ -177,8 +223,9 @@ mod tests {
                 let id = events.id();
                 let offset = events.len_persisted();
                 let events_types = events.new_event_types();
-                let serialized_events = events.serialize_new_events();
-                let contexts = events.serialize_new_event_contexts();
+                let mut serialized_events = events.serialize_new_events();
+                self.execute_pre_persist_hook(&mut serialized_events);
+                let contexts = events.serialize_new_event_contexts(op.pinned_context());
                 let now = op.maybe_now();
 
                 let rows = sqlx::query!(
@@ -188,7 +235,7 @@ mod tests {
                         offset as i32,
                         &events_types,
                         &serialized_events,
-                        contexts.as_deref() as Option<&[es_entity::ContextData]>,
+                        contexts.as_deref() as Option<&[Option<es_entity::ContextData>]>,
                     ).fetch_all(op.as_executor()).await?;
 
                 let recorded_at = rows[0].recorded_at;
@@ -210,7 +257,12 @@ mod tests {
             event: &event,
             events_table_name: "entity_events",
             event_ctx: false,
+            event_context_column: "context",
+            events_discriminator: None,
             forgettable_table_name: None,
+            notify_channel: None,
+            recorded_at_precision: None,
+            clock: ClockFieldInfo::None,
         };
 
         let mut tokens = TokenStream::new();
@@ -241,7 +293,8 @@ mod tests {
                 let id = events.id();
                 let offset = events.len_persisted();
                 let events_types = events.new_event_types();
-                let serialized_events = events.serialize_new_events();
+                let mut serialized_events = events.serialize_new_events();
+                self.execute_pre_persist_hook(&mut serialized_events);
                 let now = op.maybe_now();
 
                 let rows = sqlx::query!(
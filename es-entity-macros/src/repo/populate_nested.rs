@@ -11,6 +11,7 @@ pub struct PopulateNested<'a> {
     id: &'a syn::Ident,
     table_name: &'a str,
     events_table_name: &'a str,
+    events_discriminator_condition: String,
     repo_types_mod: syn::Ident,
     delete_option: &'a DeleteOption,
     forgettable_table_name: Option<&'a str>,
@@ -26,6 +27,10 @@ impl<'a> PopulateNested<'a> {
             id: opts.id(),
             table_name: opts.table_name(),
             events_table_name: opts.events_table_name(),
+            events_discriminator_condition: opts
+                .events_discriminator()
+                .map(|d| d.condition(Some("e")))
+                .unwrap_or_default(),
             repo_types_mod: opts.repo_types_mod(),
             delete_option: &opts.delete,
             forgettable_table_name: opts.forgettable_table_name(),
@@ -59,13 +64,14 @@ impl ToTokens for PopulateNested<'_> {
             };
 
         let query = format!(
-            "WITH entities AS (SELECT * FROM {} WHERE ({} = ANY($1)){}) SELECT i.id AS \"entity_id: {}\", e.sequence, e.event, CASE WHEN $2 THEN e.context ELSE NULL::jsonb END as \"context: es_entity::ContextData\", e.recorded_at, {} FROM entities i JOIN {} e ON i.id = e.id{} ORDER BY e.id, e.sequence",
+            "WITH entities AS (SELECT * FROM {} WHERE ({} = ANY($1)){}) SELECT i.id AS \"entity_id: {}\", e.sequence, e.event, CASE WHEN $2 THEN e.context ELSE NULL::jsonb END as \"context: es_entity::ContextData\", e.recorded_at, {} FROM entities i JOIN {} e ON i.id = e.id{}{} ORDER BY e.id, e.sequence",
             self.table_name,
             self.column.name(),
             not_deleted_condition,
             self.id,
             payload_column,
             self.events_table_name,
+            self.events_discriminator_condition,
             forgettable_join,
         );
 
@@ -73,12 +79,13 @@ impl ToTokens for PopulateNested<'_> {
 
         let include_deleted_override = if self.delete_option.is_soft() {
             let include_deleted_query = format!(
-                "WITH entities AS (SELECT * FROM {} WHERE ({} = ANY($1))) SELECT i.id AS \"entity_id: {}\", e.sequence, e.event, CASE WHEN $2 THEN e.context ELSE NULL::jsonb END as \"context: es_entity::ContextData\", e.recorded_at, {} FROM entities i JOIN {} e ON i.id = e.id{} ORDER BY e.id, e.sequence",
+                "WITH entities AS (SELECT * FROM {} WHERE ({} = ANY($1))) SELECT i.id AS \"entity_id: {}\", e.sequence, e.event, CASE WHEN $2 THEN e.context ELSE NULL::jsonb END as \"context: es_entity::ContextData\", e.recorded_at, {} FROM entities i JOIN {} e ON i.id = e.id{}{} ORDER BY e.id, e.sequence",
                 self.table_name,
                 self.column.name(),
                 self.id,
                 payload_column,
                 self.events_table_name,
+                self.events_discriminator_condition,
                 forgettable_join,
             );
             quote! {
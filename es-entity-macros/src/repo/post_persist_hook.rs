@@ -9,6 +9,7 @@ pub struct PostPersistHook<'a> {
     event: &'a syn::Ident,
     entity: &'a syn::Ident,
     hook: &'a Option<PostPersistHookConfig>,
+    spawn: bool,
 }
 
 impl<'a> From<&'a RepositoryOptions> for PostPersistHook<'a> {
@@ -17,6 +18,7 @@ impl<'a> From<&'a RepositoryOptions> for PostPersistHook<'a> {
             event: opts.event(),
             entity: opts.entity(),
             hook: &opts.post_persist_hook,
+            spawn: opts.post_persist_async_spawn,
         }
     }
 }
@@ -26,7 +28,27 @@ impl ToTokens for PostPersistHook<'_> {
         let event = &self.event;
         let entity = &self.entity;
 
-        let (error_ty, hook) = if let Some(config) = self.hook {
+        let (error_ty, hook) = if let Some(config) = self.hook.as_ref().filter(|_| self.spawn) {
+            let method = &config.method;
+            let error = &config.error;
+            (
+                quote! { #error },
+                quote! {
+                    let __repo = self.clone();
+                    let __new_events: Vec<_> = new_events.cloned().collect();
+                    let __hook = es_entity::operation::hooks::SpawnAfterCommit::new(
+                        es_entity::context::WithEventContext::with_event_context(
+                            async move { __repo.#method(__new_events).await },
+                            es_entity::context::EventContext::current().data(),
+                        ),
+                    );
+                    if let Err(__hook) = op.add_commit_hook(__hook) {
+                        __hook.spawn();
+                    }
+                    Ok(())
+                },
+            )
+        } else if let Some(config) = self.hook {
             let method = &config.method;
             let error = &config.error;
             (
@@ -76,6 +98,7 @@ mod tests {
             event: &event,
             entity: &entity,
             hook: &hook,
+            spawn: false,
         };
 
         let mut tokens = TokenStream::new();
@@ -111,6 +134,7 @@ mod tests {
             event: &event,
             entity: &entity,
             hook: &config,
+            spawn: false,
         };
 
         let mut tokens = TokenStream::new();
@@ -133,4 +157,51 @@ mod tests {
 
         assert_eq!(tokens.to_string(), expected.to_string());
     }
+
+    #[test]
+    fn post_persist_hook_async_spawn() {
+        let event = syn::Ident::new("EntityEvent", proc_macro2::Span::call_site());
+        let entity = syn::Ident::new("Entity", proc_macro2::Span::call_site());
+        let config = Some(PostPersistHookConfig {
+            method: syn::Ident::new("on_persist", proc_macro2::Span::call_site()),
+            error: syn::parse_str("sqlx::Error").unwrap(),
+        });
+
+        let hook = PostPersistHook {
+            event: &event,
+            entity: &entity,
+            hook: &config,
+            spawn: true,
+        };
+
+        let mut tokens = TokenStream::new();
+        hook.to_tokens(&mut tokens);
+
+        let expected = quote! {
+            #[inline(always)]
+            async fn execute_post_persist_hook<OP>(&self,
+                op: &mut OP,
+                entity: &Entity,
+                new_events: es_entity::LastPersisted<'_, EntityEvent>
+            ) -> Result<(), sqlx::Error>
+                where
+                    OP: es_entity::AtomicOperation
+            {
+                let __repo = self.clone();
+                let __new_events: Vec<_> = new_events.cloned().collect();
+                let __hook = es_entity::operation::hooks::SpawnAfterCommit::new(
+                    es_entity::context::WithEventContext::with_event_context(
+                        async move { __repo.on_persist(__new_events).await },
+                        es_entity::context::EventContext::current().data(),
+                    ),
+                );
+                if let Err(__hook) = op.add_commit_hook(__hook) {
+                    __hook.spawn();
+                }
+                Ok(())
+            }
+        };
+
+        assert_eq!(tokens.to_string(), expected.to_string());
+    }
 }
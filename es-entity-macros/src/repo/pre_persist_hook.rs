@@ -0,0 +1,67 @@
+use darling::ToTokens;
+use proc_macro2::TokenStream;
+use quote::{TokenStreamExt, quote};
+
+use super::options::RepositoryOptions;
+
+pub struct PrePersistHook<'a> {
+    hook: Option<&'a syn::Ident>,
+}
+
+impl<'a> From<&'a RepositoryOptions> for PrePersistHook<'a> {
+    fn from(opts: &'a RepositoryOptions) -> Self {
+        Self {
+            hook: opts.pre_persist_hook.as_ref(),
+        }
+    }
+}
+
+impl ToTokens for PrePersistHook<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let hook = if let Some(method) = self.hook {
+            quote! {
+                self.#method(events);
+            }
+        } else {
+            quote! {}
+        };
+
+        tokens.append_all(quote! {
+            #[inline(always)]
+            fn execute_pre_persist_hook(
+                &self,
+                events: &mut [es_entity::prelude::serde_json::Value],
+            ) {
+                #hook
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pre_persist_hook() {
+        let method = syn::Ident::new("enrich_events", proc_macro2::Span::call_site());
+        let hook = PrePersistHook {
+            hook: Some(&method),
+        };
+
+        let mut tokens = TokenStream::new();
+        hook.to_tokens(&mut tokens);
+
+        let expected = quote! {
+            #[inline(always)]
+            fn execute_pre_persist_hook(
+                &self,
+                events: &mut [es_entity::prelude::serde_json::Value],
+            ) {
+                self.enrich_events(events);
+            }
+        };
+
+        assert_eq!(tokens.to_string(), expected.to_string());
+    }
+}
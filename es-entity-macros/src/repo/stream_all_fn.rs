@@ -0,0 +1,179 @@
+use darling::ToTokens;
+use proc_macro2::TokenStream;
+use quote::{TokenStreamExt, quote};
+
+use super::options::*;
+
+const STREAM_ALL_PAGE_SIZE: usize = 100;
+
+pub struct StreamAllFn<'a> {
+    entity: &'a syn::Ident,
+    query_error: syn::Ident,
+}
+
+impl<'a> From<&'a RepositoryOptions> for StreamAllFn<'a> {
+    fn from(opts: &'a RepositoryOptions) -> Self {
+        Self {
+            entity: opts.entity(),
+            query_error: opts.query_error(),
+        }
+    }
+}
+
+impl ToTokens for StreamAllFn<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let entity = self.entity;
+        let query_error = &self.query_error;
+
+        tokens.append_all(quote! {
+            /// Lazily streams every (non-deleted) entity ordered by id.
+            ///
+            /// Entities are loaded page by page via `list_by_id` so memory stays bounded.
+            pub fn stream_all(
+                &self,
+            ) -> impl es_entity::prelude::futures_core::Stream<Item = Result<#entity, #query_error>> + '_ {
+                es_entity::prelude::async_stream::try_stream! {
+                    let mut after = None;
+                    loop {
+                        let es_entity::PaginatedQueryRet {
+                            entities,
+                            has_next_page,
+                            end_cursor,
+                        } = self
+                            .list_by_id(
+                                es_entity::PaginatedQueryArgs { first: #STREAM_ALL_PAGE_SIZE, after },
+                                es_entity::ListDirection::Ascending,
+                            )
+                            .await?;
+                        for entity in entities {
+                            yield entity;
+                        }
+                        if !has_next_page {
+                            break;
+                        }
+                        after = end_cursor;
+                    }
+                }
+            }
+
+            /// Like [`stream_all`](Self::stream_all) but executes every page within `op`.
+            pub fn stream_all_in_op<'s, OP>(
+                &'s self,
+                op: &'s mut OP,
+            ) -> impl es_entity::prelude::futures_core::Stream<Item = Result<#entity, #query_error>> + 's
+            where
+                OP: es_entity::AtomicOperation
+            {
+                es_entity::prelude::async_stream::try_stream! {
+                    let mut after = None;
+                    loop {
+                        let es_entity::PaginatedQueryRet {
+                            entities,
+                            has_next_page,
+                            end_cursor,
+                        } = self
+                            .list_by_id_in_op(
+                                &mut *op,
+                                es_entity::PaginatedQueryArgs { first: #STREAM_ALL_PAGE_SIZE, after },
+                                es_entity::ListDirection::Ascending,
+                            )
+                            .await?;
+                        for entity in entities {
+                            yield entity;
+                        }
+                        if !has_next_page {
+                            break;
+                        }
+                        after = end_cursor;
+                    }
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proc_macro2::Span;
+    use syn::Ident;
+
+    #[test]
+    fn stream_all_fn() {
+        let entity = Ident::new("Entity", Span::call_site());
+        let stream_all_fn = StreamAllFn {
+            entity: &entity,
+            query_error: Ident::new("EntityQueryError", Span::call_site()),
+        };
+
+        let mut tokens = TokenStream::new();
+        stream_all_fn.to_tokens(&mut tokens);
+
+        let expected = quote! {
+            /// Lazily streams every (non-deleted) entity ordered by id.
+            ///
+            /// Entities are loaded page by page via `list_by_id` so memory stays bounded.
+            pub fn stream_all(
+                &self,
+            ) -> impl es_entity::prelude::futures_core::Stream<Item = Result<Entity, EntityQueryError>> + '_ {
+                es_entity::prelude::async_stream::try_stream! {
+                    let mut after = None;
+                    loop {
+                        let es_entity::PaginatedQueryRet {
+                            entities,
+                            has_next_page,
+                            end_cursor,
+                        } = self
+                            .list_by_id(
+                                es_entity::PaginatedQueryArgs { first: 100usize, after },
+                                es_entity::ListDirection::Ascending,
+                            )
+                            .await?;
+                        for entity in entities {
+                            yield entity;
+                        }
+                        if !has_next_page {
+                            break;
+                        }
+                        after = end_cursor;
+                    }
+                }
+            }
+
+            /// Like [`stream_all`](Self::stream_all) but executes every page within `op`.
+            pub fn stream_all_in_op<'s, OP>(
+                &'s self,
+                op: &'s mut OP,
+            ) -> impl es_entity::prelude::futures_core::Stream<Item = Result<Entity, EntityQueryError>> + 's
+            where
+                OP: es_entity::AtomicOperation
+            {
+                es_entity::prelude::async_stream::try_stream! {
+                    let mut after = None;
+                    loop {
+                        let es_entity::PaginatedQueryRet {
+                            entities,
+                            has_next_page,
+                            end_cursor,
+                        } = self
+                            .list_by_id_in_op(
+                                &mut *op,
+                                es_entity::PaginatedQueryArgs { first: 100usize, after },
+                                es_entity::ListDirection::Ascending,
+                            )
+                            .await?;
+                        for entity in entities {
+                            yield entity;
+                        }
+                        if !has_next_page {
+                            break;
+                        }
+                        after = end_cursor;
+                    }
+                }
+            }
+        };
+
+        assert_eq!(tokens.to_string(), expected.to_string());
+    }
+}
@@ -0,0 +1,162 @@
+use darling::ToTokens;
+use proc_macro2::TokenStream;
+use quote::{TokenStreamExt, quote};
+
+use super::options::*;
+
+pub struct TruncateFn<'a> {
+    table_name: &'a str,
+    events_table_name: &'a str,
+    forgettable_table_name: Option<&'a str>,
+    events_discriminator: Option<&'a EventsDiscriminator>,
+    nested_fields: Vec<&'a syn::Ident>,
+}
+
+impl<'a> From<&'a RepositoryOptions> for TruncateFn<'a> {
+    fn from(opts: &'a RepositoryOptions) -> Self {
+        Self {
+            table_name: opts.table_name(),
+            events_table_name: opts.events_table_name(),
+            forgettable_table_name: opts.forgettable_table_name(),
+            events_discriminator: opts.events_discriminator(),
+            nested_fields: opts.all_nested().map(|f| f.ident()).collect(),
+        }
+    }
+}
+
+impl ToTokens for TruncateFn<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        // A shared events table only loses the events tagged for this repo.
+        let (mut tables, deletes) = if let Some(discriminator) = self.events_discriminator {
+            let delete = format!(
+                "DELETE FROM {} WHERE {} = {}",
+                self.events_table_name,
+                discriminator.column,
+                discriminator.value_literal()
+            );
+            (Vec::new(), vec![delete])
+        } else {
+            (vec![self.events_table_name], Vec::new())
+        };
+        if let Some(forgettable_tbl) = self.forgettable_table_name {
+            tables.push(forgettable_tbl);
+        }
+        tables.push(self.table_name);
+        let nested_fields = &self.nested_fields;
+
+        // The tables of nested repos are truncated in the same statement as their
+        // foreign keys point at the index table of this repo. No CASCADE so that
+        // references from tables outside of the repos surface as an error.
+        tokens.append_all(quote! {
+            #[doc(hidden)]
+            pub fn truncate_tables_for_tests(
+                &self,
+                tables: &mut Vec<&'static str>,
+                deletes: &mut Vec<&'static str>,
+            ) {
+                #(self.#nested_fields.truncate_tables_for_tests(tables, deletes);)*
+                tables.extend_from_slice(&[#(#tables),*]);
+                deletes.extend_from_slice(&[#(#deletes),*]);
+            }
+
+            /// Removes all rows from the index, events (and forgettable payload) tables
+            /// of this repo and of all nested repos.
+            ///
+            /// Only available with the `testing` feature.
+            pub async fn truncate_all_for_tests(&self) -> Result<(), sqlx::Error> {
+                let mut tables = Vec::new();
+                let mut deletes = Vec::new();
+                self.truncate_tables_for_tests(&mut tables, &mut deletes);
+                let mut tx = self.pool().begin().await?;
+                for delete in deletes {
+                    sqlx::query(delete).execute(&mut *tx).await?;
+                }
+                sqlx::query(&format!("TRUNCATE TABLE {}", tables.join(", ")))
+                    .execute(&mut *tx)
+                    .await?;
+                tx.commit().await?;
+                Ok(())
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proc_macro2::Span;
+    use syn::Ident;
+
+    #[test]
+    fn truncate_fn() {
+        let items = Ident::new("items", Span::call_site());
+        let truncate_fn = TruncateFn {
+            table_name: "orders",
+            events_table_name: "order_events",
+            forgettable_table_name: Some("orders_forgettable_payloads"),
+            events_discriminator: None,
+            nested_fields: vec![&items],
+        };
+
+        let mut tokens = TokenStream::new();
+        truncate_fn.to_tokens(&mut tokens);
+
+        let expected = quote! {
+            #[doc(hidden)]
+            pub fn truncate_tables_for_tests(
+                &self,
+                tables: &mut Vec<&'static str>,
+                deletes: &mut Vec<&'static str>,
+            ) {
+                self.items.truncate_tables_for_tests(tables, deletes);
+                tables.extend_from_slice(&["order_events", "orders_forgettable_payloads", "orders"]);
+                deletes.extend_from_slice(&[]);
+            }
+
+            /// Removes all rows from the index, events (and forgettable payload) tables
+            /// of this repo and of all nested repos.
+            ///
+            /// Only available with the `testing` feature.
+            pub async fn truncate_all_for_tests(&self) -> Result<(), sqlx::Error> {
+                let mut tables = Vec::new();
+                let mut deletes = Vec::new();
+                self.truncate_tables_for_tests(&mut tables, &mut deletes);
+                let mut tx = self.pool().begin().await?;
+                for delete in deletes {
+                    sqlx::query(delete).execute(&mut *tx).await?;
+                }
+                sqlx::query(&format!("TRUNCATE TABLE {}", tables.join(", ")))
+                    .execute(&mut *tx)
+                    .await?;
+                tx.commit().await?;
+                Ok(())
+            }
+        };
+
+        assert_eq!(tokens.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn truncate_fn_with_shared_events_table() {
+        let discriminator = EventsDiscriminator {
+            column: "kind".to_string(),
+            value: "admin".to_string(),
+        };
+        let truncate_fn = TruncateFn {
+            table_name: "shared_admins",
+            events_table_name: "shared_user_events",
+            forgettable_table_name: None,
+            events_discriminator: Some(&discriminator),
+            nested_fields: vec![],
+        };
+
+        let mut tokens = TokenStream::new();
+        truncate_fn.to_tokens(&mut tokens);
+
+        let expected = quote! {
+            tables.extend_from_slice(&["shared_admins"]);
+            deletes.extend_from_slice(&["DELETE FROM shared_user_events WHERE kind = 'admin'"]);
+        };
+        assert!(tokens.to_string().contains(&expected.to_string()));
+    }
+}
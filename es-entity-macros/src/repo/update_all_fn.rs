@@ -5,12 +5,15 @@ use quote::{TokenStreamExt, quote};
 use super::options::*;
 
 pub struct UpdateAllFn<'a> {
+    id: &'a syn::Ident,
     entity: &'a syn::Ident,
     table_name: &'a str,
     columns: &'a Columns,
     modify_error: syn::Ident,
     nested_fn_names: Vec<syn::Ident>,
     post_persist_error: Option<&'a syn::Type>,
+    batch_size: usize,
+    clock: ClockFieldInfo<'a>,
     #[cfg(feature = "instrument")]
     repo_name_snake: String,
 }
@@ -18,6 +21,7 @@ pub struct UpdateAllFn<'a> {
 impl<'a> From<&'a RepositoryOptions> for UpdateAllFn<'a> {
     fn from(opts: &'a RepositoryOptions) -> Self {
         Self {
+            id: opts.id(),
             entity: opts.entity(),
             modify_error: opts.modify_error(),
             columns: &opts.columns,
@@ -27,6 +31,8 @@ impl<'a> From<&'a RepositoryOptions> for UpdateAllFn<'a> {
                 .map(|f| f.update_nested_fn_name())
                 .collect(),
             post_persist_error: opts.post_persist_hook.as_ref().map(|h| &h.error),
+            batch_size: opts.batch_size(),
+            clock: opts.clock_field(),
             #[cfg(feature = "instrument")]
             repo_name_snake: opts.repo_name_snake_case(),
         }
@@ -35,8 +41,11 @@ impl<'a> From<&'a RepositoryOptions> for UpdateAllFn<'a> {
 
 impl ToTokens for UpdateAllFn<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
+        let op_now = RepositoryOptions::op_now_arg(self.clock);
+        let id = self.id;
         let entity = self.entity;
         let modify_error = &self.modify_error;
+        let batch_size = self.batch_size;
 
         let nested = self.nested_fn_names.iter().map(|f| {
             quote! {
@@ -57,12 +66,18 @@ impl ToTokens for UpdateAllFn<'_> {
 
         let (vec_declarations, per_entity_pushes, update_tokens) = if self.columns.updates_needed()
         {
-            let (vecs, pushes, bind_tokens) = self
+            let (vecs, pushes, mut bind_tokens) = self
                 .columns
                 .update_all_arg_parts(syn::parse_quote! { entity });
-            let set_clause = self.columns.sql_bulk_update_set();
             let column_names = self.columns.update_all_column_names();
             let n_columns = column_names.len();
+            let mut set_clause = vec![self.columns.sql_bulk_update_set()];
+            if let Some(expr) = self.columns.updated_at_expr(&format!("${}", n_columns + 1)) {
+                set_clause.push(format!("updated_at = {expr}"));
+                bind_tokens.push(quote! { .bind(#op_now) });
+            }
+            set_clause.retain(|s| !s.is_empty());
+            let set_clause = set_clause.join(", ");
             let placeholders = (1..=n_columns)
                 .map(|i| format!("${i}"))
                 .collect::<Vec<_>>()
@@ -120,6 +135,18 @@ impl ToTokens for UpdateAllFn<'_> {
         #[cfg(not(feature = "instrument"))]
         let (instrument_attr, error_recording) = (quote! {}, quote! {});
 
+        #[cfg(feature = "otel-metrics")]
+        let metrics_recording = {
+            let entity_name = entity.to_string();
+            quote! {
+                if let Ok(ref counts) = __result {
+                    es_entity::metrics::record_updated(#entity_name, counts.len());
+                }
+            }
+        };
+        #[cfg(not(feature = "otel-metrics"))]
+        let metrics_recording = quote! {};
+
         let post_persist_check = if self.post_persist_error.is_some() {
             quote! {
                 self.execute_post_persist_hook(op, &entity, entity.events().last_persisted(n_events)).await.map_err(#modify_error::PostPersistHookError)?;
@@ -139,7 +166,6 @@ impl ToTokens for UpdateAllFn<'_> {
                 Ok(res)
             }
 
-            #instrument_attr
             pub async fn update_all_in_op<OP>(
                 &self,
                 op: &mut OP,
@@ -148,57 +174,76 @@ impl ToTokens for UpdateAllFn<'_> {
             where
                 OP: es_entity::AtomicOperation
             {
-                let __result: Result<usize, #modify_error> = async {
+                let counts = self.update_all_in_op_counts(op, entities).await?;
+                Ok(counts.values().sum())
+            }
+
+            /// Like `update_all_in_op` but returns the number of events persisted per entity.
+            ///
+            /// Entities without new events are not included.
+            #instrument_attr
+            pub async fn update_all_in_op_counts<OP>(
+                &self,
+                op: &mut OP,
+                entities: &mut [#entity]
+            ) -> Result<std::collections::HashMap<#id, usize>, #modify_error>
+            where
+                OP: es_entity::AtomicOperation
+            {
+                let __result: Result<std::collections::HashMap<#id, usize>, #modify_error> = async {
+                    let mut counts = std::collections::HashMap::new();
                     if entities.is_empty() {
-                        return Ok(0);
+                        return Ok(counts);
                     }
 
-                    #nested_phase
+                    for entities in entities.chunks_mut(#batch_size) {
+                        #nested_phase
 
-                    #vec_declarations
+                        #vec_declarations
 
-                    let mut has_new_events = false;
-                    for entity in entities.iter() {
-                        if !entity.events().any_new() {
-                            continue;
-                        }
-                        has_new_events = true;
+                        let mut has_new_events = false;
+                        for entity in entities.iter() {
+                            if !entity.events().any_new() {
+                                continue;
+                            }
+                            has_new_events = true;
 
-                        #per_entity_pushes
-                    }
+                            #per_entity_pushes
+                        }
 
-                    if !has_new_events {
-                        return Ok(0);
-                    }
+                        if !has_new_events {
+                            continue;
+                        }
 
-                    #update_tokens
-
-                    let mut all_event_refs: Vec<_> = entities.iter_mut()
-                        .filter_map(|entity| {
-                            let events = Self::extract_events(entity);
-                            if events.any_new() { Some(events) } else { None }
-                        })
-                        .collect();
-                    let n_persisted = Self::extract_concurrent_modification(
-                        self.persist_events_batch(op, &mut all_event_refs).await,
-                        #modify_error::ConcurrentModification,
-                    )?;
-                    drop(all_event_refs);
-
-                    let mut total_events = 0usize;
-                    for entity in entities.iter_mut() {
-                        if let Some(&n_events) = n_persisted.get(&entity.id) {
-                            if n_events > 0 {
-                                #post_persist_check
-                                total_events += n_events;
+                        #update_tokens
+
+                        let mut all_event_refs: Vec<_> = entities.iter_mut()
+                            .filter_map(|entity| {
+                                let events = Self::extract_events(entity);
+                                if events.any_new() { Some(events) } else { None }
+                            })
+                            .collect();
+                        let n_persisted = Self::extract_concurrent_modification(
+                            self.persist_events_batch(op, &mut all_event_refs).await,
+                            #modify_error::ConcurrentModification,
+                        )?;
+                        drop(all_event_refs);
+
+                        for entity in entities.iter_mut() {
+                            if let Some(&n_events) = n_persisted.get(&entity.id) {
+                                if n_events > 0 {
+                                    #post_persist_check
+                                    counts.insert(entity.id.clone(), n_events);
+                                }
                             }
                         }
                     }
 
-                    Ok(total_events)
+                    Ok(counts)
                 }.await;
 
                 #error_recording
+                #metrics_recording
                 __result
             }
         });
@@ -225,12 +270,15 @@ mod tests {
         );
 
         let update_all_fn = UpdateAllFn {
+            id: &id,
             entity: &entity,
             table_name: "entities",
             modify_error: syn::Ident::new("EntityModifyError", Span::call_site()),
             columns: &columns,
             nested_fn_names: Vec::new(),
             post_persist_error: None,
+            batch_size: 1000,
+            clock: ClockFieldInfo::None,
             #[cfg(feature = "instrument")]
             repo_name_snake: "test_repo".to_string(),
         };
@@ -238,6 +286,15 @@ mod tests {
         let mut tokens = TokenStream::new();
         update_all_fn.to_tokens(&mut tokens);
 
+        #[cfg(feature = "otel-metrics")]
+        let metrics_recording = quote! {
+            if let Ok(ref counts) = __result {
+                es_entity::metrics::record_updated("Entity", counts.len());
+            }
+        };
+        #[cfg(not(feature = "otel-metrics"))]
+        let metrics_recording = quote! {};
+
         let expected = quote! {
             pub async fn update_all(
                 &self,
@@ -257,71 +314,89 @@ mod tests {
             where
                 OP: es_entity::AtomicOperation
             {
-                let __result: Result<usize, EntityModifyError> = async {
+                let counts = self.update_all_in_op_counts(op, entities).await?;
+                Ok(counts.values().sum())
+            }
+
+            /// Like `update_all_in_op` but returns the number of events persisted per entity.
+            ///
+            /// Entities without new events are not included.
+            pub async fn update_all_in_op_counts<OP>(
+                &self,
+                op: &mut OP,
+                entities: &mut [Entity]
+            ) -> Result<std::collections::HashMap<EntityId, usize>, EntityModifyError>
+            where
+                OP: es_entity::AtomicOperation
+            {
+                let __result: Result<std::collections::HashMap<EntityId, usize>, EntityModifyError> = async {
+                    let mut counts = std::collections::HashMap::new();
                     if entities.is_empty() {
-                        return Ok(0);
+                        return Ok(counts);
                     }
 
-                    let mut id_collection = Vec::new();
-                    let mut name_collection = Vec::new();
+                    for entities in entities.chunks_mut(1000usize) {
+                        let mut id_collection = Vec::new();
+                        let mut name_collection = Vec::new();
 
-                    let mut has_new_events = false;
-                    for entity in entities.iter() {
-                        if !entity.events().any_new() {
-                            continue;
-                        }
-                        has_new_events = true;
+                        let mut has_new_events = false;
+                        for entity in entities.iter() {
+                            if !entity.events().any_new() {
+                                continue;
+                            }
+                            has_new_events = true;
 
-                        let id = &entity.id;
-                        let name = &entity.name;
-                        id_collection.push(id);
-                        name_collection.push(name);
-                    }
+                            let id = &entity.id;
+                            let name = &entity.name;
+                            id_collection.push(id);
+                            name_collection.push(name);
+                        }
 
-                    if !has_new_events {
-                        return Ok(0);
-                    }
+                        if !has_new_events {
+                            continue;
+                        }
 
-                    sqlx::query("UPDATE entities SET name = unnested.name FROM UNNEST($1, $2) AS unnested(id, name) WHERE entities.id = unnested.id")
-                        .bind(id_collection)
-                        .bind(name_collection)
-                        .execute(op.as_executor())
-                        .await
-                        .map_err(|e| match &e {
-                            sqlx::Error::Database(db_err) if db_err.is_unique_violation() => {
-                                EntityModifyError::ConstraintViolation {
-                                    column: Self::map_constraint_column(db_err.constraint()),
-                                    value: es_entity::extract_constraint_value(db_err.as_ref()),
-                                    inner: e,
+                        sqlx::query("UPDATE entities SET name = unnested.name FROM UNNEST($1, $2) AS unnested(id, name) WHERE entities.id = unnested.id")
+                            .bind(id_collection)
+                            .bind(name_collection)
+                            .execute(op.as_executor())
+                            .await
+                            .map_err(|e| match &e {
+                                sqlx::Error::Database(db_err) if db_err.is_unique_violation() => {
+                                    EntityModifyError::ConstraintViolation {
+                                        column: Self::map_constraint_column(db_err.constraint()),
+                                        value: es_entity::extract_constraint_value(db_err.as_ref()),
+                                        inner: e,
+                                    }
+                                }
+                                _ => EntityModifyError::Sqlx(e),
+                            })?;
+
+                        let mut all_event_refs: Vec<_> = entities.iter_mut()
+                            .filter_map(|entity| {
+                                let events = Self::extract_events(entity);
+                                if events.any_new() { Some(events) } else { None }
+                            })
+                            .collect();
+                        let n_persisted = Self::extract_concurrent_modification(
+                            self.persist_events_batch(op, &mut all_event_refs).await,
+                            EntityModifyError::ConcurrentModification,
+                        )?;
+                        drop(all_event_refs);
+
+                        for entity in entities.iter_mut() {
+                            if let Some(&n_events) = n_persisted.get(&entity.id) {
+                                if n_events > 0 {
+                                    counts.insert(entity.id.clone(), n_events);
                                 }
-                            }
-                            _ => EntityModifyError::Sqlx(e),
-                        })?;
-
-                    let mut all_event_refs: Vec<_> = entities.iter_mut()
-                        .filter_map(|entity| {
-                            let events = Self::extract_events(entity);
-                            if events.any_new() { Some(events) } else { None }
-                        })
-                        .collect();
-                    let n_persisted = Self::extract_concurrent_modification(
-                        self.persist_events_batch(op, &mut all_event_refs).await,
-                        EntityModifyError::ConcurrentModification,
-                    )?;
-                    drop(all_event_refs);
-
-                    let mut total_events = 0usize;
-                    for entity in entities.iter_mut() {
-                        if let Some(&n_events) = n_persisted.get(&entity.id) {
-                            if n_events > 0 {
-                                total_events += n_events;
                             }
                         }
                     }
 
-                    Ok(total_events)
+                    Ok(counts)
                 }.await;
 
+                #metrics_recording
                 __result
             }
         };
@@ -338,12 +413,15 @@ mod tests {
         columns.set_id_column(&id);
 
         let update_all_fn = UpdateAllFn {
+            id: &id,
             entity: &entity,
             table_name: "entities",
             modify_error: syn::Ident::new("EntityModifyError", Span::call_site()),
             columns: &columns,
             nested_fn_names: Vec::new(),
             post_persist_error: None,
+            batch_size: 1000,
+            clock: ClockFieldInfo::None,
             #[cfg(feature = "instrument")]
             repo_name_snake: "test_repo".to_string(),
         };
@@ -351,6 +429,15 @@ mod tests {
         let mut tokens = TokenStream::new();
         update_all_fn.to_tokens(&mut tokens);
 
+        #[cfg(feature = "otel-metrics")]
+        let metrics_recording = quote! {
+            if let Ok(ref counts) = __result {
+                es_entity::metrics::record_updated("Entity", counts.len());
+            }
+        };
+        #[cfg(not(feature = "otel-metrics"))]
+        let metrics_recording = quote! {};
+
         let expected = quote! {
             pub async fn update_all(
                 &self,
@@ -370,47 +457,65 @@ mod tests {
             where
                 OP: es_entity::AtomicOperation
             {
-                let __result: Result<usize, EntityModifyError> = async {
+                let counts = self.update_all_in_op_counts(op, entities).await?;
+                Ok(counts.values().sum())
+            }
+
+            /// Like `update_all_in_op` but returns the number of events persisted per entity.
+            ///
+            /// Entities without new events are not included.
+            pub async fn update_all_in_op_counts<OP>(
+                &self,
+                op: &mut OP,
+                entities: &mut [Entity]
+            ) -> Result<std::collections::HashMap<EntityId, usize>, EntityModifyError>
+            where
+                OP: es_entity::AtomicOperation
+            {
+                let __result: Result<std::collections::HashMap<EntityId, usize>, EntityModifyError> = async {
+                    let mut counts = std::collections::HashMap::new();
                     if entities.is_empty() {
-                        return Ok(0);
+                        return Ok(counts);
                     }
 
-                    let mut has_new_events = false;
-                    for entity in entities.iter() {
-                        if !entity.events().any_new() {
-                            continue;
+                    for entities in entities.chunks_mut(1000usize) {
+                        let mut has_new_events = false;
+                        for entity in entities.iter() {
+                            if !entity.events().any_new() {
+                                continue;
+                            }
+                            has_new_events = true;
                         }
-                        has_new_events = true;
-                    }
 
-                    if !has_new_events {
-                        return Ok(0);
-                    }
+                        if !has_new_events {
+                            continue;
+                        }
 
-                    let mut all_event_refs: Vec<_> = entities.iter_mut()
-                        .filter_map(|entity| {
-                            let events = Self::extract_events(entity);
-                            if events.any_new() { Some(events) } else { None }
-                        })
-                        .collect();
-                    let n_persisted = Self::extract_concurrent_modification(
-                        self.persist_events_batch(op, &mut all_event_refs).await,
-                        EntityModifyError::ConcurrentModification,
-                    )?;
-                    drop(all_event_refs);
-
-                    let mut total_events = 0usize;
-                    for entity in entities.iter_mut() {
-                        if let Some(&n_events) = n_persisted.get(&entity.id) {
-                            if n_events > 0 {
-                                total_events += n_events;
+                        let mut all_event_refs: Vec<_> = entities.iter_mut()
+                            .filter_map(|entity| {
+                                let events = Self::extract_events(entity);
+                                if events.any_new() { Some(events) } else { None }
+                            })
+                            .collect();
+                        let n_persisted = Self::extract_concurrent_modification(
+                            self.persist_events_batch(op, &mut all_event_refs).await,
+                            EntityModifyError::ConcurrentModification,
+                        )?;
+                        drop(all_event_refs);
+
+                        for entity in entities.iter_mut() {
+                            if let Some(&n_events) = n_persisted.get(&entity.id) {
+                                if n_events > 0 {
+                                    counts.insert(entity.id.clone(), n_events);
+                                }
                             }
                         }
                     }
 
-                    Ok(total_events)
+                    Ok(counts)
                 }.await;
 
+                #metrics_recording
                 __result
             }
         };
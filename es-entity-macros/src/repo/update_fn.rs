@@ -11,6 +11,7 @@ pub struct UpdateFn<'a> {
     modify_error: syn::Ident,
     nested_fn_names: Vec<syn::Ident>,
     post_persist_error: Option<&'a syn::Type>,
+    clock: ClockFieldInfo<'a>,
     #[cfg(feature = "instrument")]
     repo_name_snake: String,
 }
@@ -27,6 +28,7 @@ impl<'a> From<&'a RepositoryOptions> for UpdateFn<'a> {
                 .map(|f| f.update_nested_fn_name())
                 .collect(),
             post_persist_error: opts.post_persist_hook.as_ref().map(|h| &h.error),
+            clock: opts.clock_field(),
             #[cfg(feature = "instrument")]
             repo_name_snake: opts.repo_name_snake_case(),
         }
@@ -35,6 +37,7 @@ impl<'a> From<&'a RepositoryOptions> for UpdateFn<'a> {
 
 impl ToTokens for UpdateFn<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
+        let op_now = RepositoryOptions::op_now_arg(self.clock);
         let entity = self.entity;
         let modify_error = &self.modify_error;
 
@@ -48,12 +51,21 @@ impl ToTokens for UpdateFn<'_> {
             let assignments = self
                 .columns
                 .variable_assignments_for_update(syn::parse_quote! { entity });
-            let column_updates = self.columns.sql_updates();
+            let mut args = self.columns.update_query_args();
+            let mut column_updates = vec![self.columns.sql_updates()];
+            if let Some(expr) = self
+                .columns
+                .updated_at_expr(&format!("${}", args.len() + 1))
+            {
+                column_updates.push(format!("updated_at = {expr}"));
+                args.push(quote! { #op_now });
+            }
+            column_updates.retain(|u| !u.is_empty());
             let query = format!(
                 "UPDATE {} SET {} WHERE id = $1",
-                self.table_name, column_updates,
+                self.table_name,
+                column_updates.join(", "),
             );
-            let args = self.columns.update_query_args();
             Some(quote! {
             #assignments
             sqlx::query!(
@@ -106,6 +118,18 @@ impl ToTokens for UpdateFn<'_> {
         #[cfg(not(feature = "instrument"))]
         let (instrument_attr, record_id, error_recording) = (quote! {}, quote! {}, quote! {});
 
+        #[cfg(feature = "otel-metrics")]
+        let metrics_recording = {
+            let entity_name = entity.to_string();
+            quote! {
+                if matches!(__result, Ok(n_events) if n_events > 0) {
+                    es_entity::metrics::record_updated(#entity_name, 1);
+                }
+            }
+        };
+        #[cfg(not(feature = "otel-metrics"))]
+        let metrics_recording = quote! {};
+
         let post_persist_check = if self.post_persist_error.is_some() {
             quote! {
                 self.execute_post_persist_hook(op, &entity, entity.events().last_persisted(n_events)).await.map_err(#modify_error::PostPersistHookError)?;
@@ -166,6 +190,7 @@ impl ToTokens for UpdateFn<'_> {
                 }.await;
 
                 #error_recording
+                #metrics_recording
                 __result
             }
         });
@@ -198,6 +223,7 @@ mod tests {
             columns: &columns,
             nested_fn_names: Vec::new(),
             post_persist_error: None,
+            clock: ClockFieldInfo::None,
             #[cfg(feature = "instrument")]
             repo_name_snake: "test_repo".to_string(),
         };
@@ -205,6 +231,15 @@ mod tests {
         let mut tokens = TokenStream::new();
         update_fn.to_tokens(&mut tokens);
 
+        #[cfg(feature = "otel-metrics")]
+        let metrics_recording = quote! {
+            if matches!(__result, Ok(n_events) if n_events > 0) {
+                es_entity::metrics::record_updated("Entity", 1);
+            }
+        };
+        #[cfg(not(feature = "otel-metrics"))]
+        let metrics_recording = quote! {};
+
         let expected = quote! {
             #[inline(always)]
             fn extract_events<Entity, Event>(entity: &mut Entity) -> &mut es_entity::EntityEvents<Event>
@@ -269,6 +304,7 @@ mod tests {
                     Ok(n_events)
                 }.await;
 
+                #metrics_recording
                 __result
             }
         };
@@ -291,6 +327,7 @@ mod tests {
             columns: &columns,
             nested_fn_names: Vec::new(),
             post_persist_error: None,
+            clock: ClockFieldInfo::None,
             #[cfg(feature = "instrument")]
             repo_name_snake: "test_repo".to_string(),
         };
@@ -298,6 +335,15 @@ mod tests {
         let mut tokens = TokenStream::new();
         update_fn.to_tokens(&mut tokens);
 
+        #[cfg(feature = "otel-metrics")]
+        let metrics_recording = quote! {
+            if matches!(__result, Ok(n_events) if n_events > 0) {
+                es_entity::metrics::record_updated("Entity", 1);
+            }
+        };
+        #[cfg(not(feature = "otel-metrics"))]
+        let metrics_recording = quote! {};
+
         let expected = quote! {
             #[inline(always)]
             fn extract_events<Entity, Event>(entity: &mut Entity) -> &mut es_entity::EntityEvents<Event>
@@ -342,6 +388,7 @@ mod tests {
                     Ok(n_events)
                 }.await;
 
+                #metrics_recording
                 __result
             }
         };
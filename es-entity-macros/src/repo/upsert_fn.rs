@@ -0,0 +1,231 @@
+use darling::ToTokens;
+use proc_macro2::TokenStream;
+use quote::{TokenStreamExt, quote};
+
+use super::options::*;
+
+pub struct UpsertFn<'a> {
+    entity: &'a syn::Ident,
+    columns: &'a Columns,
+    create_error: syn::Ident,
+    find_error: syn::Ident,
+    modify_error: syn::Ident,
+    query_error: syn::Ident,
+    ignore_conflicts: bool,
+    post_hydrate_hook: bool,
+    post_persist_hook: bool,
+    hard_delete: bool,
+    #[cfg(feature = "instrument")]
+    repo_name_snake: String,
+}
+
+impl<'a> From<&'a RepositoryOptions> for UpsertFn<'a> {
+    fn from(opts: &'a RepositoryOptions) -> Self {
+        Self {
+            entity: opts.entity(),
+            columns: &opts.columns,
+            create_error: opts.create_error(),
+            find_error: opts.find_error(),
+            modify_error: opts.modify_error(),
+            query_error: opts.query_error(),
+            ignore_conflicts: opts.create.ignores_conflicts(),
+            post_hydrate_hook: opts.post_hydrate_hook.is_some(),
+            post_persist_hook: opts.post_persist_hook.is_some(),
+            hard_delete: opts.hard_delete_enabled(),
+            #[cfg(feature = "instrument")]
+            repo_name_snake: opts.repo_name_snake_case(),
+        }
+    }
+}
+
+impl ToTokens for UpsertFn<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let entity = self.entity;
+        let create_error = &self.create_error;
+        let find_error = &self.find_error;
+        let modify_error = &self.modify_error;
+        let query_error = &self.query_error;
+
+        let id_assignment = self
+            .columns
+            .id_assignment_for_create(syn::parse_quote! { new_entity });
+
+        let created = if self.ignore_conflicts {
+            quote! { Ok(self.create_in_op(op, new_entity).await?.into()) }
+        } else {
+            quote! { Ok(es_entity::Idempotent::Executed(self.create_in_op(op, new_entity).await?)) }
+        };
+
+        let (query_ph_arm, find_ph_arm) = if self.post_hydrate_hook {
+            (
+                quote! { #query_error::PostHydrateError(e) => #create_error::PostHydrateError(e), },
+                quote! { #find_error::PostHydrateError(e) => #create_error::PostHydrateError(e), },
+            )
+        } else {
+            (quote! {}, quote! {})
+        };
+        let modify_pp_arm = if self.post_persist_hook {
+            quote! { #modify_error::PostPersistHookError(e) => #create_error::PostPersistHookError(e), }
+        } else {
+            quote! {}
+        };
+        let modify_hd_arm = if self.hard_delete {
+            quote! { #modify_error::UnpersistedEvents => unreachable!("UnpersistedEvents cannot occur in update operations"), }
+        } else {
+            quote! {}
+        };
+
+        #[cfg(feature = "instrument")]
+        let instrument_attr = {
+            let entity_name = entity.to_string();
+            let span_name = format!("{}.upsert", self.repo_name_snake);
+            quote! {
+                #[tracing::instrument(name = #span_name, skip_all, fields(entity = #entity_name), err)]
+            }
+        };
+        #[cfg(not(feature = "instrument"))]
+        let instrument_attr = quote! {};
+
+        tokens.append_all(quote! {
+            /// Creates the entity if its id is unknown, otherwise appends the events that are not stored yet.
+            ///
+            /// The events of `new_entity` are treated as the complete history of the entity (eg. its current
+            /// state as received by a sync pipeline). Re-delivering a history that is already stored returns
+            /// `Idempotent::AlreadyApplied`. If the stored events are not a prefix of the incoming ones the
+            /// histories diverged and `ConcurrentModification` is returned.
+            #instrument_attr
+            pub async fn upsert_in_op<OP>(
+                &self,
+                op: &mut OP,
+                new_entity: <#entity as es_entity::EsEntity>::New
+            ) -> Result<es_entity::Idempotent<#entity>, #create_error>
+            where
+                OP: es_entity::AtomicOperation
+            {
+                let (__exists, __id) = {
+                    #id_assignment
+                    let exists = self.exists_by_id_in_op(&mut *op, id).await.map_err(|e| match e {
+                        #query_error::Sqlx(e) => #create_error::Sqlx(e),
+                        #query_error::HydrationError(e) => #create_error::HydrationError(e),
+                        #query_error::CursorDestructureError(_) => unreachable!("CursorDestructureError cannot occur in exists operations"),
+                        #query_error::InvalidFilters(_) => unreachable!("InvalidFilters cannot occur in exists operations"),
+                        #query_ph_arm
+                    })?;
+                    (exists, id.clone())
+                };
+                if !__exists {
+                    return #created;
+                }
+
+                let mut entity = self.find_by_id_in_op(&mut *op, &__id).await.map_err(|e| match e {
+                    #find_error::Sqlx(e) => #create_error::Sqlx(e),
+                    #find_error::NotFound { .. } => #create_error::ConcurrentModification,
+                    #find_error::HydrationError(e) => #create_error::HydrationError(e),
+                    #find_ph_arm
+                })?;
+                match es_entity::EsEntity::events_mut(&mut entity).append_missing(Self::convert_new(new_entity)) {
+                    None => return Err(#create_error::ConcurrentModification),
+                    Some(0) => return Ok(es_entity::Idempotent::AlreadyApplied),
+                    Some(_) => (),
+                }
+                // Rehydrate so the state (and the index columns) reflect the appended events
+                let mut entity: #entity = Self::hydrate_entity(es_entity::EsEntity::events_mut(&mut entity).take())?;
+
+                self.update_in_op(op, &mut entity).await.map_err(|e| match e {
+                    #modify_error::Sqlx(e) => #create_error::Sqlx(e),
+                    #modify_error::ConstraintViolation { column, value, inner } => #create_error::ConstraintViolation { column, value, inner },
+                    #modify_error::ConcurrentModification => #create_error::ConcurrentModification,
+                    #modify_hd_arm
+                    #modify_pp_arm
+                })?;
+                Ok(es_entity::Idempotent::Executed(entity))
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proc_macro2::Span;
+    use syn::Ident;
+
+    #[test]
+    fn upsert_fn() {
+        let entity = Ident::new("Entity", Span::call_site());
+        let id = Ident::new("EntityId", Span::call_site());
+        let mut columns = Columns::default();
+        columns.set_id_column(&id);
+
+        let upsert_fn = UpsertFn {
+            entity: &entity,
+            columns: &columns,
+            create_error: Ident::new("EntityCreateError", Span::call_site()),
+            find_error: Ident::new("EntityFindError", Span::call_site()),
+            modify_error: Ident::new("EntityModifyError", Span::call_site()),
+            query_error: Ident::new("EntityQueryError", Span::call_site()),
+            ignore_conflicts: false,
+            post_hydrate_hook: false,
+            post_persist_hook: false,
+            hard_delete: false,
+            #[cfg(feature = "instrument")]
+            repo_name_snake: "test_repo".to_string(),
+        };
+
+        let mut tokens = TokenStream::new();
+        upsert_fn.to_tokens(&mut tokens);
+
+        let expected = quote! {
+            /// Creates the entity if its id is unknown, otherwise appends the events that are not stored yet.
+            ///
+            /// The events of `new_entity` are treated as the complete history of the entity (eg. its current
+            /// state as received by a sync pipeline). Re-delivering a history that is already stored returns
+            /// `Idempotent::AlreadyApplied`. If the stored events are not a prefix of the incoming ones the
+            /// histories diverged and `ConcurrentModification` is returned.
+            pub async fn upsert_in_op<OP>(
+                &self,
+                op: &mut OP,
+                new_entity: <Entity as es_entity::EsEntity>::New
+            ) -> Result<es_entity::Idempotent<Entity>, EntityCreateError>
+            where
+                OP: es_entity::AtomicOperation
+            {
+                let (__exists, __id) = {
+                    let id = &new_entity.id;
+                    let exists = self.exists_by_id_in_op(&mut *op, id).await.map_err(|e| match e {
+                        EntityQueryError::Sqlx(e) => EntityCreateError::Sqlx(e),
+                        EntityQueryError::HydrationError(e) => EntityCreateError::HydrationError(e),
+                        EntityQueryError::CursorDestructureError(_) => unreachable!("CursorDestructureError cannot occur in exists operations"),
+                        EntityQueryError::InvalidFilters(_) => unreachable!("InvalidFilters cannot occur in exists operations"),
+                    })?;
+                    (exists, id.clone())
+                };
+                if !__exists {
+                    return Ok(es_entity::Idempotent::Executed(self.create_in_op(op, new_entity).await?));
+                }
+
+                let mut entity = self.find_by_id_in_op(&mut *op, &__id).await.map_err(|e| match e {
+                    EntityFindError::Sqlx(e) => EntityCreateError::Sqlx(e),
+                    EntityFindError::NotFound { .. } => EntityCreateError::ConcurrentModification,
+                    EntityFindError::HydrationError(e) => EntityCreateError::HydrationError(e),
+                })?;
+                match es_entity::EsEntity::events_mut(&mut entity).append_missing(Self::convert_new(new_entity)) {
+                    None => return Err(EntityCreateError::ConcurrentModification),
+                    Some(0) => return Ok(es_entity::Idempotent::AlreadyApplied),
+                    Some(_) => (),
+                }
+                // Rehydrate so the state (and the index columns) reflect the appended events
+                let mut entity: Entity = Self::hydrate_entity(es_entity::EsEntity::events_mut(&mut entity).take())?;
+
+                self.update_in_op(op, &mut entity).await.map_err(|e| match e {
+                    EntityModifyError::Sqlx(e) => EntityCreateError::Sqlx(e),
+                    EntityModifyError::ConstraintViolation { column, value, inner } => EntityCreateError::ConstraintViolation { column, value, inner },
+                    EntityModifyError::ConcurrentModification => EntityCreateError::ConcurrentModification,
+                })?;
+                Ok(es_entity::Idempotent::Executed(entity))
+            }
+        };
+
+        assert_eq!(tokens.to_string(), expected.to_string());
+    }
+}
@@ -4,7 +4,10 @@ use syn::ItemFn;
 #[derive(FromMeta)]
 struct MacroArgs {
     any_error: Option<bool>,
+    /// Predicate called with `&E` instead of `E::was_concurrent_modification`.
+    is_conflict: Option<syn::Path>,
     max_retries: Option<u32>,
+    backoff_ms: Option<u64>,
 }
 
 pub fn make(
@@ -42,6 +45,37 @@ pub fn make(
     let sig = &input.sig;
 
     let any_error = args.any_error.unwrap_or(false);
+    if any_error && args.is_conflict.is_some() {
+        return Err(darling::Error::custom(
+            "`any_error` and `is_conflict` are mutually exclusive",
+        ));
+    }
+    let is_conflict = if let Some(is_conflict) = &args.is_conflict {
+        quote::quote! { #is_conflict(e) }
+    } else {
+        quote::quote! { e.was_concurrent_modification() }
+    };
+
+    // Exponential backoff between attempts: backoff_ms, 2 * backoff_ms, 4 * backoff_ms, ...
+    let backoff = if let Some(backoff_ms) = args.backoff_ms {
+        quote::quote! {
+            es_entity::clock::Clock::sleep(std::time::Duration::from_millis(
+                #backoff_ms.saturating_mul(1u64 << (n - 1).min(32)),
+            ))
+            .await;
+        }
+    } else {
+        quote::quote! {}
+    };
+
+    #[cfg(feature = "otel-metrics")]
+    let backoff = {
+        let fn_name = input.sig.ident.to_string();
+        quote::quote! {
+            es_entity::metrics::record_retry(#fn_name);
+            #backoff
+        }
+    };
 
     #[cfg(feature = "instrument")]
     let err_match = if any_error {
@@ -52,18 +86,20 @@ pub fn make(
                     max_retries = max_retries,
                     "Error detected, retrying"
                 );
+                #backoff
                 continue;
             }
         }
     } else {
         quote::quote! {
             if let Err(e) = result.as_ref() {
-                if e.was_concurrent_modification() {
+                if #is_conflict {
                     tracing::warn!(
                         attempt = n,
                         max_retries = max_retries,
                         "Concurrent modification detected, retrying"
                     );
+                    #backoff
                     continue;
                 }
             }
@@ -74,13 +110,15 @@ pub fn make(
     let err_match = if any_error {
         quote::quote! {
             if result.is_err() {
+                #backoff
                 continue;
             }
         }
     } else {
         quote::quote! {
             if let Err(e) = result.as_ref() {
-                if e.was_concurrent_modification() {
+                if #is_conflict {
+                    #backoff
                     continue;
                 }
             }
@@ -320,6 +320,15 @@
         echo "Running nextest..."
         cargo nextest run --workspace --verbose
 
+        echo "Running nextest with json-schema..."
+        cargo nextest run --workspace --features json-schema --verbose
+
+        echo "Running clock reset test..."
+        cargo nextest run --test clock_reset --features clock-reset --verbose
+
+        echo "Running macro tests with otel-metrics..."
+        cargo nextest run -p es-entity-macros --features otel-metrics --verbose
+
         echo "Running doc tests..."
         cargo test --doc --workspace
 
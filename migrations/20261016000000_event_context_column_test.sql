@@ -0,0 +1,16 @@
+CREATE TABLE legacy_users (
+  id UUID PRIMARY KEY,
+  name VARCHAR NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL
+);
+CREATE INDEX idx_legacy_users_name ON legacy_users (name);
+
+CREATE TABLE legacy_user_events (
+  id UUID NOT NULL REFERENCES legacy_users(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  metadata JSONB DEFAULT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
@@ -0,0 +1,16 @@
+CREATE TABLE truncate_users (
+  id UUID PRIMARY KEY,
+  name VARCHAR NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL
+);
+CREATE INDEX idx_truncate_users_name ON truncate_users (name);
+
+CREATE TABLE truncate_user_events (
+  id UUID NOT NULL REFERENCES truncate_users(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  context JSONB DEFAULT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
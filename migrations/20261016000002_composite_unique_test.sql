@@ -0,0 +1,17 @@
+CREATE TABLE composite_profiles (
+  id UUID PRIMARY KEY,
+  name VARCHAR NOT NULL,
+  email VARCHAR NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL
+);
+CREATE UNIQUE INDEX composite_profiles_name_email_key ON composite_profiles (name, email);
+
+CREATE TABLE composite_profile_events (
+  id UUID NOT NULL REFERENCES composite_profiles(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  context JSONB DEFAULT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
@@ -0,0 +1,16 @@
+CREATE TABLE recovered_users (
+  id UUID PRIMARY KEY,
+  name VARCHAR NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL
+);
+
+-- No foreign key so the projection row can be dropped while the events remain
+CREATE TABLE recovered_user_events (
+  id UUID NOT NULL,
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  context JSONB DEFAULT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
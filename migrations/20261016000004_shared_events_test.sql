@@ -0,0 +1,23 @@
+CREATE TABLE shared_admins (
+  id UUID PRIMARY KEY,
+  name VARCHAR NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE shared_members (
+  id UUID PRIMARY KEY,
+  name VARCHAR NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL
+);
+
+-- Events of both aggregates live in one table told apart by `kind`
+CREATE TABLE shared_user_events (
+  id UUID NOT NULL,
+  kind VARCHAR NOT NULL,
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  context JSONB DEFAULT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
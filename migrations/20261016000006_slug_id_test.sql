@@ -0,0 +1,15 @@
+CREATE TABLE articles (
+  id VARCHAR PRIMARY KEY,
+  title VARCHAR NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE article_events (
+  id VARCHAR NOT NULL REFERENCES articles(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  context JSONB DEFAULT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
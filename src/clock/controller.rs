@@ -24,6 +24,10 @@ impl ClockController {
     /// wake at their scheduled times (seeing the correct `now()` value),
     /// not at +1 day.
     ///
+    /// This relies on the woken tasks running on the same `current_thread`
+    /// runtime as the caller (as with `#[tokio::test]`), since time only moves on
+    /// once the caller is polled again.
+    ///
     /// Returns the number of wake events that were processed.
     ///
     /// # Example
@@ -55,6 +59,81 @@ impl ClockController {
         self.clock.advance(duration).await
     }
 
+    /// Advance time to the given absolute point.
+    ///
+    /// Behaves exactly like [`advance`](Self::advance) with the delta to `at`:
+    /// all wake events due at or before `at` fire in chronological order.
+    /// Time never goes backwards - if `at` is not after the current time
+    /// this is a no-op.
+    ///
+    /// Returns the number of wake events that were processed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use es_entity::clock::ClockHandle;
+    ///
+    /// # async fn example() {
+    /// let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    /// let (clock, ctrl) = ClockHandle::manual_at(start);
+    ///
+    /// let month_end = Utc.with_ymd_and_hms(2024, 1, 31, 23, 59, 59).unwrap();
+    /// ctrl.set(month_end).await;
+    /// assert_eq!(clock.now(), month_end);
+    ///
+    /// // Setting an earlier time does nothing
+    /// ctrl.set(start).await;
+    /// assert_eq!(clock.now(), month_end);
+    /// # }
+    /// ```
+    pub async fn set(&self, at: DateTime<Utc>) -> usize {
+        let delta_ms = at.timestamp_millis() - self.clock.now_ms();
+        if delta_ms <= 0 {
+            return 0;
+        }
+        self.clock
+            .advance(Duration::from_millis(delta_ms as u64))
+            .await
+    }
+
+    /// Advance time by each of the given durations in order.
+    ///
+    /// Every step behaves like [`advance`](Self::advance): the wake events due
+    /// within it fire (and get a chance to run) before the next step starts.
+    /// This lets a test describe a timeline as a slice of durations.
+    ///
+    /// Returns the total number of wake events that were processed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use es_entity::clock::ClockHandle;
+    /// use std::time::Duration;
+    ///
+    /// # async fn example() {
+    /// let (clock, ctrl) = ClockHandle::manual();
+    /// let t0 = clock.now();
+    ///
+    /// let c = clock.clone();
+    /// tokio::spawn(async move { c.sleep(Duration::from_secs(90)).await; });
+    /// tokio::task::yield_now().await;
+    ///
+    /// let woken = ctrl
+    ///     .advance_many(&[Duration::from_secs(60), Duration::from_secs(60)])
+    ///     .await;
+    /// assert_eq!(woken, 1);
+    /// assert_eq!(clock.now(), t0 + chrono::Duration::seconds(120));
+    /// # }
+    /// ```
+    pub async fn advance_many(&self, steps: &[Duration]) -> usize {
+        let mut total_woken = 0;
+        for step in steps {
+            total_woken += self.clock.advance(*step).await;
+        }
+        total_woken
+    }
+
     /// Advance to the next pending wake event.
     ///
     /// Returns the time that was advanced to, or `None` if there are no
@@ -104,6 +183,14 @@ impl ClockController {
         self.clock.pending_wake_count()
     }
 
+    /// Get the scheduled wake times of all pending sleeps, earliest first.
+    ///
+    /// Takes a snapshot without affecting the pending wakes, eg. to assert that
+    /// exactly one task sleeps until +1h before calling [`advance`](Self::advance).
+    pub fn pending_wakes(&self) -> Vec<DateTime<Utc>> {
+        self.clock.pending_wakes()
+    }
+
     /// Get the current time.
     ///
     /// This is equivalent to calling `now()` on the associated `ClockHandle`.
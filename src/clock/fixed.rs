@@ -0,0 +1,45 @@
+use chrono::{DateTime, Utc};
+use pin_project::pin_project;
+
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use super::scope::ScopeGuard;
+
+/// A future during whose polls [`ClockHandle::now`](super::ClockHandle::now) returns a fixed instant.
+///
+/// Created by [`ClockHandle::with_fixed_now_async`](super::ClockHandle::with_fixed_now_async).
+/// The override is installed for the duration of every poll so it follows the
+/// task across threads and does not leak into other tasks.
+#[pin_project]
+pub struct FixedNow<F> {
+    key: usize,
+    now: DateTime<Utc>,
+    #[pin]
+    future: F,
+}
+
+impl<F> FixedNow<F> {
+    pub(super) fn new(key: usize, now: DateTime<Utc>, future: F) -> Self {
+        Self { key, now, future }
+    }
+}
+
+impl<F: Future> Future for FixedNow<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let _guard = ScopeGuard::fixed(*this.key, *this.now);
+        this.future.poll(cx)
+    }
+}
+
+/// Runs `f` with `now()` of the clock identified by `key` pinned to `now`.
+pub(super) fn with_fixed_now<R>(key: usize, now: DateTime<Utc>, f: impl FnOnce() -> R) -> R {
+    let _guard = ScopeGuard::fixed(key, now);
+    f()
+}
@@ -1,16 +1,65 @@
 use chrono::{DateTime, Utc};
 
-use std::sync::OnceLock;
 use std::time::Duration;
 
-use super::{ClockController, ClockHandle, ClockSleep, ClockTimeout};
+use super::{ClockController, ClockHandle, ClockSleep, ClockTimeout, Elapsed};
 
 struct GlobalState {
     handle: ClockHandle,
     controller: Option<ClockController>,
 }
 
-static GLOBAL: OnceLock<GlobalState> = OnceLock::new();
+#[cfg(not(feature = "clock-reset"))]
+mod slot {
+    use std::sync::OnceLock;
+
+    use super::GlobalState;
+
+    static GLOBAL: OnceLock<GlobalState> = OnceLock::new();
+
+    pub(super) fn get() -> Option<&'static GlobalState> {
+        GLOBAL.get()
+    }
+
+    pub(super) fn get_or_init(init: impl FnOnce() -> GlobalState) -> &'static GlobalState {
+        GLOBAL.get_or_init(init)
+    }
+}
+
+#[cfg(feature = "clock-reset")]
+mod slot {
+    use parking_lot::RwLock;
+
+    use super::GlobalState;
+
+    // Installed states are leaked so that `Clock::handle()` can hand out `&'static` references
+    // that stay valid across a `Clock::reset()`.
+    static GLOBAL: RwLock<Option<&'static GlobalState>> = RwLock::new(None);
+
+    pub(super) fn get() -> Option<&'static GlobalState> {
+        *GLOBAL.read()
+    }
+
+    pub(super) fn get_or_init(init: impl FnOnce() -> GlobalState) -> &'static GlobalState {
+        if let Some(state) = get() {
+            return state;
+        }
+        GLOBAL
+            .write()
+            .get_or_insert_with(|| Box::leak(Box::new(init())))
+    }
+
+    pub(super) fn reset() {
+        *GLOBAL.write() = None;
+    }
+}
+
+fn state() -> &'static GlobalState {
+    slot::get_or_init(|| GlobalState {
+        handle: ClockHandle::realtime(),
+        controller: None,
+    })
+}
 
 /// Global clock access - like `Utc::now()` but testable.
 pub struct Clock;
@@ -30,6 +79,13 @@ impl Clock {
         Self::handle().today()
     }
 
+    /// Time elapsed since `earlier` according to the global clock.
+    ///
+    /// Saturates to zero if `earlier` lies in the future.
+    pub fn elapsed_since(earlier: DateTime<Utc>) -> Duration {
+        Self::handle().elapsed_since(earlier)
+    }
+
     /// Sleep using the global clock.
     pub fn sleep(duration: Duration) -> ClockSleep {
         Self::handle().sleep(duration)
@@ -47,14 +103,21 @@ impl Clock {
         Self::handle().timeout(duration, future)
     }
 
+    /// Timeout using the global clock that also returns the unused part of `duration`.
+    ///
+    /// See [`ClockHandle::timeout_with_remaining`] for details.
+    pub async fn timeout_with_remaining<F: std::future::Future>(
+        duration: Duration,
+        future: F,
+    ) -> Result<(F::Output, Duration), Elapsed> {
+        Self::handle()
+            .timeout_with_remaining(duration, future)
+            .await
+    }
+
     /// Get a reference to the global clock handle.
     pub fn handle() -> &'static ClockHandle {
-        &GLOBAL
-            .get_or_init(|| GlobalState {
-                handle: ClockHandle::realtime(),
-                controller: None,
-            })
-            .handle
+        &state().handle
     }
 
     /// Install a manual clock globally.
@@ -72,40 +135,45 @@ impl Clock {
     ///
     /// See [`install_manual`](Self::install_manual) for details.
     pub fn install_manual_at(start_at: DateTime<Utc>) -> ClockController {
-        // Check if already initialized
-        if let Some(state) = GLOBAL.get() {
-            return state
-                .controller
-                .clone()
-                .expect("Cannot install manual clock: realtime clock already initialized");
-        }
-
-        // Try to initialize
-        let (handle, ctrl) = ClockHandle::manual_at(start_at);
-
-        match GLOBAL.set(GlobalState {
-            handle,
-            controller: Some(ctrl.clone()),
-        }) {
-            Ok(()) => ctrl,
-            Err(_) => {
-                // Race: someone else initialized between our check and set
-                GLOBAL
-                    .get()
-                    .unwrap()
-                    .controller
-                    .clone()
-                    .expect("Cannot install manual clock: realtime clock already initialized")
+        slot::get_or_init(|| {
+            let (handle, ctrl) = ClockHandle::manual_at(start_at);
+            GlobalState {
+                handle,
+                controller: Some(ctrl),
             }
-        }
+        })
+        .controller
+        .clone()
+        .expect("Cannot install manual clock: realtime clock already initialized")
+    }
+
+    /// Uninstall the global clock so that the next access initializes it again.
+    ///
+    /// Lets tests within one binary switch between realtime and manual clocks
+    /// (eg. call `reset()` followed by [`install_manual`](Self::install_manual)).
+    ///
+    /// Caveats:
+    /// - The global is shared by all tests of a binary and they run on parallel threads
+    ///   by default - resetting it affects every test that is running concurrently.
+    ///   Run such tests with `--test-threads=1` or prefer an owned [`ClockHandle`].
+    /// - Handles obtained via [`handle`](Self::handle) before the reset (including the ones
+    ///   captured by already started operations) and previously returned controllers keep
+    ///   referring to the old clock.
+    /// - A reset racing with [`install_manual`](Self::install_manual) or the lazy realtime
+    ///   initialization on another thread decides which clock that thread ends up with -
+    ///   install the clock a test relies on *after* resetting, on the same thread.
+    /// - Every installed clock is leaked, which is negligible for test usage.
+    ///
+    /// Only available with the `clock-reset` feature. Other builds keep the global
+    /// in a `OnceLock` so that reading it doesn't take a lock.
+    #[cfg(feature = "clock-reset")]
+    pub fn reset() {
+        slot::reset();
     }
 
     /// Check if a manual clock is installed.
     pub fn is_manual() -> bool {
-        GLOBAL
-            .get()
-            .map(|s| s.controller.is_some())
-            .unwrap_or(false)
+        slot::get().map(|s| s.controller.is_some()).unwrap_or(false)
     }
 
     /// Get the current manual time, if a manual clock is installed.
@@ -115,6 +183,6 @@ impl Clock {
     /// - `None` for realtime clocks
     /// - `Some(time)` for manual clocks
     pub fn manual_now() -> Option<DateTime<Utc>> {
-        GLOBAL.get().and_then(|s| s.handle.manual_now())
+        slot::get().and_then(|s| s.handle.manual_now())
     }
 }
@@ -1,13 +1,17 @@
 use chrono::{DateTime, Utc};
 
-use std::{sync::Arc, time::Duration};
+use std::{cmp::Reverse, collections::BinaryHeap, sync::Arc, time::Duration};
 
 use super::{
     controller::ClockController,
+    fixed::{self, FixedNow},
     inner::ClockInner,
     manual::ManualClock,
+    precision::TimestampPrecision,
     realtime::RealtimeClock,
+    scope,
     sleep::{ClockSleep, ClockTimeout},
+    stable::StableNow,
 };
 
 pub use super::sleep::Elapsed;
@@ -56,6 +60,7 @@ pub use super::sleep::Elapsed;
 #[derive(Clone)]
 pub struct ClockHandle {
     inner: Arc<ClockInner>,
+    precision: Option<TimestampPrecision>,
 }
 
 impl ClockHandle {
@@ -63,6 +68,7 @@ impl ClockHandle {
     pub fn realtime() -> Self {
         Self {
             inner: Arc::new(ClockInner::Realtime(RealtimeClock)),
+            precision: None,
         }
     }
 
@@ -83,6 +89,7 @@ impl ClockHandle {
         let clock = Arc::new(ManualClock::new());
         let handle = Self {
             inner: Arc::new(ClockInner::Manual(Arc::clone(&clock))),
+            precision: None,
         };
         let controller = ClockController { clock };
         (handle, controller)
@@ -94,6 +101,11 @@ impl ClockHandle {
     /// the common time interface, while the controller provides operations
     /// for advancing time.
     ///
+    /// The clock is frozen at `start_at` (truncated to millisecond precision)
+    /// until the controller [`advance`](ClockController::advance)s or
+    /// [`set`](ClockController::set)s it, so tests can set up state before
+    /// "starting the clock" and assert on exact timestamps.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -106,25 +118,146 @@ impl ClockHandle {
         let clock = Arc::new(ManualClock::new_at(start_at));
         let handle = Self {
             inner: Arc::new(ClockInner::Manual(Arc::clone(&clock))),
+            precision: None,
         };
         let controller = ClockController { clock };
         (handle, controller)
     }
 
+    /// Returns a handle to the same clock whose [`now`](Self::now) is truncated to `precision`.
+    ///
+    /// Useful when timestamps are compared after a round trip through a system
+    /// with lower precision than `DateTime<Utc>` (eg. Postgres `timestamptz` stores microseconds).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use es_entity::clock::{ClockHandle, TimestampPrecision};
+    ///
+    /// let clock = ClockHandle::realtime().with_precision(TimestampPrecision::Milliseconds);
+    /// assert_eq!(clock.now().timestamp_subsec_nanos() % 1_000_000, 0);
+    /// ```
+    pub fn with_precision(mut self, precision: TimestampPrecision) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
     /// Get the current time.
     ///
     /// This is a fast, synchronous operation regardless of clock type.
     ///
     /// For real-time clocks, this returns `Utc::now()`.
     /// For manual clocks, this returns the current manual time.
+    /// If a precision was configured via [`with_precision`](Self::with_precision) the time is truncated to it.
+    ///
+    /// Within a [`stable_now`](Self::stable_now) scope repeated calls return the same time
+    /// until the task yields. Within a [`with_fixed_now`](Self::with_fixed_now) scope the
+    /// fixed instant is returned instead.
     #[inline]
     pub fn now(&self) -> DateTime<Utc> {
-        match &*self.inner {
+        let now = scope::scoped_now(self.key(), || match &*self.inner {
             ClockInner::Realtime(rt) => rt.now(),
             ClockInner::Manual(clock) => clock.now(),
+        });
+        match self.precision {
+            Some(precision) => precision.truncate(now),
+            None => now,
         }
     }
 
+    /// Runs `future` so that [`now`](Self::now) is stable within each poll.
+    ///
+    /// Consecutive `now()` calls in the same synchronous section (between two points where
+    /// the task yields) return the same timestamp, so invariants like `end >= start` hold even
+    /// if time moves on in the meantime. The cached time is invalidated every time `future`
+    /// yields at an `.await` (ie. it returns `Pending`) - an `.await` on something that is
+    /// already ready does not invalidate it.
+    ///
+    /// Only `now()` calls on this clock (or its clones) are affected; the cache lives in a
+    /// thread local for the duration of a poll so it does not leak into other tasks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use es_entity::clock::ClockHandle;
+    ///
+    /// # async fn example() {
+    /// let clock = ClockHandle::realtime();
+    /// let c = clock.clone();
+    /// clock
+    ///     .stable_now(async move {
+    ///         let start = c.now();
+    ///         let end = c.now();
+    ///         assert_eq!(start, end);
+    ///     })
+    ///     .await;
+    /// # }
+    /// ```
+    pub fn stable_now<F: std::future::Future>(&self, future: F) -> StableNow<F> {
+        StableNow::new(self.key(), future)
+    }
+
+    /// Runs `f` with [`now`](Self::now) pinned to `instant`.
+    ///
+    /// A lightweight, localized override for date-dependent assertions that doesn't require
+    /// switching the whole clock to manual mode. Only `now()` calls on this clock (or its
+    /// clones) made on the current thread while `f` runs are affected - sleeps and timeouts
+    /// keep following the underlying clock. Scopes can be nested, the innermost one wins.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use es_entity::clock::ClockHandle;
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let clock = ClockHandle::realtime();
+    /// let new_year = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+    /// clock.with_fixed_now(new_year, || {
+    ///     assert_eq!(clock.now(), new_year);
+    /// });
+    /// assert_ne!(clock.now(), new_year);
+    /// ```
+    pub fn with_fixed_now<R>(&self, instant: DateTime<Utc>, f: impl FnOnce() -> R) -> R {
+        fixed::with_fixed_now(self.key(), instant, f)
+    }
+
+    /// Async variant of [`with_fixed_now`](Self::with_fixed_now).
+    ///
+    /// [`now`](Self::now) is pinned to `instant` whenever `future` is polled, so the
+    /// override holds across `.await` points on the current task without leaking into
+    /// other tasks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use es_entity::clock::ClockHandle;
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// # async fn example() {
+    /// let clock = ClockHandle::realtime();
+    /// let new_year = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+    /// let c = clock.clone();
+    /// clock
+    ///     .with_fixed_now_async(new_year, async move {
+    ///         tokio::task::yield_now().await;
+    ///         assert_eq!(c.now(), new_year);
+    ///     })
+    ///     .await;
+    /// # }
+    /// ```
+    pub fn with_fixed_now_async<F: std::future::Future>(
+        &self,
+        instant: DateTime<Utc>,
+        future: F,
+    ) -> FixedNow<F> {
+        FixedNow::new(self.key(), instant, future)
+    }
+
+    /// Identifies the shared clock state so scoped overrides apply to all clones.
+    fn key(&self) -> usize {
+        Arc::as_ptr(&self.inner) as usize
+    }
+
     /// Sleep for the given duration.
     ///
     /// For real-time clocks, this delegates to `tokio::time::sleep`.
@@ -156,6 +289,42 @@ impl ClockHandle {
         ClockTimeout::new(&self.inner, duration, future)
     }
 
+    /// Like [`timeout`](Self::timeout) but also returns the unused part of `duration`.
+    ///
+    /// The remaining budget is measured with [`now()`](Self::now) when `future` completes,
+    /// so under a manual clock it reflects how much simulated time was left.
+    /// Handy to carry a budget through a chain of bounded steps.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use es_entity::clock::ClockHandle;
+    /// use std::time::Duration;
+    ///
+    /// # async fn example() {
+    /// let clock = ClockHandle::realtime();
+    /// let (value, remaining) = clock
+    ///     .timeout_with_remaining(Duration::from_secs(5), async { 42 })
+    ///     .await
+    ///     .unwrap();
+    /// assert_eq!(value, 42);
+    /// assert!(remaining <= Duration::from_secs(5));
+    /// # }
+    /// ```
+    pub async fn timeout_with_remaining<F>(
+        &self,
+        duration: Duration,
+        future: F,
+    ) -> Result<(F::Output, Duration), Elapsed>
+    where
+        F: std::future::Future,
+    {
+        let start = self.now();
+        let output = self.timeout(duration, future).await?;
+        let remaining = duration.saturating_sub(self.elapsed_since(start));
+        Ok((output, remaining))
+    }
+
     /// Check if this clock is manual (as opposed to realtime).
     pub fn is_manual(&self) -> bool {
         matches!(&*self.inner, ClockInner::Manual(_))
@@ -169,6 +338,75 @@ impl ClockHandle {
         self.now().date_naive()
     }
 
+    /// Check whether `deadline` has been reached.
+    ///
+    /// Returns `true` once [`now()`](Self::now) is at or past `deadline`.
+    /// Under a manual clock nothing becomes due until the controller
+    /// advances time across the deadline.
+    #[inline]
+    pub fn is_due(&self, deadline: DateTime<Utc>) -> bool {
+        deadline <= self.now()
+    }
+
+    /// Time elapsed between `earlier` and [`now()`](Self::now).
+    ///
+    /// Saturates to [`Duration::ZERO`] if `earlier` lies in the future.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use es_entity::clock::ClockHandle;
+    /// use std::time::Duration;
+    ///
+    /// # async fn example() {
+    /// let (clock, ctrl) = ClockHandle::manual();
+    /// let start = clock.now();
+    ///
+    /// ctrl.advance(Duration::from_secs(30)).await;
+    /// assert_eq!(clock.elapsed_since(start), Duration::from_secs(30));
+    /// # }
+    /// ```
+    #[inline]
+    pub fn elapsed_since(&self, earlier: DateTime<Utc>) -> Duration {
+        (self.now() - earlier).to_std().unwrap_or(Duration::ZERO)
+    }
+
+    /// Pop every item whose deadline has been reached from a min-heap of
+    /// `(deadline, item)` pairs.
+    ///
+    /// Items are returned in deadline order. Entries that are not yet due
+    /// remain in the heap.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use es_entity::clock::ClockHandle;
+    /// use std::{cmp::Reverse, collections::BinaryHeap, time::Duration};
+    ///
+    /// # async fn example() {
+    /// let (clock, ctrl) = ClockHandle::manual();
+    /// let mut timers = BinaryHeap::new();
+    /// timers.push(Reverse((clock.now() + chrono::Duration::seconds(10), "ping")));
+    ///
+    /// assert!(clock.due(&mut timers).is_empty());
+    /// ctrl.advance(Duration::from_secs(10)).await;
+    /// assert_eq!(clock.due(&mut timers), vec!["ping"]);
+    /// # }
+    /// ```
+    pub fn due<T: Ord>(&self, items: &mut BinaryHeap<Reverse<(DateTime<Utc>, T)>>) -> Vec<T> {
+        let now = self.now();
+        let mut due = Vec::new();
+        while items
+            .peek()
+            .is_some_and(|Reverse((deadline, _))| *deadline <= now)
+        {
+            if let Some(Reverse((_, item))) = items.pop() {
+                due.push(item);
+            }
+        }
+        due
+    }
+
     /// Get the current manual time, if this is a manual clock.
     ///
     /// Returns:
@@ -177,6 +415,10 @@ impl ClockHandle {
     ///
     /// This is useful for code that needs to cache time when running under
     /// manual clocks but use fresh time for realtime clocks.
+    ///
+    /// Scoped overrides ([`with_fixed_now`](Self::with_fixed_now),
+    /// [`stable_now`](Self::stable_now)) and [`with_precision`](Self::with_precision)
+    /// only apply to [`now`](Self::now) - this always reports the controller's time.
     pub fn manual_now(&self) -> Option<DateTime<Utc>> {
         match &*self.inner {
             ClockInner::Realtime(_) => None,
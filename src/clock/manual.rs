@@ -261,6 +261,22 @@ impl ManualClock {
     pub fn pending_wake_count(&self) -> usize {
         self.pending_wakes.lock().len() + self.coalesce_wakes.lock().len()
     }
+
+    /// Snapshot of the pending wake times (both regular and coalesceable), earliest first.
+    pub fn pending_wakes(&self) -> Vec<DateTime<Utc>> {
+        let mut wakes: Vec<_> = self
+            .pending_wakes
+            .lock()
+            .iter()
+            .chain(self.coalesce_wakes.lock().iter())
+            .map(|w| w.wake_at_ms)
+            .collect();
+        wakes.sort_unstable();
+        wakes
+            .into_iter()
+            .map(|ms| DateTime::from_timestamp_millis(ms).expect("valid timestamp"))
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -298,4 +314,24 @@ mod tests {
 
         assert_eq!(clock.next_wake_time(), Some(3000));
     }
+
+    #[test]
+    fn test_pending_wakes_snapshot() {
+        let clock = ManualClock::new_at(DateTime::from_timestamp_millis(0).unwrap());
+
+        let waker = futures::task::noop_waker();
+
+        clock.register_wake(3000, 1, waker.clone());
+        clock.register_coalesce_wake(2000, 2, waker.clone());
+        clock.register_wake(1000, 3, waker);
+
+        let expected: Vec<_> = [1000, 2000, 3000]
+            .into_iter()
+            .map(|ms| DateTime::from_timestamp_millis(ms).unwrap())
+            .collect();
+        assert_eq!(clock.pending_wakes(), expected);
+        // Taking the snapshot leaves the queues untouched
+        assert_eq!(clock.pending_wakes(), expected);
+        assert_eq!(clock.pending_wake_count(), 3);
+    }
 }
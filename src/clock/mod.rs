@@ -63,15 +63,22 @@
 #![forbid(unsafe_code)]
 
 mod controller;
+mod fixed;
 mod global;
 mod handle;
 mod inner;
 mod manual;
+mod precision;
 mod realtime;
+mod scope;
 mod sleep;
+mod stable;
 
 // Re-export public API
 pub use controller::ClockController;
+pub use fixed::FixedNow;
 pub use global::Clock;
 pub use handle::{ClockHandle, Elapsed};
+pub use precision::TimestampPrecision;
 pub use sleep::{ClockSleep, ClockTimeout};
+pub use stable::StableNow;
@@ -0,0 +1,20 @@
+use chrono::{DateTime, SubsecRound, Utc};
+
+/// Sub-second precision that timestamps can be truncated to.
+///
+/// See [`ClockHandle::with_precision`](super::ClockHandle::with_precision).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimestampPrecision {
+    Milliseconds,
+    Microseconds,
+}
+
+impl TimestampPrecision {
+    /// Truncates (not rounds) `time` to this precision.
+    pub fn truncate(&self, time: DateTime<Utc>) -> DateTime<Utc> {
+        match self {
+            Self::Milliseconds => time.trunc_subsecs(3),
+            Self::Microseconds => time.trunc_subsecs(6),
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use chrono::{DateTime, Utc};
+
+use std::cell::RefCell;
+
+/// An override of `now()` for the clock identified by `key`.
+///
+/// Fixed scopes carry their instant up front, stable scopes cache the first
+/// `now()` of a poll (unless they are nested in a fixed scope of the same clock).
+struct Scope {
+    key: usize,
+    now: Option<DateTime<Utc>>,
+    fixed: bool,
+}
+
+thread_local! {
+    /// Clocks (keyed by the address of their shared inner state) that are currently
+    /// within a [`StableNow`](super::StableNow) or fixed `now()` scope, innermost last.
+    static SCOPES: RefCell<Vec<Scope>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Keeps a scope installed until dropped.
+pub(super) struct ScopeGuard;
+
+impl ScopeGuard {
+    /// Enters a scope caching the first `now()` of the clock identified by `key`.
+    pub(super) fn stable(key: usize) -> Self {
+        SCOPES.with(|scopes| {
+            let mut scopes = scopes.borrow_mut();
+            // An enclosing fixed instant keeps winning
+            let fixed = scopes
+                .iter()
+                .rev()
+                .find(|scope| scope.key == key)
+                .filter(|scope| scope.fixed)
+                .and_then(|scope| scope.now);
+            scopes.push(Scope {
+                key,
+                now: fixed,
+                fixed: fixed.is_some(),
+            });
+        });
+        ScopeGuard
+    }
+
+    /// Enters a scope pinning `now()` of the clock identified by `key` to `now`.
+    pub(super) fn fixed(key: usize, now: DateTime<Utc>) -> Self {
+        SCOPES.with(|scopes| {
+            scopes.borrow_mut().push(Scope {
+                key,
+                now: Some(now),
+                fixed: true,
+            })
+        });
+        ScopeGuard
+    }
+}
+
+impl Drop for ScopeGuard {
+    fn drop(&mut self) {
+        SCOPES.with(|scopes| scopes.borrow_mut().pop());
+    }
+}
+
+/// Returns the time of the innermost scope of the clock identified by `key`,
+/// caching the result of `now` on first access in a stable scope.
+pub(super) fn scoped_now(key: usize, now: impl FnOnce() -> DateTime<Utc>) -> DateTime<Utc> {
+    SCOPES.with(|scopes| {
+        let mut scopes = scopes.borrow_mut();
+        match scopes.iter_mut().rev().find(|scope| scope.key == key) {
+            Some(scope) => *scope.now.get_or_insert_with(now),
+            None => now(),
+        }
+    })
+}
@@ -0,0 +1,39 @@
+use pin_project::pin_project;
+
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use super::scope::ScopeGuard;
+
+/// A future whose synchronous sections see a consistent [`ClockHandle::now`](super::ClockHandle::now).
+///
+/// Created by [`ClockHandle::stable_now`](super::ClockHandle::stable_now).
+/// The first call to `now()` during a poll caches the time, subsequent calls
+/// within the same poll return the cached value. The cache is invalidated
+/// whenever the wrapped future yields (an `.await` returning `Pending`) - an
+/// `.await` that completes immediately does *not* invalidate it.
+#[pin_project]
+pub struct StableNow<F> {
+    key: usize,
+    #[pin]
+    future: F,
+}
+
+impl<F> StableNow<F> {
+    pub(super) fn new(key: usize, future: F) -> Self {
+        Self { key, future }
+    }
+}
+
+impl<F: Future> Future for StableNow<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let _guard = ScopeGuard::stable(*this.key);
+        this.future.poll(cx)
+    }
+}
@@ -31,6 +31,9 @@
 //! // Only request_id remains in parent
 //! ```
 //!
+//! [`EventContext::scope`] (and [`EventContext::scope_async`] for futures) does the
+//! same without having to keep the forked handle alive in the right place.
+//!
 //! ## Async Task Context
 //! ```rust
 //! use es_entity::context::{EventContext, WithEventContext};
@@ -62,12 +65,43 @@
 //! });
 //! ```
 //!
+//! # Capturing Arguments
+//!
+//! The [`es_event_context`](crate::es_event_context) attribute inserts function arguments
+//! (or fields of them) into a forked context, keyed by the last segment of their path.
+//! Two captures sharing a key are rejected at compile time:
+//!
+//! ```compile_fail
+//! pub struct Actor {
+//!     id: u64,
+//! }
+//!
+//! pub struct Command {
+//!     id: u64,
+//!     actor: Actor,
+//! }
+//!
+//! // both are stored under "id"
+//! #[es_entity::es_event_context(cmd.id, cmd.actor.id)]
+//! fn handle(cmd: Command) {}
+//! ```
+//!
 //! # Database Integration
 //!
 //! When events are persisted using repositories with `event_context = true`, the current
 //! context is automatically serialized to JSON and stored in a `context` column
 //! alongside the event data, enabling comprehensive audit trails and debugging.
+//! Keys matching the [redaction policy](set_redaction_policy) are masked before
+//! they are written.
+//!
+//! # Replaying Traces
+//!
+//! With the `tracing-context` feature the persisted context also records the span that
+//! wrote the event under the `"tracing"` key. [`TracingContext::from_context_data`] reads
+//! it back from a loaded event's `context` and `TracingContext::add_as_link` links the
+//! current span to it, so replays can be correlated with the original trace.
 
+mod redaction;
 mod sqlx;
 mod tracing;
 mod with_event_context;
@@ -76,6 +110,7 @@ use serde::{Deserialize, Serialize};
 
 use std::{borrow::Cow, cell::RefCell, rc::Rc};
 
+pub use redaction::*;
 pub use tracing::*;
 pub use with_event_context::*;
 
@@ -93,7 +128,7 @@ pub use with_event_context::*;
 pub struct ContextData(im::HashMap<Cow<'static, str>, serde_json::Value>);
 
 impl ContextData {
-    fn new() -> Self {
+    pub(crate) fn new() -> Self {
         Self(im::HashMap::new())
     }
 
@@ -101,6 +136,10 @@ impl ContextData {
         self.0 = self.0.update(Cow::Borrowed(key), value);
     }
 
+    fn remove(&mut self, key: &str) {
+        self.0 = self.0.without(key);
+    }
+
     #[cfg(feature = "tracing-context")]
     pub(crate) fn with_tracing_info(mut self) -> Self {
         let tracing = TracingContext::current();
@@ -111,6 +150,36 @@ impl ContextData {
         self
     }
 
+    /// Returns the raw JSON value stored under `key`, if any.
+    pub fn get(&self, key: &str) -> Option<&serde_json::Value> {
+        self.0.get(key)
+    }
+
+    /// Returns the number of keys in this context data.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if no keys are set.
+    ///
+    /// Empty contexts are persisted as `NULL` rather than as an empty JSON object.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns an iterator over the keys present in this context data.
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.0.keys().map(|k| k.as_ref())
+    }
+
+    /// Serializes the context data as it is persisted, with the
+    /// [redaction policy](set_redaction_policy) applied.
+    pub fn as_json(&self) -> serde_json::Value {
+        let mut value = serde_json::to_value(&self.0).expect("Could not serialize context");
+        redaction::redact(&mut value);
+        value
+    }
+
     pub fn lookup<T: serde::de::DeserializeOwned>(
         &self,
         key: &'static str,
@@ -147,6 +216,7 @@ thread_local! {
 ///
 /// - **Same thread**: Use [`fork()`](Self::fork) to create isolated child contexts
 /// - **Async tasks**: Use [`with_event_context()`](WithEventContext::with_event_context) from the [`WithEventContext`] trait
+/// - **Streams**: Use [`with_event_context()`](WithEventContextStream::with_event_context) from the [`WithEventContextStream`] trait
 /// - **New threads**: Use [`seed()`](Self::seed) with data from [`data()`](Self::data) to transfer context
 ///
 /// # Examples
@@ -282,6 +352,57 @@ impl EventContext {
         Self::seed(data)
     }
 
+    /// Runs `f` within a forked context.
+    ///
+    /// Like holding a [`fork()`](Self::fork) for the duration of `f` but without
+    /// having to manage the lifetime of the handle: the forked entry is popped
+    /// before returning, also when `f` returns early or panics. Changes made inside
+    /// `f` are not visible to the caller's context afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use es_entity::context::EventContext;
+    ///
+    /// let mut ctx = EventContext::current();
+    /// ctx.insert("request_id", &"abc123").unwrap();
+    ///
+    /// EventContext::scope(|| {
+    ///     EventContext::current().insert("operation", &"update").unwrap();
+    ///     // both "request_id" and "operation" are available here
+    /// });
+    /// assert!(ctx.data().get("operation").is_none());
+    /// ```
+    pub fn scope<T>(f: impl FnOnce() -> T) -> T {
+        let _forked = Self::fork();
+        f()
+    }
+
+    /// Async variant of [`scope`](Self::scope).
+    ///
+    /// Captures the current context data now and runs `future` with a copy of it
+    /// via [`with_event_context`](WithEventContext::with_event_context), so changes
+    /// made while polling it never leak into the caller's context.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use es_entity::context::EventContext;
+    ///
+    /// # async fn example() {
+    /// EventContext::scope_async(async {
+    ///     EventContext::current().insert("operation", &"update").unwrap();
+    ///     tokio::task::yield_now().await;
+    ///     // "operation" is still available here
+    /// })
+    /// .await;
+    /// assert!(EventContext::current().data().get("operation").is_none());
+    /// # }
+    /// ```
+    pub fn scope_async<F: std::future::Future>(future: F) -> EventContextFuture<F> {
+        future.with_event_context(Self::current().data())
+    }
+
     /// Inserts a key-value pair into the current context.
     ///
     /// The value will be serialized to JSON and stored in the context data.
@@ -327,6 +448,39 @@ impl EventContext {
         Ok(())
     }
 
+    /// Removes a key from the current context.
+    ///
+    /// Only this context's data is affected - parent contexts (e.g. the one
+    /// this context was forked from) retain the key once this context is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use es_entity::context::EventContext;
+    ///
+    /// let mut ctx = EventContext::current();
+    /// ctx.insert("raw_password", &"hunter2").unwrap();
+    ///
+    /// {
+    ///     let mut child = EventContext::fork();
+    ///     child.remove("raw_password");
+    ///     // child context no longer has "raw_password"
+    /// }
+    /// // parent context still has "raw_password"
+    /// ```
+    pub fn remove(&mut self, key: &str) {
+        CONTEXT_STACK.with(|c| {
+            let mut stack = c.borrow_mut();
+            for entry in stack.iter_mut().rev() {
+                if Rc::ptr_eq(&entry.id, &self.id) {
+                    entry.data.remove(key);
+                    return;
+                }
+            }
+            panic!("EventContext missing on CONTEXT_STACK")
+        });
+    }
+
     /// Returns a copy of the current context data.
     ///
     /// This method returns a snapshot of all key-value pairs stored in this context.
@@ -446,6 +600,75 @@ mod tests {
         assert_eq!(current_json(), serde_json::json!({ "data": value }));
     }
 
+    #[test]
+    fn remove() {
+        let mut ctx = EventContext::current();
+        ctx.insert("raw_password", &"hunter2").unwrap();
+        ctx.insert("user_id", &42).unwrap();
+
+        {
+            let mut forked = EventContext::fork();
+            forked.remove("raw_password");
+            assert_eq!(current_json(), serde_json::json!({ "user_id": 42 }));
+            forked.remove("missing");
+            assert_eq!(current_json(), serde_json::json!({ "user_id": 42 }));
+        }
+
+        assert_eq!(
+            current_json(),
+            serde_json::json!({ "raw_password": "hunter2", "user_id": 42 })
+        );
+
+        ctx.remove("raw_password");
+        assert_eq!(current_json(), serde_json::json!({ "user_id": 42 }));
+    }
+
+    #[test]
+    fn scope() {
+        let mut ctx = EventContext::current();
+        ctx.insert("parent", &"value").unwrap();
+
+        let res = EventContext::scope(|| {
+            EventContext::current().insert("child", &"value").unwrap();
+            assert_eq!(stack_depth(), 2);
+            current_json()
+        });
+        assert_eq!(
+            res,
+            serde_json::json!({ "parent": "value", "child": "value" })
+        );
+        assert_eq!(stack_depth(), 1);
+        assert_eq!(current_json(), serde_json::json!({ "parent": "value" }));
+
+        let panicked = std::panic::catch_unwind(|| {
+            EventContext::scope(|| {
+                EventContext::current().insert("child", &"value").unwrap();
+                panic!("inside scope");
+            })
+        });
+        assert!(panicked.is_err());
+        assert_eq!(stack_depth(), 1);
+        assert_eq!(current_json(), serde_json::json!({ "parent": "value" }));
+    }
+
+    #[tokio::test]
+    async fn scope_async() {
+        let mut ctx = EventContext::current();
+        ctx.insert("parent", &"value").unwrap();
+
+        let res = EventContext::scope_async(async {
+            EventContext::current().insert("child", &"value").unwrap();
+            tokio::task::yield_now().await;
+            current_json()
+        })
+        .await;
+        assert_eq!(
+            res,
+            serde_json::json!({ "parent": "value", "child": "value" })
+        );
+        assert_eq!(current_json(), serde_json::json!({ "parent": "value" }));
+    }
+
     #[tokio::test]
     async fn async_context() {
         async fn inner_async() {
@@ -473,6 +696,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn data_get_and_keys() {
+        let mut ctx = EventContext::current();
+        ctx.insert("request_id", &"req-123").unwrap();
+        ctx.insert("user_id", &42).unwrap();
+
+        let data = ctx.data();
+        assert_eq!(data.get("request_id"), Some(&serde_json::json!("req-123")));
+        assert_eq!(data.get("user_id"), Some(&serde_json::json!(42)));
+        assert_eq!(data.get("missing"), None);
+
+        let mut keys: Vec<_> = data.keys().collect();
+        keys.sort();
+        assert_eq!(keys, vec!["request_id", "user_id"]);
+    }
+
+    #[test]
+    fn data_len_and_is_empty() {
+        let mut ctx = EventContext::current();
+        assert!(ctx.data().is_empty());
+        assert_eq!(ctx.data().len(), 0);
+
+        ctx.insert("request_id", &"req-123").unwrap();
+        let data = ctx.data();
+        assert!(!data.is_empty());
+        assert_eq!(data.len(), 1);
+    }
+
     #[test]
     fn fork() {
         let mut ctx = EventContext::current();
@@ -560,4 +811,41 @@ mod tests {
 
         assert_eq!(current_json(), serde_json::json!({ "parent": "context" }));
     }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn with_event_context_stream() {
+        use futures::StreamExt;
+
+        let mut ctx = EventContext::current();
+        ctx.insert("parent", &serde_json::json!("context")).unwrap();
+        let data = ctx.data();
+
+        let handle = tokio::spawn(async move {
+            let mut stream = futures::stream::iter([("item_0", 0), ("item_1", 1), ("item_2", 2)])
+                .map(|(key, i)| {
+                    assert_eq!(stack_depth(), 1);
+                    EventContext::current().insert(key, &i).unwrap();
+                    current_json()
+                })
+                .with_event_context(data);
+            let mut items = Vec::new();
+            while let Some(item) = stream.next().await {
+                // The context does not outlive the poll
+                assert_eq!(stack_depth(), 0);
+                items.push(item);
+            }
+            items
+        });
+
+        let items = handle.await.unwrap();
+        assert_eq!(
+            items[0],
+            serde_json::json!({ "parent": "context", "item_0": 0 })
+        );
+        assert_eq!(
+            items[2],
+            serde_json::json!({ "parent": "context", "item_0": 0, "item_1": 1, "item_2": 2 })
+        );
+        assert_eq!(current_json(), serde_json::json!({ "parent": "context" }));
+    }
 }
@@ -0,0 +1,102 @@
+use parking_lot::RwLock;
+
+/// Placeholder written in place of a redacted context value.
+pub const REDACTED: &str = "[REDACTED]";
+
+static REDACTION_POLICY: RwLock<Vec<String>> = RwLock::new(Vec::new());
+
+/// Sets the process-wide redaction policy applied when [`ContextData`](super::ContextData)
+/// is persisted.
+///
+/// Every top-level context key matching one of the `patterns` has its value replaced by
+/// [`REDACTED`] before it is written to the context column - regardless of where the key
+/// was inserted. A pattern is either an exact key or contains `*` wildcards that match any
+/// sequence of characters (e.g. `"password"`, `"*_token"`, `"secret*"`).
+///
+/// Replaces any previously configured policy.
+///
+/// # Examples
+///
+/// ```rust
+/// use es_entity::context::{self, EventContext};
+///
+/// context::set_redaction_policy(["password", "*_token"]);
+///
+/// let mut ctx = EventContext::current();
+/// ctx.insert("password", &"hunter2").unwrap();
+/// ctx.insert("api_token", &"abc").unwrap();
+/// ctx.insert("user_id", &"123").unwrap();
+///
+/// assert_eq!(
+///     ctx.data().as_json(),
+///     serde_json::json!({
+///         "password": "[REDACTED]",
+///         "api_token": "[REDACTED]",
+///         "user_id": "123"
+///     })
+/// );
+/// # context::clear_redaction_policy();
+/// ```
+pub fn set_redaction_policy<P: Into<String>>(patterns: impl IntoIterator<Item = P>) {
+    *REDACTION_POLICY.write() = patterns.into_iter().map(Into::into).collect();
+}
+
+/// Removes the process-wide redaction policy.
+pub fn clear_redaction_policy() {
+    REDACTION_POLICY.write().clear();
+}
+
+pub(super) fn redact(value: &mut serde_json::Value) {
+    let policy = REDACTION_POLICY.read();
+    if policy.is_empty() {
+        return;
+    }
+    let Some(map) = value.as_object_mut() else {
+        return;
+    };
+    for (key, value) in map.iter_mut() {
+        if policy.iter().any(|pattern| matches(pattern, key)) {
+            *value = serde_json::Value::String(REDACTED.to_string());
+        }
+    }
+}
+
+fn matches(pattern: &str, key: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = key.strip_prefix(first) else {
+        return false;
+    };
+    let mut parts = parts.peekable();
+    if parts.peek().is_none() {
+        return rest.is_empty();
+    }
+    while let Some(part) = parts.next() {
+        if parts.peek().is_none() {
+            return rest.ends_with(part);
+        }
+        match rest.find(part) {
+            Some(idx) => rest = &rest[idx + part.len()..],
+            None => return false,
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pattern_matching() {
+        assert!(matches("password", "password"));
+        assert!(!matches("password", "password_hash"));
+        assert!(matches("password*", "password_hash"));
+        assert!(matches("*_token", "api_token"));
+        assert!(!matches("*_token", "token_type"));
+        assert!(matches("*secret*", "my_secret_key"));
+        assert!(matches("a*b*c", "abc"));
+        assert!(!matches("a*b*c", "acb"));
+        assert!(matches("*", "anything"));
+    }
+}
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use super::ContextData;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TracingContext {
     pub trace_id: String,
@@ -10,6 +12,12 @@ pub struct TracingContext {
 }
 
 impl TracingContext {
+    /// Reads the tracing context that was captured under the `"tracing"` key
+    /// when the event carrying `data` was persisted.
+    pub fn from_context_data(data: &ContextData) -> Option<Self> {
+        data.lookup("tracing").ok().flatten()
+    }
+
     #[cfg(feature = "tracing-context")]
     pub fn current() -> Option<Self> {
         use opentelemetry::trace::TraceContextExt;
@@ -52,4 +60,54 @@ impl TracingContext {
         let extracted_context = propagator.extract(&carrier);
         let _ = tracing::Span::current().set_parent(extracted_context);
     }
+
+    /// Adds a (follows-from) link to the stored span to the current span.
+    ///
+    /// Unlike [`inject_as_parent`](Self::inject_as_parent) the current span stays in its
+    /// own trace, eg. when replaying persisted events the replay shows up as
+    /// following the trace that originally recorded them.
+    #[cfg(feature = "tracing-context")]
+    pub fn add_as_link(&self) {
+        use opentelemetry::propagation::TextMapPropagator;
+        use opentelemetry::trace::TraceContextExt;
+        use opentelemetry_sdk::propagation::TraceContextPropagator;
+        use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+        let mut carrier = std::collections::HashMap::new();
+
+        carrier.insert("traceparent".to_string(), self.traceparent.clone());
+
+        let propagator = TraceContextPropagator::new();
+        let extracted_context = propagator.extract(&carrier);
+        let span_context = extracted_context.span().span_context().clone();
+        if span_context.is_valid() {
+            tracing::Span::current().add_link(span_context);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_context_data() {
+        let mut data = ContextData::new();
+        assert!(TracingContext::from_context_data(&data).is_none());
+
+        data.insert("tracing", serde_json::Value::Null);
+        assert!(TracingContext::from_context_data(&data).is_none());
+
+        data.insert(
+            "tracing",
+            serde_json::json!({
+                "trace_id": "4bf92f3577b34da6a3ce929d0e0e4736",
+                "span_id": "00f067aa0ba902b7",
+                "trace_flags": 1,
+                "traceparent": "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+            }),
+        );
+        let tracing = TracingContext::from_context_data(&data).expect("tracing context");
+        assert_eq!(tracing.span_id, "00f067aa0ba902b7");
+    }
 }
@@ -1,3 +1,4 @@
+use futures_core::Stream;
 use pin_project::pin_project;
 
 use std::{
@@ -88,3 +89,81 @@ impl<F: Future> Future for EventContextFuture<F> {
         res
     }
 }
+
+/// Extension trait for propagating event context into the items of a `Stream`.
+///
+/// The stream counterpart of [`WithEventContext`]: every call to `poll_next` runs
+/// with the seeded context active, so events recorded while producing an item carry
+/// the parent context even when the stream is driven from a spawned task.
+///
+/// # Examples
+///
+/// ```rust
+/// use es_entity::context::{EventContext, WithEventContextStream};
+/// use futures::StreamExt;
+///
+/// async fn example() {
+///     let mut ctx = EventContext::current();
+///     ctx.insert("request_id", &"abc123").unwrap();
+///
+///     let data = ctx.data();
+///     tokio::spawn(async move {
+///         futures::stream::iter([1, 2, 3])
+///             .map(|item| {
+///                 // Context is available while each item is produced
+///                 let current = EventContext::current();
+///                 item
+///             })
+///             .with_event_context(data)
+///             .collect::<Vec<_>>()
+///             .await
+///     })
+///     .await
+///     .unwrap();
+/// }
+/// ```
+pub trait WithEventContextStream: Stream {
+    /// Wraps this stream with event context data.
+    ///
+    /// The context is entered around every `poll_next` and torn down again before
+    /// it returns, so it never leaks into unrelated work on the same thread between
+    /// polls. Changes made to the context while polling are carried over to the
+    /// next poll.
+    fn with_event_context(self, context_data: ContextData) -> EventContextStream<Self>
+    where
+        Self: Sized,
+    {
+        EventContextStream {
+            stream: self,
+            context_data,
+        }
+    }
+}
+
+impl<S: Stream> WithEventContextStream for S {}
+
+/// A stream wrapper that provides event context while polling for the next item.
+///
+/// Created by [`WithEventContextStream::with_event_context`].
+#[pin_project]
+pub struct EventContextStream<S> {
+    #[pin]
+    stream: S,
+    context_data: ContextData,
+}
+
+impl<S: Stream> Stream for EventContextStream<S> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let ctx = EventContext::seed(this.context_data.clone());
+        let res = this.stream.poll_next(cx);
+        *this.context_data = ctx.data();
+        res
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
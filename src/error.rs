@@ -11,13 +11,100 @@ pub enum EntityHydrationError {
     EventDeserialization(#[from] serde_json::Error),
 }
 
+/// Error returned when a combined cursor is converted into the cursor of a different sort order.
 #[derive(Error, Debug)]
-#[error("CursorDestructureError: couldn't turn {0} into {1}")]
-pub struct CursorDestructureError(&'static str, &'static str);
+#[error("{}", self.message())]
+pub struct CursorDestructureError {
+    cursor: &'static str,
+    expected: &'static str,
+    field: Option<&'static str>,
+}
+
+impl CursorDestructureError {
+    /// The name of the cursor type that was expected.
+    pub fn expected_cursor(&self) -> &'static str {
+        self.expected
+    }
+
+    /// The field the expected cursor is keyed on, which the supplied cursor lacks.
+    pub fn missing_field(&self) -> Option<&'static str> {
+        self.field
+    }
+
+    fn message(&self) -> String {
+        match self.field {
+            Some(field) => format!(
+                "CursorDestructureError: expected {}, missing field `{}`",
+                self.expected, field
+            ),
+            None => format!(
+                "CursorDestructureError: couldn't turn {} into {}",
+                self.cursor, self.expected
+            ),
+        }
+    }
+}
 
 impl From<(&'static str, &'static str)> for CursorDestructureError {
     fn from((name, variant): (&'static str, &'static str)) -> Self {
-        Self(name, variant)
+        Self {
+            cursor: name,
+            expected: variant,
+            field: None,
+        }
+    }
+}
+
+impl From<(&'static str, &'static str, &'static str)> for CursorDestructureError {
+    fn from((name, variant, field): (&'static str, &'static str, &'static str)) -> Self {
+        Self {
+            cursor: name,
+            expected: variant,
+            field: Some(field),
+        }
+    }
+}
+
+/// Error returned by the generated `Filters::validate` when a combination of
+/// `list_for_filters` filters can never match any entity.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum FilterError {
+    #[error("FilterError - EmptyRange: `{0}` has its lower bound after its upper bound")]
+    EmptyRange(&'static str),
+}
+
+/// Error returned when converting a user supplied `first` into [`PaginatedQueryArgs`](crate::PaginatedQueryArgs).
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum PaginatedQueryArgsError {
+    #[error("PaginatedQueryArgsError - NegativeFirst: {0}")]
+    NegativeFirst(i32),
+}
+
+/// Error returned when parsing a generated `<Entity>SortBy` from a string that is not one of its columns.
+#[derive(Error, Debug, PartialEq, Eq)]
+#[error("SortByParseError: unknown sort `{value}`, expected one of: {}", valid.join(", "))]
+pub struct SortByParseError {
+    value: String,
+    valid: &'static [&'static str],
+}
+
+impl SortByParseError {
+    #[doc(hidden)]
+    pub fn new(value: impl Into<String>, valid: &'static [&'static str]) -> Self {
+        Self {
+            value: value.into(),
+            valid,
+        }
+    }
+
+    /// The value that could not be parsed.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// The accepted (snake_case) column names.
+    pub fn valid_values(&self) -> &'static [&'static str] {
+        self.valid
     }
 }
 
@@ -128,6 +215,17 @@ mod tests {
         assert_eq!(parse_constraint_detail_value(detail), Some("".to_string()));
     }
 
+    #[test]
+    fn cursor_destructure_error_names_missing_field() {
+        let err = CursorDestructureError::from(("OrderCursor", "OrdersByStatusCursor", "status"));
+        assert_eq!(err.expected_cursor(), "OrdersByStatusCursor");
+        assert_eq!(err.missing_field(), Some("status"));
+        assert_eq!(
+            err.to_string(),
+            "CursorDestructureError: expected OrdersByStatusCursor, missing field `status`"
+        );
+    }
+
     #[test]
     fn not_found_value_uses_display_when_available() {
         #[allow(unused_imports)]
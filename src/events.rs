@@ -21,6 +21,49 @@ pub struct GenericEvent<Id> {
     pub forgettable_payload: Option<serde_json::Value>,
 }
 
+/// A row of the events table as stored - `event` is not deserialized.
+///
+/// Returned by the generated `load_events_raw` to inspect streams that fail to hydrate.
+#[derive(Debug, Clone)]
+pub struct RawEvent {
+    /// The sequence number of the event in the event stream
+    pub sequence: usize,
+    /// The value of the `event_type` column
+    pub event_type: String,
+    /// The serialized event as stored in the `event` column
+    pub event: serde_json::Value,
+    /// The timestamp which marks event persistence
+    pub recorded_at: DateTime<Utc>,
+    /// The serialized context (if one was persisted)
+    pub context: Option<serde_json::Value>,
+}
+
+/// Key under which `#[es_event(version = N)]` is stored alongside `type`.
+const SCHEMA_VERSION_KEY: &str = "schema_version";
+
+/// Deserializes a loaded event together with the `schema_version` it was written with.
+///
+/// Only versioned events own the `schema_version` key - for other events it may well be
+/// a field of their own. Unversioned events and rows written before an event was
+/// versioned count as version 1.
+fn deserialize_versioned<T: EsEvent>(
+    payload: serde_json::Value,
+) -> Result<(T, u32), serde_json::Error> {
+    let stored = payload
+        .get(SCHEMA_VERSION_KEY)
+        .and_then(serde_json::Value::as_u64)
+        .and_then(|v| u32::try_from(v).ok());
+    let event: T = serde_json::from_value(payload)?;
+    let schema_version = event.schema_version().and(stored).unwrap_or(1);
+    Ok((event, schema_version))
+}
+
+/// Empty contexts are stored as `NULL` (older rows may hold `{}`) - both load as an empty
+/// context for events that carry one.
+fn loaded_context<T: EsEvent>(context: Option<crate::ContextData>) -> Option<crate::ContextData> {
+    context.or_else(|| T::event_context().then(crate::ContextData::new))
+}
+
 /// Strongly-typed event wrapper with metadata for successfully stored events.
 ///
 /// Contains the event data along with persistence metadata (sequence, timestamp, entity_id).
@@ -38,6 +81,8 @@ pub struct PersistedEvent<E: EsEvent> {
     /// The context when the event was persisted
     /// It is only popluated if 'event_context' set on EsEvent
     pub context: Option<crate::ContextData>,
+    /// The `schema_version` the event was written with (1 if none was recorded)
+    pub schema_version: u32,
 }
 
 impl<E: Clone + EsEvent> Clone for PersistedEvent<E> {
@@ -48,6 +93,7 @@ impl<E: Clone + EsEvent> Clone for PersistedEvent<E> {
             sequence: self.sequence,
             event: self.event.clone(),
             context: self.context.clone(),
+            schema_version: self.schema_version,
         }
     }
 }
@@ -125,6 +171,9 @@ where
     }
 
     /// Returns the timestamp of the last persisted event, indicating when the entity was last modified
+    ///
+    /// Pairs with [`entity_first_persisted_at`](Self::entity_first_persisted_at) - events that
+    /// have not been persisted yet are not taken into account.
     pub fn entity_last_modified_at(&self) -> Option<DateTime<Utc>> {
         self.persisted_events.last().map(|e| e.recorded_at)
     }
@@ -153,6 +202,50 @@ where
             }));
     }
 
+    /// Appends the events of `history` that are not yet part of this stream.
+    ///
+    /// `history` is treated as the complete event stream of the entity (eg. its current
+    /// state as delivered by a sync pipeline) so the events in `self` must be a prefix of it.
+    /// Events are compared by their serialized form. Returns the number of appended events
+    /// or `None` if the streams diverge, in which case `self` is left untouched.
+    pub fn append_missing(&mut self, history: EntityEvents<T>) -> Option<usize> {
+        let history: Vec<T> = history
+            .persisted_events
+            .into_iter()
+            .map(|e| e.event)
+            .chain(history.new_events.into_iter().map(|e| e.event))
+            .collect();
+        let n_known = self.persisted_events.len() + self.new_events.len();
+        if history.len() < n_known {
+            return None;
+        }
+        let serialize = |event: &T| serde_json::to_value(event).expect("Failed to serialize event");
+        if !self
+            .iter_all()
+            .zip(history.iter())
+            .all(|(known, incoming)| serialize(known) == serialize(incoming))
+        {
+            return None;
+        }
+        let n_missing = history.len() - n_known;
+        self.extend(history.into_iter().skip(n_known));
+        Some(n_missing)
+    }
+
+    /// Returns true if the stream starts with the events of `initial` (eg. the events of
+    /// a `New` entity whose create was already applied). Events are compared by their
+    /// serialized form.
+    pub fn begins_with(&self, initial: &EntityEvents<T>) -> bool {
+        let n_initial = initial.persisted_events.len() + initial.new_events.len();
+        if self.persisted_events.len() + self.new_events.len() < n_initial {
+            return false;
+        }
+        let serialize = |event: &T| serde_json::to_value(event).expect("Failed to serialize event");
+        self.iter_all()
+            .zip(initial.iter_all())
+            .all(|(known, incoming)| serialize(known) == serialize(incoming))
+    }
+
     /// Returns true if there are any unpersisted events waiting to be saved
     pub fn any_new(&self) -> bool {
         !self.new_events.is_empty()
@@ -164,6 +257,10 @@ where
     }
 
     /// Returns an iterator over all persisted events
+    ///
+    /// Each item carries the persistence metadata (sequence, `recorded_at`, context) next to the
+    /// domain event - use `.map(|e| &e.event)` to fold over the events alone.
+    /// Events appended in memory but not yet saved are not included (see [`iter_all`](Self::iter_all)).
     pub fn iter_persisted(&self) -> impl DoubleEndedIterator<Item = &PersistedEvent<T>> + Clone {
         self.persisted_events.iter()
     }
@@ -175,6 +272,10 @@ where
     }
 
     /// Returns an iterator over all events (both persisted and new) in chronological order
+    ///
+    /// This includes the new events that have been appended in memory via [`push`](Self::push) or
+    /// [`extend`](Self::extend) but not persisted yet - so it reflects the current in-memory state
+    /// of the entity rather than what is stored in the database.
     pub fn iter_all(&self) -> impl DoubleEndedIterator<Item = &T> + Clone {
         self.persisted_events
             .iter()
@@ -182,6 +283,18 @@ where
             .chain(self.new_events.iter().map(|e| &e.event))
     }
 
+    /// Folds all events (both persisted and new) into a state starting from `S::default()`
+    ///
+    /// A building block for derived values (eg. a running balance) that don't need the
+    /// full entity hydrated via [`TryFromEvents`].
+    pub fn replay_into<S: Default>(&self, mut f: impl FnMut(&mut S, &T)) -> S {
+        let mut state = S::default();
+        for event in self.iter_all() {
+            f(&mut state, event);
+        }
+        state
+    }
+
     /// Loads and reconstructs the first entity from a stream of GenericEvents, marking events as `persisted`.
     ///
     /// Returns `Ok(None)` if no events are present, `Ok(Some(entity))` on success.
@@ -204,15 +317,18 @@ where
             }
             let cur = current.as_mut().expect("Could not get current");
             let mut event_json = e.event;
+            T::strip_entity_id(&mut event_json);
             if let Some(payload) = e.forgettable_payload {
                 crate::forgettable::inject_forgettable_payload(&mut event_json, payload);
             }
+            let (event, schema_version) = deserialize_versioned(event_json)?;
             cur.persisted_events.push(PersistedEvent {
                 entity_id: e.entity_id,
                 recorded_at: e.recorded_at,
                 sequence: e.sequence as usize,
-                event: serde_json::from_value(event_json)?,
-                context: e.context,
+                event,
+                context: loaded_context::<T>(e.context),
+                schema_version,
             });
         }
         if let Some(current) = current {
@@ -251,15 +367,18 @@ where
             }
             let cur = current.as_mut().expect("Could not get current");
             let mut event_json = e.event;
+            T::strip_entity_id(&mut event_json);
             if let Some(payload) = e.forgettable_payload {
                 crate::forgettable::inject_forgettable_payload(&mut event_json, payload);
             }
+            let (event, schema_version) = deserialize_versioned(event_json)?;
             cur.persisted_events.push(PersistedEvent {
                 entity_id: e.entity_id,
                 recorded_at: e.recorded_at,
                 sequence: e.sequence as usize,
-                event: serde_json::from_value(event_json)?,
-                context: e.context,
+                event,
+                context: loaded_context::<T>(e.context),
+                schema_version,
             });
         }
         if let Some(current) = current.take() {
@@ -289,6 +408,7 @@ where
                         entity_id: self.entity_id.clone(),
                         recorded_at,
                         sequence: i + offset,
+                        schema_version: event.event.schema_version().unwrap_or(1),
                         event: event.event,
                         context: event.context,
                     }),
@@ -308,7 +428,17 @@ where
     pub fn serialize_new_events(&self) -> Vec<serde_json::Value> {
         self.new_events
             .iter()
-            .map(|event| serde_json::to_value(&event.event).expect("Failed to serialize event"))
+            .map(|event| {
+                let mut payload =
+                    serde_json::to_value(&event.event).expect("Failed to serialize event");
+                T::inject_entity_id(&self.entity_id, &mut payload);
+                if let (Some(version), Some(payload)) =
+                    (event.event.schema_version(), payload.as_object_mut())
+                {
+                    payload.insert(SCHEMA_VERSION_KEY.to_string(), version.into());
+                }
+                payload
+            })
             .collect()
     }
 
@@ -322,6 +452,12 @@ where
         for persisted in &mut self.persisted_events {
             forget_fn(&mut persisted.event);
         }
+        self.take()
+    }
+
+    /// Takes ownership of the event stream, leaving `self` as an empty shell.
+    #[doc(hidden)]
+    pub fn take(&mut self) -> Self {
         let entity_id = self.entity_id.clone();
         std::mem::replace(
             self,
@@ -334,12 +470,19 @@ where
     }
 
     #[doc(hidden)]
-    pub fn serialize_new_event_contexts(&self) -> Option<Vec<crate::ContextData>> {
+    pub fn serialize_new_event_contexts(
+        &self,
+        pinned: Option<&crate::ContextData>,
+    ) -> Option<Vec<Option<crate::ContextData>>> {
         if <T as EsEvent>::event_context() {
             let contexts = self
                 .new_events
                 .iter()
-                .map(|event| event.context.clone().expect("Missing context"))
+                .map(|event| match pinned {
+                    Some(pinned) => pinned.clone(),
+                    None => event.context.clone().expect("Missing context"),
+                })
+                .map(|context| (!context.is_empty()).then_some(context))
                 .collect();
 
             Some(contexts)
@@ -415,6 +558,173 @@ mod tests {
         }
     }
 
+    #[test]
+    fn iter_all_includes_new_events() {
+        let mut events = EntityEvents::init(
+            Uuid::parse_str("00000000-0000-0000-0000-000000000003").unwrap(),
+            vec![DummyEntityEvent::Created("first".to_owned())],
+        );
+        events.mark_new_events_persisted_at(chrono::Utc::now());
+        events.push(DummyEntityEvent::Created("second".to_owned()));
+
+        let persisted: Vec<_> = events.iter_persisted().map(|e| &e.event).collect();
+        assert_eq!(persisted.len(), 1);
+        let all: Vec<_> = events
+            .iter_all()
+            .map(|e| match e {
+                DummyEntityEvent::Created(name) => name.as_str(),
+            })
+            .collect();
+        assert_eq!(all, ["first", "second"]);
+    }
+
+    #[test]
+    fn replay_into() {
+        #[derive(Debug, serde::Serialize, serde::Deserialize)]
+        enum AmountEvent {
+            Deposited(i64),
+            Withdrawn(i64),
+        }
+
+        impl EsEvent for AmountEvent {
+            type EntityId = Uuid;
+            fn event_context() -> bool {
+                false
+            }
+            fn event_type(&self) -> &'static str {
+                match self {
+                    Self::Deposited(_) => "deposited",
+                    Self::Withdrawn(_) => "withdrawn",
+                }
+            }
+        }
+
+        #[derive(Default)]
+        struct Balance {
+            total: i64,
+            n_withdrawals: usize,
+        }
+
+        let mut events = EntityEvents::init(
+            Uuid::parse_str("00000000-0000-0000-0000-000000000006").unwrap(),
+            [AmountEvent::Deposited(100), AmountEvent::Withdrawn(30)],
+        );
+        events.mark_new_events_persisted_at(chrono::Utc::now());
+        events.push(AmountEvent::Deposited(5));
+
+        let balance = events.replay_into(|balance: &mut Balance, event| match event {
+            AmountEvent::Deposited(amount) => balance.total += amount,
+            AmountEvent::Withdrawn(amount) => {
+                balance.total -= amount;
+                balance.n_withdrawals += 1;
+            }
+        });
+        assert_eq!(balance.total, 75);
+        assert_eq!(balance.n_withdrawals, 1);
+    }
+
+    #[test]
+    fn append_missing() {
+        let id = Uuid::parse_str("00000000-0000-0000-0000-000000000005").unwrap();
+        let history = |names: &[&str]| {
+            EntityEvents::init(
+                id,
+                names
+                    .iter()
+                    .map(|name| DummyEntityEvent::Created((*name).to_owned())),
+            )
+        };
+        let mut events = history(&["first"]);
+        events.mark_new_events_persisted_at(chrono::Utc::now());
+
+        assert_eq!(events.append_missing(history(&["first"])), Some(0));
+        assert!(!events.any_new());
+
+        assert_eq!(events.append_missing(history(&["other", "second"])), None);
+        assert_eq!(events.append_missing(history(&[])), None);
+        assert!(!events.any_new());
+
+        assert_eq!(
+            events.append_missing(history(&["first", "second"])),
+            Some(1)
+        );
+        let all: Vec<_> = events
+            .iter_all()
+            .map(|e| match e {
+                DummyEntityEvent::Created(name) => name.as_str(),
+            })
+            .collect();
+        assert_eq!(all, ["first", "second"]);
+        assert_eq!(events.len_persisted(), 1);
+    }
+
+    #[test]
+    fn begins_with() {
+        let id = Uuid::parse_str("00000000-0000-0000-0000-000000000006").unwrap();
+        let history = |names: &[&str]| {
+            EntityEvents::init(
+                id,
+                names
+                    .iter()
+                    .map(|name| DummyEntityEvent::Created((*name).to_owned())),
+            )
+        };
+        let mut events = history(&["first", "second"]);
+        events.mark_new_events_persisted_at(chrono::Utc::now());
+
+        assert!(events.begins_with(&history(&["first"])));
+        assert!(events.begins_with(&history(&["first", "second"])));
+        assert!(!events.begins_with(&history(&["other"])));
+        assert!(!events.begins_with(&history(&["first", "second", "third"])));
+    }
+
+    #[derive(Debug, serde::Serialize, serde::Deserialize)]
+    #[serde(tag = "type", rename_all = "snake_case")]
+    enum ImportEvent {
+        Imported { schema_version: u32 },
+        Migrated,
+    }
+
+    impl EsEvent for ImportEvent {
+        type EntityId = Uuid;
+        fn event_context() -> bool {
+            false
+        }
+        fn event_type(&self) -> &'static str {
+            match self {
+                Self::Imported { .. } => "imported",
+                Self::Migrated => "migrated",
+            }
+        }
+        fn schema_version(&self) -> Option<u32> {
+            match self {
+                Self::Imported { .. } => None,
+                Self::Migrated => Some(2),
+            }
+        }
+    }
+
+    #[test]
+    fn schema_version_is_only_read_for_versioned_events() {
+        let (event, version) = deserialize_versioned::<ImportEvent>(serde_json::json!({
+            "type": "imported", "schema_version": 7
+        }))
+        .unwrap();
+        assert!(matches!(event, ImportEvent::Imported { schema_version: 7 }));
+        assert_eq!(version, 1);
+
+        let (_, version) = deserialize_versioned::<ImportEvent>(serde_json::json!({
+            "type": "migrated", "schema_version": 3
+        }))
+        .unwrap();
+        assert_eq!(version, 3);
+
+        let (_, version) =
+            deserialize_versioned::<ImportEvent>(serde_json::json!({ "type": "migrated" }))
+                .unwrap();
+        assert_eq!(version, 1);
+    }
+
     #[test]
     fn load_zero_events() {
         let generic_events = vec![];
@@ -466,4 +776,30 @@ mod tests {
         assert!(!more);
         assert_eq!(entity.len(), 2);
     }
+
+    #[test]
+    fn first_persisted_and_last_modified_at() {
+        let entity_id = Uuid::parse_str("00000000-0000-0000-0000-000000000004").unwrap();
+        let created_at = chrono::Utc::now() - chrono::Duration::minutes(5);
+        let updated_at = chrono::Utc::now();
+        let generic_events = [(1, created_at, "first"), (2, updated_at, "second")].map(
+            |(sequence, recorded_at, name)| GenericEvent {
+                entity_id,
+                sequence,
+                event: serde_json::to_value(DummyEntityEvent::Created(name.to_owned()))
+                    .expect("Could not serialize"),
+                context: None,
+                recorded_at,
+                forgettable_payload: None,
+            },
+        );
+        let mut entity: DummyEntity = EntityEvents::load_first(generic_events)
+            .expect("Could not load")
+            .expect("No entity found");
+        entity
+            .events
+            .push(DummyEntityEvent::Created("new".to_owned()));
+        assert_eq!(entity.events.entity_first_persisted_at(), Some(created_at));
+        assert_eq!(entity.events.entity_last_modified_at(), Some(updated_at));
+    }
 }
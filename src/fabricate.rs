@@ -0,0 +1,51 @@
+//! Construct entities in memory for tests (requires the `testing` feature).
+
+use chrono::{DateTime, Utc};
+
+use crate::{clock::Clock, error::EntityHydrationError, events::EntityEvents, traits::*};
+
+/// Assembles an entity at a target state by applying a list of events - without a database.
+///
+/// The events are marked as persisted (as if the entity had been loaded via a repo) and
+/// the entity is hydrated via its [`TryFromEvents`] implementation.
+/// Usually obtained via the `__fabricator` fn generated on an `EsRepo`.
+pub struct EntityFabricator<E: EsEntity> {
+    id: <<E as EsEntity>::Event as EsEvent>::EntityId,
+    events: Vec<<E as EsEntity>::Event>,
+    recorded_at: Option<DateTime<Utc>>,
+}
+
+impl<E: EsEntity> EntityFabricator<E> {
+    pub fn new(id: <<E as EsEntity>::Event as EsEvent>::EntityId) -> Self {
+        Self {
+            id,
+            events: Vec::new(),
+            recorded_at: None,
+        }
+    }
+
+    /// Appends an event to the stream.
+    pub fn event(mut self, event: <E as EsEntity>::Event) -> Self {
+        self.events.push(event);
+        self
+    }
+
+    /// Appends multiple events to the stream.
+    pub fn events(mut self, events: impl IntoIterator<Item = <E as EsEntity>::Event>) -> Self {
+        self.events.extend(events);
+        self
+    }
+
+    /// Overrides the `recorded_at` of all events. Defaults to [`Clock::now`].
+    pub fn recorded_at(mut self, recorded_at: DateTime<Utc>) -> Self {
+        self.recorded_at = Some(recorded_at);
+        self
+    }
+
+    /// Hydrates the entity from the collected events.
+    pub fn build(self) -> Result<E, EntityHydrationError> {
+        let mut events = EntityEvents::init(self.id, self.events);
+        events.mark_new_events_persisted_at(self.recorded_at.unwrap_or_else(Clock::now));
+        E::try_from_events(events)
+    }
+}
@@ -52,6 +52,16 @@ pub enum Idempotent<T> {
 }
 
 impl<T> Idempotent<T> {
+    /// Creates an [`Executed`][Idempotent::Executed] result carrying `value`.
+    pub fn executed(value: T) -> Self {
+        Idempotent::Executed(value)
+    }
+
+    /// Creates an [`AlreadyApplied`][Idempotent::AlreadyApplied] result.
+    pub fn ignored() -> Self {
+        Idempotent::AlreadyApplied
+    }
+
     /// Returns true if the operation was ignored due to idempotency checks.
     pub fn was_already_applied(&self) -> bool {
         matches!(self, Idempotent::AlreadyApplied)
@@ -79,6 +89,71 @@ impl<T> Idempotent<T> {
     }
 }
 
+/// Conversions of idempotency checks that run *before* the mutation.
+///
+/// At that point the mutation has not produced a value yet, so these only exist for
+/// `Idempotent<()>`. Mutations returning a value should check first and then construct
+/// [`Executed`][Idempotent::Executed] (or use [`executed`][Idempotent::executed]) with it.
+impl Idempotent<()> {
+    /// Converts the result of a boolean "was this already applied" check.
+    ///
+    /// Returns `AlreadyApplied` when `already_applied` is true and `Executed(())` otherwise.
+    pub fn from_was_already_applied(already_applied: bool) -> Self {
+        if already_applied {
+            Idempotent::AlreadyApplied
+        } else {
+            Idempotent::Executed(())
+        }
+    }
+
+    /// Converts a lookup of a previous result.
+    ///
+    /// Returns `AlreadyApplied` when `existing` is `Some` and `Executed(())` otherwise.
+    pub fn from_previous<T>(existing: Option<T>) -> Self {
+        Self::from_was_already_applied(existing.is_some())
+    }
+}
+
+/// Result of a `create` on a repository configured with `create(on_conflict = "ignore")`.
+///
+/// Unlike [`Idempotent`] both variants carry an entity: the ignored variant holds the
+/// one that was already stored under the id, so no further lookup is needed.
+#[must_use]
+pub enum CreateOutcome<T> {
+    /// The entity was inserted.
+    Created(T),
+    /// The id already existed with the same events, carries the stored entity.
+    Ignored(T),
+}
+
+impl<T> CreateOutcome<T> {
+    /// Returns true if the entity was inserted.
+    pub fn did_create(&self) -> bool {
+        matches!(self, CreateOutcome::Created(_))
+    }
+
+    /// Returns true if the create was ignored because the entity already existed.
+    pub fn was_ignored(&self) -> bool {
+        matches!(self, CreateOutcome::Ignored(_))
+    }
+
+    /// Returns the created or already existing entity.
+    pub fn into_inner(self) -> T {
+        match self {
+            CreateOutcome::Created(t) | CreateOutcome::Ignored(t) => t,
+        }
+    }
+}
+
+impl<T> From<CreateOutcome<T>> for Idempotent<T> {
+    fn from(outcome: CreateOutcome<T>) -> Self {
+        match outcome {
+            CreateOutcome::Created(t) => Idempotent::Executed(t),
+            CreateOutcome::Ignored(_) => Idempotent::AlreadyApplied,
+        }
+    }
+}
+
 /// Internal trait used by the [`idempotency_guard`][crate::idempotency_guard] macro.
 ///
 /// This internal-only trait is implemented on [`idempotency_guard`][crate::idempotency_guard] for it to create
@@ -101,3 +176,49 @@ impl<T, E> FromAlreadyApplied for Result<Idempotent<T>, E> {
         Ok(Idempotent::AlreadyApplied)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn executed_carries_value() {
+        let res = Idempotent::executed(42);
+        assert!(res.did_execute());
+        assert_eq!(res.unwrap(), 42);
+    }
+
+    #[test]
+    fn ignored_is_already_applied() {
+        let res = Idempotent::<i32>::ignored();
+        assert!(res.was_already_applied());
+        assert!(!res.did_execute());
+    }
+
+    #[test]
+    fn from_was_already_applied() {
+        assert!(Idempotent::from_was_already_applied(true).was_already_applied());
+        assert!(Idempotent::from_was_already_applied(false).did_execute());
+    }
+
+    #[test]
+    fn from_previous() {
+        assert!(Idempotent::from_previous(Some("existing")).was_already_applied());
+        assert!(Idempotent::from_previous(None::<&str>).did_execute());
+        assert!(Idempotent::from_previous(Some(())).was_already_applied());
+    }
+
+    #[test]
+    fn create_outcome_carries_entity() {
+        let created = CreateOutcome::Created(1);
+        assert!(created.did_create());
+        assert_eq!(created.into_inner(), 1);
+
+        let ignored = CreateOutcome::Ignored(2);
+        assert!(ignored.was_ignored());
+        assert_eq!(ignored.into_inner(), 2);
+
+        assert!(Idempotent::from(CreateOutcome::Ignored(3)).was_already_applied());
+        assert!(Idempotent::from(CreateOutcome::Created(4)).did_execute());
+    }
+}
@@ -28,9 +28,13 @@ pub mod context;
 pub mod db;
 pub mod error;
 pub mod events;
+#[cfg(feature = "testing")]
+pub mod fabricate;
 pub mod forgettable;
 pub mod idempotent;
 mod macros;
+#[cfg(feature = "otel-metrics")]
+pub mod metrics;
 pub mod nested;
 pub mod one_time_executor;
 pub mod operation;
@@ -42,7 +46,10 @@ pub mod traits;
 pub mod prelude {
     //! Convenience re-export of crates that the derive macros reference in generated code.
 
+    pub use async_stream;
     pub use chrono;
+    pub use futures_core;
+    pub use futures_util;
     pub use serde;
     pub use serde_json;
     pub use sqlx;
@@ -58,12 +65,16 @@ pub use context::*;
 pub use error::*;
 pub use es_entity_macros::EsEntity;
 pub use es_entity_macros::EsEvent;
+pub use es_entity_macros::EsEventHeader;
 pub use es_entity_macros::EsRepo;
 pub use es_entity_macros::es_event_context;
 pub use es_entity_macros::expand_es_query;
 pub use es_entity_macros::retry_on_concurrent_modification;
 #[doc(inline)]
 pub use events::*;
+#[cfg(feature = "testing")]
+#[doc(inline)]
+pub use fabricate::*;
 #[doc(inline)]
 pub use forgettable::{Forgettable, ForgettableRef};
 #[doc(inline)]
@@ -101,4 +112,59 @@ pub mod graphql {
             id.0
         }
     }
+
+    /// Encodes a pagination cursor as URL-safe base64 (without padding) of its JSON.
+    pub fn encode_cursor<T: serde::Serialize>(cursor: &T) -> String {
+        use base64::{Engine as _, engine::general_purpose};
+        let json = serde_json::to_string(cursor).expect("could not serialize token");
+        general_purpose::URL_SAFE_NO_PAD.encode(json.as_bytes())
+    }
+
+    /// Decodes a cursor produced by [`encode_cursor`].
+    ///
+    /// Cursors encoded with the standard base64 alphabet (as issued by earlier
+    /// versions) are still accepted.
+    pub fn decode_cursor<T: serde::de::DeserializeOwned>(s: &str) -> Result<T, String> {
+        use base64::{Engine as _, engine::general_purpose};
+        let bytes = general_purpose::URL_SAFE_NO_PAD
+            .decode(s.as_bytes())
+            .or_else(|_| general_purpose::STANDARD_NO_PAD.decode(s.as_bytes()))
+            .map_err(|e| e.to_string())?;
+        let json = String::from_utf8(bytes).map_err(|e| e.to_string())?;
+        serde_json::from_str(&json).map_err(|e| e.to_string())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use base64::{Engine as _, engine::general_purpose};
+
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Cursor {
+            name: String,
+        }
+
+        // '>' / '?' serialize to bytes that map to '+' / '/' in the standard alphabet
+        fn cursor() -> Cursor {
+            Cursor {
+                name: ">>>???".to_string(),
+            }
+        }
+
+        #[test]
+        fn cursor_is_url_safe() {
+            let encoded = super::encode_cursor(&cursor());
+            let standard = general_purpose::STANDARD_NO_PAD
+                .encode(serde_json::to_string(&cursor()).unwrap().as_bytes());
+            assert!(standard.contains('+') && standard.contains('/'));
+            assert!(!encoded.contains(['+', '/', '=']));
+            assert_eq!(super::decode_cursor::<Cursor>(&encoded).unwrap(), cursor());
+        }
+
+        #[test]
+        fn standard_alphabet_cursor_still_decodes() {
+            let standard = general_purpose::STANDARD_NO_PAD
+                .encode(serde_json::to_string(&cursor()).unwrap().as_bytes());
+            assert_eq!(super::decode_cursor::<Cursor>(&standard).unwrap(), cursor());
+        }
+    }
 }
@@ -149,6 +149,12 @@ macro_rules! idempotency_guard {
 ///
 /// - `tbl_prefix`: Table prefix to ignore when deriving entity names from table names (optional)
 /// - `entity`: Override the entity type (optional, useful when table name doesn't match entity name)
+/// - `event_context_column`: Name of the events table column holding the event context
+///   (optional, must match the repo's `event_context_column` when that is configured)
+/// - `probe_limit`: Placeholder bound to `first + 1` as the query's `LIMIT` (optional). The row
+///   beyond `first` is then only loaded with its first event as it merely signals
+///   `has_next_page` to [`fetch_n()`](crate::query::EsQuery::fetch_n). Must be the first key
+///   unless `events_tbl` is.
 /// - SQL query string
 /// - Additional arguments for the SQL query (optional)
 ///
@@ -169,9 +175,123 @@ macro_rules! idempotency_guard {
 ///     "SELECT id FROM custom_users_table WHERE id = $1",
 ///     id as UserId
 /// )
+///
+/// // With a custom event context column
+/// es_query!(
+///     entity = User,
+///     event_context_column = "metadata",
+///     "SELECT id FROM users WHERE id = $1",
+///     id as UserId
+/// )
+///
+/// // Paginated - the `first + 1`th row only probes for a next page
+/// es_query!(
+///     probe_limit = "$1",
+///     entity = User,
+///     "SELECT name, id FROM users ORDER BY name, id LIMIT $1",
+///     (first + 1) as i64
+/// ).fetch_n(&pool, first)
 /// ```
 #[macro_export]
 macro_rules! es_query {
+    // Repos sharing an events table (`events_discriminator`) forward everything as is
+    (
+        events_tbl = $events_tbl:literal,
+        $($rest:tt)*
+    ) => ({
+        $crate::expand_es_query!(
+            events_tbl = $events_tbl,
+            $($rest)*
+        )
+    });
+
+    // Paginated queries probing for a next page forward everything as is
+    (
+        probe_limit = $probe_limit:literal,
+        $($rest:tt)*
+    ) => ({
+        $crate::expand_es_query!(
+            probe_limit = $probe_limit,
+            $($rest)*
+        )
+    });
+
+    // The `event_context_column` arms must come before the ones without it
+    // as the `$query:expr` fragment would otherwise swallow the key.
+
+    // With entity override + forgettable + event context column
+    (
+        entity = $entity:ident,
+        forgettable_tbl = $forgettable_tbl:literal,
+        event_context_column = $event_context_column:literal,
+        $query:expr
+        $(, $($args:tt)*)?
+    ) => ({
+        $crate::expand_es_query!(
+            entity = $entity,
+            forgettable_tbl = $forgettable_tbl,
+            event_context_column = $event_context_column,
+            sql = $query
+            $(, args = [$($args)*])?
+        )
+    });
+    // With entity override + event context column
+    (
+        entity = $entity:ident,
+        event_context_column = $event_context_column:literal,
+        $query:expr
+        $(, $($args:tt)*)?
+    ) => ({
+        $crate::expand_es_query!(
+            entity = $entity,
+            event_context_column = $event_context_column,
+            sql = $query
+            $(, args = [$($args)*])?
+        )
+    });
+    // With tbl_prefix + forgettable + event context column
+    (
+        tbl_prefix = $tbl_prefix:literal,
+        forgettable_tbl = $forgettable_tbl:literal,
+        event_context_column = $event_context_column:literal,
+        $query:expr
+        $(, $($args:tt)*)?
+    ) => ({
+        $crate::expand_es_query!(
+            tbl_prefix = $tbl_prefix,
+            forgettable_tbl = $forgettable_tbl,
+            event_context_column = $event_context_column,
+            sql = $query
+            $(, args = [$($args)*])?
+        )
+    });
+    // With tbl_prefix + event context column
+    (
+        tbl_prefix = $tbl_prefix:literal,
+        event_context_column = $event_context_column:literal,
+        $query:expr
+        $(, $($args:tt)*)?
+    ) => ({
+        $crate::expand_es_query!(
+            tbl_prefix = $tbl_prefix,
+            event_context_column = $event_context_column,
+            sql = $query
+            $(, args = [$($args)*])?
+        )
+    });
+    // Basic form + event context column
+    (
+        event_context_column = $event_context_column:literal,
+        $query:expr
+        $(, $($args:tt)*)?
+    ) => ({
+        $crate::expand_es_query!(
+            event_context_column = $event_context_column,
+            sql = $query
+            $(, args = [$($args)*])?
+        )
+    });
+
     // With entity override + forgettable
     (
         entity = $entity:ident,
@@ -292,6 +412,35 @@ macro_rules! es_query {
     });
 }
 
+/// Query the index table for a few columns without hydrating entities.
+///
+/// Expands to [`sqlx::query_as!`] (so the query is checked at compile time) wrapped in an
+/// [`EsProjectionQuery`](crate::query::EsProjectionQuery) whose `fetch_*` fns accept anything
+/// implementing [`IntoOneTimeExecutor`](crate::IntoOneTimeExecutor) - just like `es_query!`.
+/// No events are loaded which makes it a cheap option for read-model-only queries.
+///
+/// # Example
+///
+/// ```ignore
+/// struct UserName {
+///     id: UserId,
+///     name: String,
+/// }
+///
+/// let names = es_query_project!(
+///     UserName,
+///     r#"SELECT id as "id: UserId", name FROM users ORDER BY name"#
+/// )
+/// .fetch_all(&pool)
+/// .await?;
+/// ```
+#[macro_export]
+macro_rules! es_query_project {
+    ($row:path, $query:expr $(, $args:expr)* $(,)?) => {
+        $crate::query::EsProjectionQuery::new(sqlx::query_as!($row, $query $(, $args)*))
+    };
+}
+
 // Helper macro for common entity_id implementations (internal use only)
 #[doc(hidden)]
 #[macro_export]
@@ -0,0 +1,92 @@
+//! OpenTelemetry metrics for repository operations.
+//!
+//! With the `otel-metrics` feature enabled the code generated by `EsRepo` and
+//! `retry_on_concurrent_modification` records the following instruments on the
+//! `es-entity` meter:
+//!
+//! | Instrument | Kind | Attributes |
+//! |------------|------|------------|
+//! | `es_entity.create.count` | counter | `entity` |
+//! | `es_entity.update.count` | counter | `entity` |
+//! | `es_entity.query.duration` | histogram (seconds) | `entity`, `operation` |
+//! | `es_entity.concurrent_modification.retries` | counter | `function` |
+//!
+//! Counts are recorded when the operation succeeds within its `AtomicOperation` -
+//! a later rollback of that operation is not accounted for.
+//!
+//! The instruments are created on first use from [`opentelemetry::global::meter`],
+//! so the global `MeterProvider` must be installed before any repository is used.
+//! Without the feature none of this code is generated.
+
+use opentelemetry::{
+    KeyValue, global,
+    metrics::{Counter, Histogram},
+};
+
+use std::{sync::LazyLock, time::Instant};
+
+struct Instruments {
+    created: Counter<u64>,
+    updated: Counter<u64>,
+    query_duration: Histogram<f64>,
+    retries: Counter<u64>,
+}
+
+static INSTRUMENTS: LazyLock<Instruments> = LazyLock::new(|| {
+    let meter = global::meter("es-entity");
+    Instruments {
+        created: meter
+            .u64_counter("es_entity.create.count")
+            .with_description("Number of entities created")
+            .build(),
+        updated: meter
+            .u64_counter("es_entity.update.count")
+            .with_description("Number of entities updated with new events")
+            .build(),
+        query_duration: meter
+            .f64_histogram("es_entity.query.duration")
+            .with_description("Duration of repository queries")
+            .with_unit("s")
+            .build(),
+        retries: meter
+            .u64_counter("es_entity.concurrent_modification.retries")
+            .with_description("Number of retries after a concurrent modification")
+            .build(),
+    }
+});
+
+#[doc(hidden)]
+pub fn record_created(entity: &'static str, n: usize) {
+    if n > 0 {
+        INSTRUMENTS
+            .created
+            .add(n as u64, &[KeyValue::new("entity", entity)]);
+    }
+}
+
+#[doc(hidden)]
+pub fn record_updated(entity: &'static str, n: usize) {
+    if n > 0 {
+        INSTRUMENTS
+            .updated
+            .add(n as u64, &[KeyValue::new("entity", entity)]);
+    }
+}
+
+#[doc(hidden)]
+pub fn record_query_duration(entity: &'static str, operation: &'static str, started_at: Instant) {
+    INSTRUMENTS.query_duration.record(
+        started_at.elapsed().as_secs_f64(),
+        &[
+            KeyValue::new("entity", entity),
+            KeyValue::new("operation", operation),
+        ],
+    );
+}
+
+#[doc(hidden)]
+pub fn record_retry(function: &'static str) {
+    INSTRUMENTS
+        .retries
+        .add(1, &[KeyValue::new("function", function)]);
+}
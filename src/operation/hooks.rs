@@ -261,6 +261,43 @@ impl<'c, H> PreCommitRet<'c, H> {
     }
 }
 
+/// A [`CommitHook`] that spawns futures onto the tokio runtime once the transaction committed.
+///
+/// Used by `EsRepo`s configured with `post_persist_async_spawn` to run the post-persist hook
+/// off the transaction. Delivery is at-most-once: nothing is spawned when the transaction rolls
+/// back and nothing is retried if the spawned task fails or the process exits before it ran.
+pub struct SpawnAfterCommit {
+    futures: Vec<BoxFuture<'static, ()>>,
+}
+
+impl SpawnAfterCommit {
+    pub fn new(future: impl Future<Output = ()> + Send + 'static) -> Self {
+        Self {
+            futures: vec![Box::pin(future)],
+        }
+    }
+
+    /// Spawns the collected futures immediately.
+    ///
+    /// Useful when [`AtomicOperation::add_commit_hook()`] returns `Err(hook)`.
+    pub fn spawn(self) {
+        for future in self.futures {
+            tokio::spawn(future);
+        }
+    }
+}
+
+impl CommitHook for SpawnAfterCommit {
+    fn post_commit(self) {
+        self.spawn();
+    }
+
+    fn merge(&mut self, other: &mut Self) -> bool {
+        self.futures.append(&mut other.futures);
+        true
+    }
+}
+
 // --- Object-safe internal trait ---
 trait DynHook: Send {
     #[allow(clippy::type_complexity)]
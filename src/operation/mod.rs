@@ -1,12 +1,14 @@
 //! Handle execution of database operations and transactions.
 
 pub mod hooks;
+mod savepoint;
 mod with_time;
 
 use sqlx::{Acquire, Transaction};
 
-use crate::{clock::ClockHandle, db, one_time_executor::OneTimeExecutor};
+use crate::{ContextData, clock::ClockHandle, db, one_time_executor::OneTimeExecutor};
 
+pub use savepoint::*;
 pub use with_time::*;
 
 /// Default return type of the derived EsRepo::begin_op().
@@ -21,6 +23,8 @@ pub struct DbOp<'c> {
     tx: Transaction<'c, db::Db>,
     clock: ClockHandle,
     now: Option<chrono::DateTime<chrono::Utc>>,
+    context: Option<ContextData>,
+    read_only: bool,
     commit_hooks: Option<hooks::CommitHooks>,
 }
 
@@ -34,6 +38,8 @@ impl<'c> DbOp<'c> {
             tx,
             clock,
             now: time,
+            context: None,
+            read_only: false,
             commit_hooks: Some(hooks::CommitHooks::new()),
         }
     }
@@ -61,6 +67,44 @@ impl<'c> DbOp<'c> {
         Ok(DbOp::new(tx, clock.clone(), time))
     }
 
+    /// Initializes a read-only transaction using the global clock.
+    ///
+    /// Delegates to [`init_read_only_with_clock`](Self::init_read_only_with_clock) using the global clock handle.
+    pub async fn init_read_only(pool: &db::Pool) -> Result<DbOp<'static>, sqlx::Error> {
+        Self::init_read_only_with_clock(pool, crate::clock::Clock::handle()).await
+    }
+
+    /// Initializes a transaction with the specified clock and issues `SET TRANSACTION READ ONLY`.
+    ///
+    /// Any write attempted through the operation (or its nested transactions) is rejected
+    /// by the database, so accidental writes on a read path fail fast.
+    pub async fn init_read_only_with_clock(
+        pool: &db::Pool,
+        clock: &ClockHandle,
+    ) -> Result<DbOp<'static>, sqlx::Error> {
+        let mut op = Self::init_with_clock(pool, clock).await?;
+        sqlx::query("SET TRANSACTION READ ONLY")
+            .execute(&mut *op.tx)
+            .await?;
+        op.read_only = true;
+        Ok(op)
+    }
+
+    /// Whether the transaction was started read-only.
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// Pins `data` as the context of every event persisted through this operation.
+    ///
+    /// Takes precedence over the thread-local [`EventContext`](crate::EventContext)
+    /// captured when the events were pushed, so the context can't get lost when
+    /// work hops threads across `.await` points.
+    pub fn with_context(mut self, data: ContextData) -> Self {
+        self.context = Some(data);
+        self
+    }
+
     /// Transitions to a [`DbOpWithTime`] with the given time cached.
     pub fn with_time(self, time: chrono::DateTime<chrono::Utc>) -> DbOpWithTime<'c> {
         DbOpWithTime::new(self, time)
@@ -99,11 +143,10 @@ impl<'c> DbOp<'c> {
 
     /// Begins a nested transaction.
     pub async fn begin(&mut self) -> Result<DbOp<'_>, sqlx::Error> {
-        Ok(DbOp::new(
-            self.tx.begin().await?,
-            self.clock.clone(),
-            self.now,
-        ))
+        let mut op = DbOp::new(self.tx.begin().await?, self.clock.clone(), self.now);
+        op.context = self.context.clone();
+        op.read_only = self.read_only;
+        Ok(op)
     }
 
     /// Commits the inner transaction.
@@ -121,6 +164,17 @@ impl<'c> DbOp<'c> {
     }
 }
 
+/// Wraps a transaction that was started elsewhere (using the global clock).
+///
+/// Lets several repos enlist in the same transaction while keeping
+/// commit hook support and time caching.
+impl<'c> From<Transaction<'c, db::Db>> for DbOp<'c> {
+    fn from(tx: Transaction<'c, db::Db>) -> Self {
+        let clock = crate::clock::Clock::handle();
+        DbOp::new(tx, clock.clone(), clock.manual_now())
+    }
+}
+
 impl<'o> AtomicOperation for DbOp<'o> {
     fn maybe_now(&self) -> Option<chrono::DateTime<chrono::Utc>> {
         self.maybe_now()
@@ -130,6 +184,10 @@ impl<'o> AtomicOperation for DbOp<'o> {
         &self.clock
     }
 
+    fn pinned_context(&self) -> Option<&ContextData> {
+        self.context.as_ref()
+    }
+
     fn connection(&mut self) -> &mut db::Connection {
         self.tx.connection()
     }
@@ -192,6 +250,10 @@ impl<'o> AtomicOperation for DbOpWithTime<'o> {
         self.inner.clock()
     }
 
+    fn pinned_context(&self) -> Option<&ContextData> {
+        self.inner.pinned_context()
+    }
+
     fn connection(&mut self) -> &mut db::Connection {
         self.inner.connection()
     }
@@ -235,6 +297,14 @@ pub trait AtomicOperation: Send {
         crate::clock::Clock::handle()
     }
 
+    /// The context pinned via [`DbOp::with_context`], if any.
+    ///
+    /// When set it is stored with every event persisted through this operation
+    /// instead of the context captured when the event was pushed.
+    fn pinned_context(&self) -> Option<&ContextData> {
+        None
+    }
+
     /// Returns the raw underlying connection.
     /// The desired way to represent this would actually be as a GAT:
     /// ```rust
@@ -298,6 +368,19 @@ pub trait AtomicOperation: Send {
     fn supports_hooks(&self) -> bool {
         false
     }
+
+    /// Creates a [`Savepoint`] for a sub-operation that can be rolled back
+    /// independently of the surrounding transaction.
+    ///
+    /// Every savepoint gets a unique name so savepoints can be nested.
+    fn savepoint(
+        &mut self,
+    ) -> impl std::future::Future<Output = Result<Savepoint<'_, Self>, sqlx::Error>> + Send
+    where
+        Self: Sized,
+    {
+        Savepoint::new(self)
+    }
 }
 
 impl<'c> AtomicOperation for sqlx::Transaction<'c, db::Db> {
@@ -0,0 +1,80 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use super::AtomicOperation;
+use crate::{clock::ClockHandle, db};
+
+static SAVEPOINT_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// A Postgres `SAVEPOINT` within an [`AtomicOperation`].
+///
+/// Created via [`AtomicOperation::savepoint`]. Work executed against the savepoint
+/// (it implements [`AtomicOperation`] itself so repo `_in_op` fns can be used) can be
+/// undone via [`rollback_to`](Self::rollback_to) without aborting the surrounding
+/// transaction, or kept via [`release`](Self::release).
+///
+/// Dropping a `Savepoint` without calling either leaves it open - its work is then
+/// committed or rolled back together with the surrounding transaction.
+///
+/// Commit hooks are not supported within a savepoint as they could not be discarded
+/// on [`rollback_to`](Self::rollback_to). Register them on the surrounding operation instead.
+pub struct Savepoint<'a, Op: AtomicOperation + ?Sized> {
+    inner: &'a mut Op,
+    name: String,
+}
+
+impl<'a, Op: AtomicOperation + ?Sized> Savepoint<'a, Op> {
+    pub(super) async fn new(op: &'a mut Op) -> Result<Self, sqlx::Error> {
+        let name = format!(
+            "es_entity_savepoint_{}",
+            SAVEPOINT_COUNTER.fetch_add(1, Ordering::Relaxed)
+        );
+        sqlx::query(&format!("SAVEPOINT {name}"))
+            .execute(op.connection())
+            .await?;
+        Ok(Self { inner: op, name })
+    }
+
+    /// The generated name of the savepoint.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Keeps the work done since the savepoint was created (`RELEASE SAVEPOINT`).
+    pub async fn release(self) -> Result<(), sqlx::Error> {
+        sqlx::query(&format!("RELEASE SAVEPOINT {}", self.name))
+            .execute(self.inner.connection())
+            .await?;
+        Ok(())
+    }
+
+    /// Discards the work done since the savepoint was created (`ROLLBACK TO SAVEPOINT`).
+    ///
+    /// The surrounding operation remains usable afterwards.
+    pub async fn rollback_to(self) -> Result<(), sqlx::Error> {
+        sqlx::query(&format!("ROLLBACK TO SAVEPOINT {}", self.name))
+            .execute(&mut *self.inner.connection())
+            .await?;
+        sqlx::query(&format!("RELEASE SAVEPOINT {}", self.name))
+            .execute(self.inner.connection())
+            .await?;
+        Ok(())
+    }
+}
+
+impl<'a, Op: AtomicOperation + ?Sized> AtomicOperation for Savepoint<'a, Op> {
+    fn maybe_now(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.inner.maybe_now()
+    }
+
+    fn clock(&self) -> &ClockHandle {
+        self.inner.clock()
+    }
+
+    fn pinned_context(&self) -> Option<&crate::ContextData> {
+        self.inner.pinned_context()
+    }
+
+    fn connection(&mut self) -> &mut db::Connection {
+        self.inner.connection()
+    }
+}
@@ -64,6 +64,10 @@ impl<'a, Op: AtomicOperation + ?Sized> AtomicOperation for OpWithTime<'a, Op> {
         self.inner.clock()
     }
 
+    fn pinned_context(&self) -> Option<&crate::ContextData> {
+        self.inner.pinned_context()
+    }
+
     fn connection(&mut self) -> &mut db::Connection {
         self.inner.connection()
     }
@@ -1,5 +1,9 @@
 //! Control and customize the query execution and its response.
 
+/// Number of entities fetched per page when `first` is not specified
+/// (see [`PaginatedQueryArgs::default_first`]).
+pub const DEFAULT_PAGE_SIZE: usize = 100;
+
 /// Controls the sorting order when listing the entities from the database
 ///
 /// `ListDirection` enum is used to specify order when listing entities from the database using [`EsRepo`][crate::EsRepo]
@@ -20,7 +24,7 @@
 ///     ListDirection::Descending,
 /// ).await?
 /// ```
-#[derive(Default, std::fmt::Debug, Clone, Copy)]
+#[derive(Default, std::fmt::Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ListDirection {
     /// Sets the default direction variant to `Ascending`
     #[default]
@@ -28,6 +32,35 @@ pub enum ListDirection {
     Descending,
 }
 
+impl ListDirection {
+    /// Returns the opposite direction (eg. for navigating to the previous page).
+    pub fn reverse(self) -> Self {
+        match self {
+            Self::Ascending => Self::Descending,
+            Self::Descending => Self::Ascending,
+        }
+    }
+
+    pub fn is_ascending(&self) -> bool {
+        matches!(self, Self::Ascending)
+    }
+
+    pub fn is_descending(&self) -> bool {
+        matches!(self, Self::Descending)
+    }
+}
+
+/// `true` maps to `Ascending`, `false` to `Descending`.
+impl From<bool> for ListDirection {
+    fn from(ascending: bool) -> Self {
+        if ascending {
+            Self::Ascending
+        } else {
+            Self::Descending
+        }
+    }
+}
+
 /// Structure to sort entities on a specific field when listing from database
 ///
 /// Sort enum is used to specify the sorting order and the field to sort the entities by when listing them using `list_for_filters`
@@ -54,7 +87,10 @@ pub enum ListDirection {
 /// )
 /// .await?;
 /// ```
-#[derive(std::fmt::Debug, Clone, Copy)]
+///
+/// The generated `SortBy` enums default to the `Id` variant so `Sort::default()`
+/// sorts ascending by `id`.
+#[derive(Default, std::fmt::Debug, Clone, Copy)]
 pub struct Sort<T> {
     // T parameter represents the field
     pub by: T,
@@ -110,14 +146,73 @@ where
     }
 }
 
-impl<T: std::fmt::Debug> Default for PaginatedQueryArgs<T> {
-    /// Default value fetches first 100 entities
-    fn default() -> Self {
+impl<T: std::fmt::Debug> PaginatedQueryArgs<T> {
+    /// Fetch the first `n` entities (from the start of the list).
+    ///
+    /// ```
+    /// # use es_entity::PaginatedQueryArgs;
+    /// let args = PaginatedQueryArgs::<String>::first(10).after("cursor".to_string());
+    /// assert_eq!(args.first, 10);
+    /// assert_eq!(args.after.as_deref(), Some("cursor"));
+    /// ```
+    pub fn first(n: usize) -> Self {
         Self {
-            first: 100,
+            first: n,
             after: None,
         }
     }
+
+    /// Fetch the first [`DEFAULT_PAGE_SIZE`] entities (from the start of the list).
+    ///
+    /// ```
+    /// # use es_entity::{DEFAULT_PAGE_SIZE, PaginatedQueryArgs};
+    /// let args = PaginatedQueryArgs::<String>::default_first();
+    /// assert_eq!(args.first, DEFAULT_PAGE_SIZE);
+    /// ```
+    pub fn default_first() -> Self {
+        Self::first(DEFAULT_PAGE_SIZE)
+    }
+
+    /// Continue after the given cursor.
+    pub fn after(mut self, cursor: T) -> Self {
+        self.after = Some(cursor);
+        self
+    }
+
+    /// Caps `first` at `max` to protect the database from oversized requests.
+    ///
+    /// ```
+    /// # use es_entity::PaginatedQueryArgs;
+    /// let args = PaginatedQueryArgs::<String>::first(1_000_000).max_first(100);
+    /// assert_eq!(args.first, 100);
+    /// ```
+    pub fn max_first(mut self, max: usize) -> Self {
+        self.first = self.first.min(max);
+        self
+    }
+}
+
+/// Converts a user supplied (eg. GraphQL) `first` argument.
+///
+/// `None` falls back to [`DEFAULT_PAGE_SIZE`], negative values are rejected.
+impl<T: std::fmt::Debug> TryFrom<Option<i32>> for PaginatedQueryArgs<T> {
+    type Error = crate::error::PaginatedQueryArgsError;
+
+    fn try_from(first: Option<i32>) -> Result<Self, Self::Error> {
+        match first {
+            None => Ok(Self::default()),
+            Some(n) => usize::try_from(n)
+                .map(Self::first)
+                .map_err(|_| crate::error::PaginatedQueryArgsError::NegativeFirst(n)),
+        }
+    }
+}
+
+impl<T: std::fmt::Debug> Default for PaginatedQueryArgs<T> {
+    /// Fetches the first [`DEFAULT_PAGE_SIZE`] entities
+    fn default() -> Self {
+        Self::default_first()
+    }
 }
 
 /// Return type for paginated queries containing entities and pagination metadata
@@ -152,6 +247,7 @@ impl<T: std::fmt::Debug> Default for PaginatedQueryArgs<T> {
 ///     let next_result = users.list_by_id(next_query_args, ListDirection::Ascending).await?;
 /// }
 /// ```
+#[must_use = "dropping the result loses `has_next_page` and `end_cursor` needed to fetch the next page"]
 pub struct PaginatedQueryRet<T, C> {
     /// [Vec] for the fetched `entities` by the paginated query
     pub entities: Vec<T>,
@@ -163,6 +259,7 @@ pub struct PaginatedQueryRet<T, C> {
 
 impl<T, C> PaginatedQueryRet<T, C> {
     /// Convenience method to create next query args if more pages are available
+    #[must_use]
     pub fn into_next_query(self) -> Option<PaginatedQueryArgs<C>>
     where
         C: std::fmt::Debug,
@@ -176,4 +273,95 @@ impl<T, C> PaginatedQueryRet<T, C> {
             None
         }
     }
+
+    /// Transforms the entities (e.g. into DTOs) while keeping the pagination metadata.
+    ///
+    /// The cursor is unchanged as it was derived from the original entities.
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> PaginatedQueryRet<U, C> {
+        PaginatedQueryRet {
+            entities: self.entities.into_iter().map(f).collect(),
+            has_next_page: self.has_next_page,
+            end_cursor: self.end_cursor,
+        }
+    }
+
+    /// Fallible version of [`map`](Self::map) returning the first error encountered.
+    pub fn try_map<U, E>(
+        self,
+        f: impl FnMut(T) -> Result<U, E>,
+    ) -> Result<PaginatedQueryRet<U, C>, E> {
+        Ok(PaginatedQueryRet {
+            entities: self.entities.into_iter().map(f).collect::<Result<_, _>>()?,
+            has_next_page: self.has_next_page,
+            end_cursor: self.end_cursor,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::PaginatedQueryArgsError;
+
+    #[test]
+    fn try_from_optional_first() {
+        let args = PaginatedQueryArgs::<String>::try_from(Some(5)).unwrap();
+        assert_eq!(args.first, 5);
+        assert!(args.after.is_none());
+
+        let args = PaginatedQueryArgs::<String>::try_from(None).unwrap();
+        assert_eq!(args.first, DEFAULT_PAGE_SIZE);
+        assert!(args.after.is_none());
+
+        let err = PaginatedQueryArgs::<String>::try_from(Some(-1)).unwrap_err();
+        assert_eq!(err, PaginatedQueryArgsError::NegativeFirst(-1));
+    }
+
+    #[test]
+    fn list_direction_helpers() {
+        assert_eq!(
+            ListDirection::Ascending.reverse(),
+            ListDirection::Descending
+        );
+        assert_eq!(
+            ListDirection::Descending.reverse(),
+            ListDirection::Ascending
+        );
+        assert!(ListDirection::default().is_ascending());
+        assert!(ListDirection::Descending.is_descending());
+        assert_eq!(ListDirection::from(true), ListDirection::Ascending);
+        assert_eq!(ListDirection::from(false), ListDirection::Descending);
+    }
+
+    #[test]
+    fn max_first_only_lowers() {
+        assert_eq!(
+            PaginatedQueryArgs::<String>::first(10).max_first(100).first,
+            10
+        );
+        assert_eq!(
+            PaginatedQueryArgs::<String>::first(500)
+                .max_first(100)
+                .first,
+            100
+        );
+    }
+
+    #[test]
+    fn map_keeps_pagination_metadata() {
+        let ret = PaginatedQueryRet {
+            entities: vec![1, 2, 3],
+            has_next_page: true,
+            end_cursor: Some("c".to_string()),
+        };
+        let mapped = ret.map(|n| n.to_string());
+        assert_eq!(mapped.entities, vec!["1", "2", "3"]);
+        assert!(mapped.has_next_page);
+        assert_eq!(mapped.end_cursor.as_deref(), Some("c"));
+
+        let err = mapped
+            .try_map(|s| if s == "2" { Err(s) } else { Ok(s) })
+            .err();
+        assert_eq!(err.as_deref(), Some("2"));
+    }
 }
@@ -73,7 +73,7 @@ where
             return Ok(None);
         }
 
-        Ok(EntityEvents::load_first(rows.into_iter())?)
+        Ok(EntityEvents::load_first(rows)?)
     }
 
     async fn fetch_n_inner<E: From<sqlx::Error> + From<EntityHydrationError>>(
@@ -83,7 +83,15 @@ where
     ) -> Result<(Vec<<Repo as EsRepo>::Entity>, bool), E> {
         let executor = op.into_executor();
         let rows = executor.fetch_all(self.inner).await?;
-        Ok(EntityEvents::load_n(rows.into_iter(), first)?)
+        Ok(EntityEvents::load_n(rows, first)?)
+    }
+
+    async fn fetch_all_inner<E: From<sqlx::Error> + From<EntityHydrationError>>(
+        self,
+        op: impl IntoOneTimeExecutor<'_>,
+    ) -> Result<Vec<<Repo as EsRepo>::Entity>, E> {
+        let (entities, _) = self.fetch_n_inner::<E>(op, usize::MAX).await?;
+        Ok(entities)
     }
 }
 
@@ -109,6 +117,24 @@ where
         self.fetch_optional_inner(op).await
     }
 
+    /// Fetches one entity or, if the query matches none, produces it via `fallback`.
+    ///
+    /// The fallback receives the same operation (eg. to create the entity on read).
+    pub async fn fetch_one_or<OP, E>(
+        self,
+        op: &mut OP,
+        fallback: impl AsyncFnOnce(&mut OP) -> Result<<Repo as EsRepo>::Entity, E>,
+    ) -> Result<<Repo as EsRepo>::Entity, E>
+    where
+        OP: AtomicOperation,
+        E: From<<Repo as EsRepo>::QueryError>,
+    {
+        match self.fetch_optional(&mut *op).await? {
+            Some(entity) => Ok(entity),
+            None => fallback(op).await,
+        }
+    }
+
     /// Fetches up to `first` entities from the query results.
     ///
     /// Returns a tuple of (entities, has_more) where `has_more` indicates if there
@@ -120,6 +146,14 @@ where
     ) -> Result<(Vec<<Repo as EsRepo>::Entity>, bool), <Repo as EsRepo>::QueryError> {
         self.fetch_n_inner(op, first).await
     }
+
+    /// Fetches every entity returned by the query.
+    pub async fn fetch_all(
+        self,
+        op: impl IntoOneTimeExecutor<'_>,
+    ) -> Result<Vec<<Repo as EsRepo>::Entity>, <Repo as EsRepo>::QueryError> {
+        self.fetch_all_inner(op).await
+    }
 }
 
 impl<'q, Repo, F, A> EsQuery<'q, Repo, EsQueryFlavorNested, F, A>
@@ -161,6 +195,25 @@ where
         Ok(Some(entity))
     }
 
+    /// Fetches one entity with its nested relationships or, if the query matches none,
+    /// produces it via `fallback`.
+    ///
+    /// The fallback receives the same operation (eg. to create the entity on read).
+    pub async fn fetch_one_or<OP, E>(
+        self,
+        op: &mut OP,
+        fallback: impl AsyncFnOnce(&mut OP) -> Result<<Repo as EsRepo>::Entity, E>,
+    ) -> Result<<Repo as EsRepo>::Entity, E>
+    where
+        OP: AtomicOperation,
+        E: From<<Repo as EsRepo>::QueryError>,
+    {
+        match self.fetch_optional(op).await? {
+            Some(entity) => Ok(entity),
+            None => fallback(op).await,
+        }
+    }
+
     /// Fetches up to `first` entities and loads all nested relationships.
     ///
     /// Returns a tuple of (entities, has_more) where all entities have their nested
@@ -184,6 +237,25 @@ where
         Ok((entities, more))
     }
 
+    /// Fetches every entity returned by the query and loads all nested relationships.
+    pub async fn fetch_all<OP>(
+        self,
+        op: &mut OP,
+    ) -> Result<Vec<<Repo as EsRepo>::Entity>, <Repo as EsRepo>::QueryError>
+    where
+        OP: AtomicOperation,
+    {
+        let mut entities = self
+            .fetch_all_inner::<<Repo as EsRepo>::QueryError>(&mut *op)
+            .await?;
+        <Repo as EsRepo>::load_all_nested_in_op::<_, <Repo as EsRepo>::QueryError>(
+            op,
+            &mut entities,
+        )
+        .await?;
+        Ok(entities)
+    }
+
     /// Like [`fetch_optional`](EsQuery::fetch_optional) but transitively includes
     /// soft-deleted nested entities.
     pub async fn fetch_optional_include_deleted<OP>(
@@ -229,4 +301,65 @@ where
         .await?;
         Ok((entities, more))
     }
+
+    /// Like [`fetch_all`](EsQuery::fetch_all) but transitively includes soft-deleted
+    /// nested entities.
+    pub async fn fetch_all_include_deleted<OP>(
+        self,
+        op: &mut OP,
+    ) -> Result<Vec<<Repo as EsRepo>::Entity>, <Repo as EsRepo>::QueryError>
+    where
+        OP: AtomicOperation,
+    {
+        let mut entities = self
+            .fetch_all_inner::<<Repo as EsRepo>::QueryError>(&mut *op)
+            .await?;
+        <Repo as EsRepo>::load_all_nested_in_op_include_deleted::<_, <Repo as EsRepo>::QueryError>(
+            op,
+            &mut entities,
+        )
+        .await?;
+        Ok(entities)
+    }
+}
+
+/// Query builder for projection-only reads.
+///
+/// Generated by the `es_query_project!` macro. Runs the query against the index
+/// table only (no events join) and maps every row into `R` - no entity is hydrated.
+pub struct EsProjectionQuery<'q, R, F, A> {
+    inner: sqlx::query::Map<'q, db::Db, F, A>,
+    _row: std::marker::PhantomData<R>,
+}
+
+impl<'q, R, F, A> EsProjectionQuery<'q, R, F, A>
+where
+    R: Send + Unpin,
+    F: FnMut(db::Row) -> Result<R, sqlx::Error> + Send,
+    A: 'q + Send + sqlx::IntoArguments<'q, db::Db>,
+{
+    pub fn new(query: sqlx::query::Map<'q, db::Db, F, A>) -> Self {
+        Self {
+            inner: query,
+            _row: std::marker::PhantomData,
+        }
+    }
+
+    /// Fetches exactly one row - errors with `RowNotFound` if there is none.
+    pub async fn fetch_one(self, op: impl IntoOneTimeExecutor<'_>) -> Result<R, sqlx::Error> {
+        op.into_executor().fetch_one(self.inner).await
+    }
+
+    /// Fetches at most one row.
+    pub async fn fetch_optional(
+        self,
+        op: impl IntoOneTimeExecutor<'_>,
+    ) -> Result<Option<R>, sqlx::Error> {
+        op.into_executor().fetch_optional(self.inner).await
+    }
+
+    /// Fetches every row returned by the query.
+    pub async fn fetch_all(self, op: impl IntoOneTimeExecutor<'_>) -> Result<Vec<R>, sqlx::Error> {
+        op.into_executor().fetch_all(self.inner).await
+    }
 }
@@ -69,6 +69,55 @@ pub trait EsEvent: DeserializeOwned + Serialize + Send + Sync {
     /// that shadows this default. Manual implementors can override it if needed.
     #[doc(hidden)]
     const HAS_FORGETTABLE_FIELDS: bool = false;
+
+    /// Adds the entity id to the serialized `payload` before it is persisted.
+    ///
+    /// A no-op unless the event opts in via `#[es_event(implicit_id)]`.
+    #[doc(hidden)]
+    fn inject_entity_id(_entity_id: &Self::EntityId, _payload: &mut serde_json::Value) {}
+
+    /// Removes the entity id added by [`inject_entity_id`](Self::inject_entity_id)
+    /// from a loaded `payload` before it is deserialized.
+    #[doc(hidden)]
+    fn strip_entity_id(_payload: &mut serde_json::Value) {}
+
+    /// The `schema_version` stored next to `type` in the serialized event.
+    ///
+    /// Set via `#[es_event(version = N)]` on the enum or on individual variants.
+    /// `None` means no version is written (and the event reads back as version 1).
+    fn schema_version(&self) -> Option<u32> {
+        None
+    }
+}
+
+/// Fields shared by every variant of an event enum with `#[es_event(header = "..")]`.
+///
+/// Derived via `#[derive(EsEventHeader)]`. Each variant embeds the header with
+/// `#[serde(flatten)]` so its fields are stored next to `type` in the persisted JSON.
+pub trait EsEventHeader {
+    /// The serialized names of the header fields.
+    const FIELDS: &'static [&'static str];
+}
+
+/// Used by `#[derive(EsEvent)]` to reject variant fields that collide with the header.
+#[doc(hidden)]
+pub const fn header_has_field(fields: &[&str], name: &str) -> bool {
+    let name = name.as_bytes();
+    let mut i = 0;
+    while i < fields.len() {
+        let field = fields[i].as_bytes();
+        if field.len() == name.len() {
+            let mut j = 0;
+            while j < name.len() && field[j] == name[j] {
+                j += 1;
+            }
+            if j == name.len() {
+                return true;
+            }
+        }
+        i += 1;
+    }
+    false
 }
 
 /// Required trait for converting new entities into their initial events before persistence.
@@ -293,6 +342,11 @@ pub trait EsRepo: Send {
     type QueryError: From<sqlx::Error> + From<EntityHydrationError> + Send;
     type EsQueryFlavor;
 
+    /// Name of the index table (including any `tbl_prefix`).
+    const TABLE_NAME: &'static str;
+    /// Name of the events table (including any `tbl_prefix`).
+    const EVENTS_TABLE_NAME: &'static str;
+
     /// Loads all nested entities for a given set of parent entities within an atomic operation.
     fn load_all_nested_in_op<OP, E>(
         op: &mut OP,
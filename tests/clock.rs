@@ -1,6 +1,8 @@
 use chrono::{TimeZone, Utc};
-use es_entity::clock::{Clock, ClockHandle};
+use es_entity::clock::{Clock, ClockHandle, TimestampPrecision};
 
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Duration;
@@ -38,6 +40,23 @@ async fn test_manual_at_starts_at_specified_time() {
     assert_eq!(clock.now(), start + chrono::Duration::hours(1));
 }
 
+#[tokio::test]
+async fn test_manual_at_stays_frozen_until_advanced() {
+    let start =
+        Utc.with_ymd_and_hms(2020, 1, 1, 12, 30, 0).unwrap() + chrono::Duration::milliseconds(123);
+    let (clock, ctrl) = ClockHandle::manual_at(start);
+
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    assert_eq!(clock.now(), start);
+
+    let later = start + chrono::Duration::days(1);
+    ctrl.set(later).await;
+    assert_eq!(clock.now(), later);
+
+    ctrl.advance(Duration::from_secs(1)).await;
+    assert_eq!(clock.now(), later + chrono::Duration::seconds(1));
+}
+
 #[tokio::test]
 async fn test_manual_time_stands_still() {
     let (clock, _ctrl) = ClockHandle::manual();
@@ -121,6 +140,10 @@ async fn test_multiple_sleeps_wake_in_order() {
     // Let tasks register
     tokio::task::yield_now().await;
     assert_eq!(ctrl.pending_wake_count(), 3);
+    assert_eq!(
+        ctrl.pending_wakes(),
+        [10, 20, 30].map(|secs| t0 + chrono::Duration::seconds(secs))
+    );
 
     // Advance 1 minute - all should wake in order
     ctrl.advance(Duration::from_secs(60)).await;
@@ -141,6 +164,53 @@ async fn test_multiple_sleeps_wake_in_order() {
     assert_eq!(order[2].1, t0 + chrono::Duration::seconds(30));
 }
 
+#[tokio::test]
+async fn test_many_concurrent_sleepers_wake_in_order() {
+    const SLEEPERS: u64 = 500;
+
+    let (clock, ctrl) = ClockHandle::manual();
+    let t0 = clock.now();
+
+    let wake_order = Arc::new(parking_lot::Mutex::new(Vec::new()));
+
+    // Distinct durations (1..=SLEEPERS seconds) spawned in a scrambled order
+    let mut handles = Vec::new();
+    for i in 0..SLEEPERS {
+        let secs = (i * 7919) % SLEEPERS + 1;
+        let wo = wake_order.clone();
+        let c = clock.clone();
+        handles.push(tokio::spawn(async move {
+            c.sleep(Duration::from_secs(secs)).await;
+            wo.lock()
+                .push((t0 + chrono::Duration::seconds(secs as i64), c.now()));
+        }));
+    }
+
+    // Let all tasks register (the scheduler only polls a batch of them per yield)
+    while ctrl.pending_wake_count() < SLEEPERS as usize {
+        tokio::task::yield_now().await;
+    }
+
+    // Advance past all of them in one call
+    let woken = ctrl.advance(Duration::from_secs(SLEEPERS * 2)).await;
+    assert_eq!(woken, SLEEPERS as usize);
+
+    for handle in handles {
+        handle.await.unwrap();
+    }
+
+    let order = wake_order.lock();
+    assert_eq!(order.len(), SLEEPERS as usize);
+    for (scheduled, observed) in order.iter() {
+        assert_eq!(observed, scheduled);
+    }
+    assert!(order.windows(2).all(|w| w[0].0 < w[1].0));
+    assert_eq!(
+        clock.now(),
+        t0 + chrono::Duration::seconds(SLEEPERS as i64 * 2)
+    );
+}
+
 #[tokio::test]
 async fn test_advance_to_next_wake() {
     let (clock, ctrl) = ClockHandle::manual();
@@ -202,6 +272,36 @@ async fn test_timeout_elapsed() {
     assert!(result.is_err());
 }
 
+#[tokio::test]
+async fn test_timeout_with_remaining() {
+    let (clock, ctrl) = ClockHandle::manual();
+
+    let c = clock.clone();
+    let result = tokio::spawn(async move {
+        let sleeper = c.clone();
+        c.timeout_with_remaining(Duration::from_secs(10), async move {
+            sleeper.sleep(Duration::from_secs(3)).await;
+            42
+        })
+        .await
+    });
+
+    tokio::task::yield_now().await;
+    ctrl.advance(Duration::from_secs(5)).await;
+
+    let value = result.await.unwrap();
+    assert_eq!(value, Ok((42, Duration::from_secs(7))));
+
+    let c = clock.clone();
+    let result = tokio::spawn(async move {
+        c.timeout_with_remaining(Duration::from_secs(1), std::future::pending::<()>())
+            .await
+    });
+    tokio::task::yield_now().await;
+    ctrl.advance(Duration::from_secs(2)).await;
+    assert!(result.await.unwrap().is_err());
+}
+
 #[tokio::test]
 async fn test_cloned_handles_share_time() {
     let (clock1, ctrl) = ClockHandle::manual();
@@ -520,3 +620,220 @@ async fn test_cancelled_coalesce_sleep_cleanup() {
     tokio::task::yield_now().await;
     assert_eq!(ctrl.pending_wake_count(), 0);
 }
+
+#[tokio::test]
+async fn test_is_due_under_manual_clock() {
+    let (clock, ctrl) = ClockHandle::manual();
+    let deadline = clock.now() + chrono::Duration::seconds(60);
+
+    assert!(!clock.is_due(deadline));
+
+    ctrl.advance(Duration::from_secs(59)).await;
+    assert!(!clock.is_due(deadline));
+
+    ctrl.advance(Duration::from_secs(1)).await;
+    assert!(clock.is_due(deadline));
+}
+
+#[tokio::test]
+async fn test_elapsed_since() {
+    let (clock, ctrl) = ClockHandle::manual();
+    let start = clock.now();
+
+    assert_eq!(clock.elapsed_since(start), Duration::ZERO);
+
+    ctrl.advance(Duration::from_secs(90)).await;
+    assert_eq!(clock.elapsed_since(start), Duration::from_secs(90));
+
+    // An instant in the future saturates to zero
+    let future = clock.now() + chrono::Duration::seconds(10);
+    assert_eq!(clock.elapsed_since(future), Duration::ZERO);
+}
+
+#[tokio::test]
+async fn test_due_timer_wheel() {
+    let (clock, ctrl) = ClockHandle::manual();
+    let t0 = clock.now();
+
+    let mut wheel = BinaryHeap::new();
+    wheel.push(Reverse((t0 + chrono::Duration::seconds(30), "c")));
+    wheel.push(Reverse((t0 + chrono::Duration::seconds(10), "a")));
+    wheel.push(Reverse((t0 + chrono::Duration::seconds(20), "b")));
+    wheel.push(Reverse((t0 + chrono::Duration::seconds(10), "a2")));
+
+    // Nothing is due until time is advanced
+    assert!(clock.due(&mut wheel).is_empty());
+    assert_eq!(wheel.len(), 4);
+
+    ctrl.advance(Duration::from_secs(10)).await;
+    assert_eq!(clock.due(&mut wheel), vec!["a", "a2"]);
+
+    ctrl.advance(Duration::from_secs(5)).await;
+    assert!(clock.due(&mut wheel).is_empty());
+
+    // A large advance releases everything that became due, in deadline order
+    wheel.push(Reverse((t0 + chrono::Duration::seconds(25), "b2")));
+    ctrl.advance(Duration::from_secs(60)).await;
+    assert_eq!(clock.due(&mut wheel), vec!["b", "b2", "c"]);
+    assert!(wheel.is_empty());
+}
+
+#[tokio::test]
+async fn test_advance_many_fires_wakes_per_step() {
+    let (clock, ctrl) = ClockHandle::manual();
+    let t0 = clock.now();
+
+    let woken_at = Arc::new(parking_lot::Mutex::new(Vec::new()));
+    let c = clock.clone();
+    let w = woken_at.clone();
+    tokio::spawn(async move {
+        for _ in 0..2 {
+            c.sleep(Duration::from_secs(10)).await;
+            w.lock().push(c.now());
+        }
+    });
+    tokio::task::yield_now().await;
+
+    let woken = ctrl
+        .advance_many(&[
+            Duration::from_secs(15),
+            Duration::from_secs(15),
+            Duration::from_secs(15),
+        ])
+        .await;
+
+    // The second sleep is registered during the first step and fires in the second
+    assert_eq!(woken, 2);
+    assert_eq!(
+        *woken_at.lock(),
+        vec![
+            t0 + chrono::Duration::seconds(10),
+            t0 + chrono::Duration::seconds(20)
+        ]
+    );
+    assert_eq!(clock.now(), t0 + chrono::Duration::seconds(45));
+}
+
+#[tokio::test]
+async fn test_set_fires_wakes_in_order() {
+    let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let (clock, ctrl) = ClockHandle::manual_at(start);
+
+    let woken_at = Arc::new(parking_lot::Mutex::new(Vec::new()));
+    for secs in [120, 60] {
+        let c = clock.clone();
+        let w = woken_at.clone();
+        tokio::spawn(async move {
+            c.sleep(Duration::from_secs(secs)).await;
+            w.lock().push(c.now());
+        });
+    }
+    tokio::task::yield_now().await;
+
+    let target = start + chrono::Duration::hours(1);
+    assert_eq!(ctrl.set(target).await, 2);
+    assert_eq!(clock.now(), target);
+    assert_eq!(
+        *woken_at.lock(),
+        vec![
+            start + chrono::Duration::seconds(60),
+            start + chrono::Duration::seconds(120)
+        ]
+    );
+
+    // Time never goes backwards
+    assert_eq!(ctrl.set(start).await, 0);
+    assert_eq!(clock.now(), target);
+}
+
+#[tokio::test]
+async fn test_with_precision_truncates_now() {
+    let clock = ClockHandle::realtime();
+
+    let millis = clock
+        .clone()
+        .with_precision(TimestampPrecision::Milliseconds);
+    assert_eq!(millis.now().timestamp_subsec_nanos() % 1_000_000, 0);
+
+    let micros = clock
+        .clone()
+        .with_precision(TimestampPrecision::Microseconds);
+    assert_eq!(micros.now().timestamp_subsec_nanos() % 1_000, 0);
+}
+
+#[tokio::test]
+async fn test_stable_now_within_poll() {
+    let clock = ClockHandle::realtime();
+    let c = clock.clone();
+    clock
+        .stable_now(async move {
+            let start = c.now();
+            std::thread::sleep(Duration::from_millis(2));
+            assert_eq!(c.now(), start);
+        })
+        .await;
+
+    let (clock, ctrl) = ClockHandle::manual();
+    let c = clock.clone();
+    clock
+        .stable_now(async move {
+            let start = c.now();
+            ctrl.advance(Duration::from_secs(1)).await;
+            tokio::task::yield_now().await;
+            // the cache is invalidated once the task yields
+            assert_eq!(c.now(), start + chrono::Duration::seconds(1));
+            assert_eq!(c.now(), ctrl.now());
+        })
+        .await;
+}
+
+#[tokio::test]
+async fn test_with_fixed_now() {
+    let clock = ClockHandle::realtime();
+    let fixed = Utc.with_ymd_and_hms(2024, 12, 31, 23, 59, 59).unwrap();
+    let other = ClockHandle::realtime();
+
+    let today = clock.with_fixed_now(fixed, || {
+        assert_eq!(clock.clone().now(), fixed);
+        let nested = fixed + chrono::Duration::seconds(1);
+        clock.with_fixed_now(nested, || assert_eq!(clock.now(), nested));
+        // unrelated clocks are not affected
+        assert_ne!(other.now(), fixed);
+        clock.today()
+    });
+    assert_eq!(today, fixed.date_naive());
+    assert_ne!(clock.now(), fixed);
+
+    let c = clock.clone();
+    clock
+        .with_fixed_now_async(fixed, async move {
+            tokio::task::yield_now().await;
+            assert_eq!(c.now(), fixed);
+        })
+        .await;
+    assert_ne!(clock.now(), fixed);
+
+    // The fixed instant wins over a stable scope no matter how they are nested
+    let c = clock.clone();
+    clock
+        .with_fixed_now_async(
+            fixed,
+            clock.stable_now(async move {
+                assert_eq!(c.now(), fixed);
+            }),
+        )
+        .await;
+    let c = clock.clone();
+    clock
+        .stable_now(async move {
+            let start = c.now();
+            c.with_fixed_now(fixed, || assert_eq!(c.now(), fixed));
+            assert_eq!(c.now(), start);
+        })
+        .await;
+
+    // The override does not leak into the time cached by operations
+    let (manual, _ctrl) = ClockHandle::manual();
+    let manual_time = manual.manual_now();
+    manual.with_fixed_now(fixed, || assert_eq!(manual.manual_now(), manual_time));
+}
@@ -0,0 +1,23 @@
+#![cfg(feature = "clock-reset")]
+
+// Resetting the global clock affects every test of a binary, so this lives in its own one.
+
+use chrono::Utc;
+use es_entity::clock::Clock;
+
+#[test]
+fn reset_allows_installing_a_different_clock() {
+    Clock::reset();
+    let _ = Clock::now();
+    assert!(!Clock::is_manual());
+
+    Clock::reset();
+    let start_at = Utc::now() - chrono::Duration::days(1);
+    let ctrl = Clock::install_manual_at(start_at);
+    assert!(Clock::is_manual());
+    assert_eq!(Clock::now(), ctrl.now());
+
+    Clock::reset();
+    assert!(!Clock::is_manual());
+    assert_eq!(Clock::manual_now(), None);
+}
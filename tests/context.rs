@@ -4,12 +4,26 @@ use serde_json::json;
 
 struct TestStruct;
 
+struct Actor {
+    id: u64,
+}
+
+struct TestCommand {
+    tenant_id: String,
+    actor: Actor,
+}
+
 impl TestStruct {
     #[es_event_context(value, count)]
     async fn test_arg_capture(&self, value: &str, count: u32) -> serde_json::Value {
         serde_json::to_value(EventContext::current().data()).unwrap()
     }
 
+    #[es_event_context(cmd.tenant_id, cmd.actor.id)]
+    async fn test_field_capture(&self, cmd: TestCommand) -> serde_json::Value {
+        serde_json::to_value(EventContext::current().data()).unwrap()
+    }
+
     #[es_event_context]
     async fn test_no_args(&self) -> serde_json::Value {
         let mut ctx = EventContext::current();
@@ -79,3 +93,20 @@ async fn es_event_context_macro_integration() {
         json!({ "initial": "data", "method": "no_macro" })
     );
 }
+
+#[tokio::test]
+async fn es_event_context_macro_field_capture() {
+    let test_struct = TestStruct;
+    let result = test_struct
+        .test_field_capture(TestCommand {
+            tenant_id: "tenant".to_string(),
+            actor: Actor { id: 7 },
+        })
+        .await;
+    assert_eq!(result, json!({ "tenant_id": "tenant", "id": 7 }));
+
+    assert_eq!(
+        serde_json::to_value(EventContext::current().data()).unwrap(),
+        json!({})
+    );
+}
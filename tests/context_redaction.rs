@@ -0,0 +1,47 @@
+mod entities;
+mod helpers;
+
+use entities::user::*;
+use es_entity::{context::EventContext, *};
+use sqlx::PgPool;
+
+#[derive(EsRepo, Debug)]
+#[es_repo(
+    entity = "User",
+    persist_event_context = true,
+    columns(name(ty = "String"))
+)]
+pub struct Users {
+    pool: PgPool,
+}
+
+#[tokio::test]
+async fn redacted_keys_never_reach_persisted_context() -> anyhow::Result<()> {
+    context::set_redaction_policy(["password", "*_token"]);
+
+    let mut ctx = EventContext::current();
+    ctx.insert("request_id", &"req-1").unwrap();
+    ctx.insert("password", &"hunter2").unwrap();
+    ctx.insert("session_token", &"abc").unwrap();
+
+    let pool = helpers::init_pool().await?;
+    let users = Users { pool: pool.clone() };
+    let id = UserId::new();
+    let new_user = NewUser::builder().id(id).name("Redacted").build().unwrap();
+    users.create(new_user).await?;
+
+    let row = sqlx::query!(
+        "SELECT context FROM user_events WHERE id = $1",
+        id as UserId
+    )
+    .fetch_one(&pool)
+    .await?;
+    // Only compare the keys under test (`tracing-context` adds a `tracing` key)
+    let context = row.context.expect("context should be persisted");
+    assert_eq!(context["request_id"], "req-1");
+    assert_eq!(context["password"], "[REDACTED]");
+    assert_eq!(context["session_token"], "[REDACTED]");
+
+    context::clear_redaction_policy();
+    Ok(())
+}
@@ -0,0 +1,60 @@
+mod entities;
+mod helpers;
+
+use entities::user::*;
+use es_entity::*;
+use sqlx::PgPool;
+
+#[derive(EsRepo, Debug)]
+#[es_repo(
+    entity = "User",
+    tbl = "defaulted_users",
+    events_tbl = "defaulted_user_events",
+    columns(name(ty = "String", create(default = "String::from(\"pending\")")))
+)]
+pub struct DefaultedUsers {
+    pool: PgPool,
+}
+
+#[tokio::test]
+async fn create_default_is_only_written_to_the_index_table() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let users = DefaultedUsers { pool: pool.clone() };
+    // `find_by_name` expects a single row per value
+    sqlx::query!("TRUNCATE defaulted_user_events, defaulted_users")
+        .execute(&pool)
+        .await?;
+
+    let new_user = NewUser::builder()
+        .id(UserId::new())
+        .name("Alice")
+        .build()
+        .unwrap();
+    let mut user = users.create(new_user).await?;
+    assert_eq!(user.name, "Alice");
+
+    // The index table holds the default...
+    let found = users.find_by_name("pending".to_string()).await?;
+    assert_eq!(found.id, user.id);
+    // ...while the entity is hydrated from its events
+    assert_eq!(found.name, "Alice");
+    assert!(
+        users
+            .maybe_find_by_name("Alice".to_string())
+            .await?
+            .is_none()
+    );
+
+    // The next update persists the value of the entity
+    let _ = user.update_name("Bob");
+    users.update(&mut user).await?;
+    assert_eq!(users.find_by_name("Bob".to_string()).await?.id, user.id);
+    assert!(
+        users
+            .maybe_find_by_name("pending".to_string())
+            .await?
+            .is_none()
+    );
+
+    Ok(())
+}
@@ -7,7 +7,7 @@ use es_entity::*;
 
 es_entity::entity_id! { UserId }
 
-#[derive(EsEvent, Debug, Serialize, Deserialize)]
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
 #[es_event(id = "UserId", event_context)]
 pub enum UserEvent {
@@ -17,6 +17,7 @@ pub enum UserEvent {
 
 #[derive(EsEntity, Builder)]
 #[builder(pattern = "owned", build_fn(error = "EntityHydrationError"))]
+#[es_entity(snapshot)]
 pub struct User {
     pub id: UserId,
     pub name: String,
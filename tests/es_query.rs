@@ -6,6 +6,10 @@ use es_entity::*;
 use helpers::init_pool;
 use sqlx::PgPool;
 
+fn repo_table_names<R: EsRepo>() -> (&'static str, &'static str) {
+    (R::TABLE_NAME, R::EVENTS_TABLE_NAME)
+}
+
 mod tbl_prefix_param {
     use super::*;
 
@@ -36,6 +40,7 @@ mod tbl_prefix_param {
                 entity: "User",
                 column: Some(UserColumn::Id),
                 value: format!("{:?}", id),
+                id: Some(id),
             })
         }
 
@@ -49,6 +54,14 @@ mod tbl_prefix_param {
         }
     }
 
+    #[test]
+    fn table_names() {
+        assert_eq!(
+            repo_table_names::<UsersTblPrefix>(),
+            ("ignore_prefix_users", "ignore_prefix_user_events")
+        );
+    }
+
     #[tokio::test]
     async fn with_args() -> anyhow::Result<()> {
         let pool = init_pool().await?;
@@ -121,6 +134,7 @@ mod entity_param {
                 entity: "User",
                 column: Some(UserColumn::Id),
                 value: format!("{:?}", id),
+                id: Some(id),
             })
         }
 
@@ -132,6 +146,14 @@ mod entity_param {
         }
     }
 
+    #[test]
+    fn table_names() {
+        assert_eq!(
+            repo_table_names::<UsersEntity>(),
+            ("custom_name_for_users", "custom_name_for_user_events")
+        );
+    }
+
     #[tokio::test]
     async fn with_args() -> anyhow::Result<()> {
         let pool = init_pool().await?;
@@ -194,6 +216,7 @@ mod no_params {
                     entity: "User",
                     column: Some(UserColumn::Id),
                     value: format!("{:?}", id),
+                    id: Some(id),
                 })
         }
 
@@ -202,6 +225,31 @@ mod no_params {
                 .fetch_n(self.pool(), 2)
                 .await
         }
+
+        async fn find_all_by_name_prefix(&self, prefix: &str) -> Result<Vec<User>, UserQueryError> {
+            es_query!(
+                "SELECT * FROM users WHERE name LIKE $1 ORDER BY name",
+                format!("{prefix}%")
+            )
+            .fetch_all(self.pool())
+            .await
+        }
+
+        async fn find_or_create_by_name(&self, name: &str) -> anyhow::Result<User> {
+            let mut op = self.begin_op().await?;
+            let user = es_query!("SELECT * FROM users WHERE name = $1", name)
+                .fetch_one_or(&mut op, async |op| {
+                    let new_user = NewUser::builder()
+                        .id(UserId::new())
+                        .name(name)
+                        .build()
+                        .unwrap();
+                    Ok::<_, anyhow::Error>(self.create_in_op(op, new_user).await?)
+                })
+                .await?;
+            op.commit().await?;
+            Ok(user)
+        }
     }
 
     #[tokio::test]
@@ -242,4 +290,159 @@ mod no_params {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn fetch_all_without_limit() -> anyhow::Result<()> {
+        let pool = init_pool().await?;
+        let users = UsersNoParams::new(pool);
+        let prefix = format!("Unbounded {}", UserId::new());
+
+        for i in 0..3 {
+            let new_user = NewUser::builder()
+                .id(UserId::new())
+                .name(format!("{prefix} {i}"))
+                .build()
+                .unwrap();
+            users.create(new_user).await?;
+        }
+
+        let loaded = users.find_all_by_name_prefix(&prefix).await?;
+        let names: Vec<_> = loaded.iter().map(|u| u.name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                format!("{prefix} 0"),
+                format!("{prefix} 1"),
+                format!("{prefix} 2")
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn fetch_one_or() -> anyhow::Result<()> {
+        let pool = init_pool().await?;
+        let users = UsersNoParams::new(pool);
+        let name = format!("Created on read {}", UserId::new());
+
+        let created = users.find_or_create_by_name(&name).await?;
+        let found = users.find_or_create_by_name(&name).await?;
+        assert_eq!(created.id, found.id);
+
+        Ok(())
+    }
+}
+
+mod event_context_column_param {
+    use super::*;
+
+    #[derive(EsRepo, Debug)]
+    #[es_repo(
+        tbl_prefix = "legacy",
+        entity = "User",
+        persist_event_context = true,
+        event_context_column = "metadata",
+        columns(name(ty = "String"))
+    )]
+    struct LegacyUsers {
+        pool: PgPool,
+    }
+
+    impl LegacyUsers {
+        fn new(pool: PgPool) -> Self {
+            Self { pool }
+        }
+
+        async fn query_with_args(&self, id: UserId) -> Result<Option<User>, UserQueryError> {
+            es_query!(
+                tbl_prefix = "legacy",
+                event_context_column = "metadata",
+                "SELECT * FROM legacy_users WHERE id = $1",
+                id as UserId
+            )
+            .fetch_optional(self.pool())
+            .await
+        }
+    }
+
+    #[tokio::test]
+    async fn persists_and_loads_context() -> anyhow::Result<()> {
+        let mut ctx = EventContext::current();
+        ctx.insert("source", &"legacy").unwrap();
+
+        let pool = init_pool().await?;
+        let users = LegacyUsers::new(pool);
+        let id = UserId::new();
+        let new_user = NewUser::builder().id(id).name("Frank").build().unwrap();
+        users.create(new_user).await?;
+
+        let loaded_user = users.find_by_id(id).await?;
+        let context = loaded_user
+            .events()
+            .iter_persisted()
+            .next()
+            .and_then(|e| e.context.clone())
+            .expect("context should be loaded");
+        assert_eq!(serde_json::to_value(&context)?["source"], "legacy");
+
+        let queried_user = users.query_with_args(id).await?.expect("user not found");
+        assert!(
+            queried_user
+                .events()
+                .iter_persisted()
+                .next()
+                .unwrap()
+                .context
+                .is_some()
+        );
+
+        Ok(())
+    }
+}
+
+mod projection {
+    use super::*;
+
+    #[derive(EsRepo, Debug)]
+    #[es_repo(entity = "User", columns(name(ty = "String")))]
+    struct Users {
+        pool: PgPool,
+    }
+
+    struct UserName {
+        id: UserId,
+        name: String,
+    }
+
+    #[tokio::test]
+    async fn projects_rows_without_hydrating() -> anyhow::Result<()> {
+        let pool = init_pool().await?;
+        let users = Users { pool: pool.clone() };
+        let id = UserId::new();
+        let new_user = NewUser::builder().id(id).name("Projected").build().unwrap();
+        users.create(new_user).await?;
+
+        let row = es_query_project!(
+            UserName,
+            r#"SELECT id as "id: UserId", name FROM users WHERE id = $1"#,
+            id as UserId
+        )
+        .fetch_one(&pool)
+        .await?;
+        assert_eq!(row.id, id);
+        assert_eq!(row.name, "Projected");
+
+        let mut op = users.begin_op().await?;
+        let missing = es_query_project!(
+            UserName,
+            r#"SELECT id as "id: UserId", name FROM users WHERE id = $1"#,
+            UserId::new() as UserId
+        )
+        .fetch_optional(&mut op)
+        .await?;
+        assert!(missing.is_none());
+
+        Ok(())
+    }
 }
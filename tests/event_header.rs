@@ -0,0 +1,177 @@
+mod entities;
+mod helpers;
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use entities::user::UserId;
+use es_entity::*;
+use sqlx::PgPool;
+
+#[derive(EsEventHeader, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Audit {
+    actor: String,
+    #[serde(rename = "reason")]
+    why: String,
+}
+
+impl Audit {
+    fn new(actor: &str, why: &str) -> Self {
+        Self {
+            actor: actor.to_string(),
+            why: why.to_string(),
+        }
+    }
+}
+
+/// Same table as `UserEvent` with an `Audit` header stored inline in every payload.
+#[derive(EsEvent, Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "UserId", header = "Audit")]
+pub enum AuditedUserEvent {
+    Initialized {
+        #[serde(flatten)]
+        audit: Audit,
+        id: UserId,
+        name: String,
+    },
+    NameUpdated {
+        #[serde(flatten)]
+        audit: Audit,
+        name: String,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EntityHydrationError"))]
+pub struct AuditedUser {
+    pub id: UserId,
+    pub name: String,
+    events: EntityEvents<AuditedUserEvent>,
+}
+
+impl AuditedUser {
+    fn update_name(&mut self, name: impl Into<String>, audit: Audit) {
+        let name = name.into();
+        self.name = name.clone();
+        self.events
+            .push(AuditedUserEvent::NameUpdated { audit, name });
+    }
+}
+
+impl TryFromEvents<AuditedUserEvent> for AuditedUser {
+    fn try_from_events(
+        events: EntityEvents<AuditedUserEvent>,
+    ) -> Result<Self, EntityHydrationError> {
+        let mut builder = AuditedUserBuilder::default();
+        for event in events.iter_all() {
+            match event {
+                AuditedUserEvent::Initialized { id, name, .. } => {
+                    builder = builder.id(*id).name(name.clone());
+                }
+                AuditedUserEvent::NameUpdated { name, .. } => {
+                    builder = builder.name(name.clone());
+                }
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+pub struct NewAuditedUser {
+    id: UserId,
+    name: String,
+    audit: Audit,
+}
+
+impl IntoEvents<AuditedUserEvent> for NewAuditedUser {
+    fn into_events(self) -> EntityEvents<AuditedUserEvent> {
+        EntityEvents::init(
+            self.id,
+            [AuditedUserEvent::Initialized {
+                audit: self.audit,
+                id: self.id,
+                name: self.name,
+            }],
+        )
+    }
+}
+
+#[derive(EsRepo, Debug)]
+#[es_repo(
+    entity = "AuditedUser",
+    id = "UserId",
+    tbl = "users",
+    events_tbl = "user_events",
+    columns(name(ty = "String"))
+)]
+pub struct AuditedUsers {
+    pool: PgPool,
+}
+
+#[tokio::test]
+async fn header_fields_are_stored_next_to_type() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let users = AuditedUsers { pool: pool.clone() };
+
+    let id = UserId::new();
+    let mut user = users
+        .create(NewAuditedUser {
+            id,
+            name: "Audited".to_string(),
+            audit: Audit::new("admin", "signup"),
+        })
+        .await?;
+    user.update_name("Renamed", Audit::new("support", "typo"));
+    users.update(&mut user).await?;
+
+    let rows = sqlx::query!(
+        "SELECT event FROM user_events WHERE id = $1 ORDER BY sequence",
+        id as UserId
+    )
+    .fetch_all(&pool)
+    .await?;
+    assert_eq!(
+        rows[0].event,
+        serde_json::json!({ "type": "initialized", "actor": "admin", "reason": "signup", "id": id, "name": "Audited" })
+    );
+    assert_eq!(
+        rows[1].event,
+        serde_json::json!({ "type": "name_updated", "actor": "support", "reason": "typo", "name": "Renamed" })
+    );
+
+    let user = users.find_by_id(id).await?;
+    assert_eq!(user.name, "Renamed");
+    let headers: Vec<_> = user
+        .events()
+        .iter_all()
+        .map(|e| e.header().clone())
+        .collect();
+    assert_eq!(
+        headers,
+        vec![Audit::new("admin", "signup"), Audit::new("support", "typo")]
+    );
+
+    Ok(())
+}
+
+#[derive(EsEventHeader, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestHeader {
+    request_id: String,
+    #[serde(rename = "by")]
+    actor_id: String,
+}
+
+#[test]
+fn header_fields_follow_serde_renames() {
+    let header = RequestHeader {
+        request_id: "req".to_string(),
+        actor_id: "actor".to_string(),
+    };
+    let value = serde_json::to_value(&header).unwrap();
+    let keys: Vec<_> = value.as_object().unwrap().keys().cloned().collect();
+    let mut fields = RequestHeader::FIELDS.to_vec();
+    fields.sort();
+    assert_eq!(keys, fields);
+}
@@ -0,0 +1,128 @@
+mod entities;
+mod helpers;
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use entities::user::*;
+use es_entity::*;
+use sqlx::PgPool;
+
+/// Same on-disk format as `UserEvent` but the `Initialized` variant has been
+/// renamed to `Registered` in Rust.
+#[derive(EsEvent, Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "UserId", event_context)]
+pub enum AccountEvent {
+    #[es_event(rename = "initialized")]
+    #[serde(rename = "initialized")]
+    Registered {
+        id: UserId,
+        name: String,
+    },
+    NameUpdated {
+        name: String,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EntityHydrationError"))]
+pub struct Account {
+    pub id: UserId,
+    pub name: String,
+    events: EntityEvents<AccountEvent>,
+}
+
+impl TryFromEvents<AccountEvent> for Account {
+    fn try_from_events(events: EntityEvents<AccountEvent>) -> Result<Self, EntityHydrationError> {
+        let mut builder = AccountBuilder::default();
+        for event in events.iter_all() {
+            match event {
+                AccountEvent::Registered { id, name } => {
+                    builder = builder.id(*id).name(name.clone());
+                }
+                AccountEvent::NameUpdated { name } => {
+                    builder = builder.name(name.clone());
+                }
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+pub struct NewAccount {
+    id: UserId,
+    name: String,
+}
+
+impl IntoEvents<AccountEvent> for NewAccount {
+    fn into_events(self) -> EntityEvents<AccountEvent> {
+        EntityEvents::init(
+            self.id,
+            [AccountEvent::Registered {
+                id: self.id,
+                name: self.name,
+            }],
+        )
+    }
+}
+
+#[derive(EsRepo, Debug)]
+#[es_repo(
+    entity = "Account",
+    id = "UserId",
+    tbl = "users",
+    events_tbl = "user_events",
+    columns(name(ty = "String"))
+)]
+pub struct Accounts {
+    pool: PgPool,
+}
+
+#[derive(EsRepo, Debug)]
+#[es_repo(entity = "User", columns(name(ty = "String")))]
+pub struct Users {
+    pool: PgPool,
+}
+
+#[tokio::test]
+async fn renamed_variant_round_trips_historical_events() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let users = Users { pool: pool.clone() };
+    let accounts = Accounts { pool: pool.clone() };
+
+    // Written with the old variant name...
+    let id = UserId::new();
+    let new_user = NewUser::builder().id(id).name("Legacy").build().unwrap();
+    users.create(new_user).await?;
+
+    // ...loads into the renamed variant
+    let account = accounts.find_by_id(id).await?;
+    assert_eq!(account.name, "Legacy");
+    assert!(matches!(
+        account.events().iter_all().next(),
+        Some(AccountEvent::Registered { .. })
+    ));
+
+    // ...and the renamed variant is persisted with the original tag
+    let id = UserId::new();
+    accounts
+        .create(NewAccount {
+            id,
+            name: "Renamed".to_string(),
+        })
+        .await?;
+    let row = sqlx::query!(
+        "SELECT event_type, event->>'type' AS tag FROM user_events WHERE id = $1",
+        id as UserId
+    )
+    .fetch_one(&pool)
+    .await?;
+    assert_eq!(row.event_type, "initialized");
+    assert_eq!(row.tag.as_deref(), Some("initialized"));
+
+    let user = users.find_by_id(id).await?;
+    assert_eq!(user.name, "Renamed");
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+#![cfg(feature = "json-schema")]
+
+use es_entity::prelude::schemars::JsonSchema;
+use es_entity::*;
+use serde::{Deserialize, Serialize};
+
+es_entity::entity_id! { OrderId }
+
+#[derive(EsEvent, Debug, Serialize, Deserialize, JsonSchema)]
+#[schemars(crate = "es_entity::prelude::schemars")]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "OrderId", json_schema)]
+pub enum OrderEvent {
+    Initialized {
+        id: OrderId,
+        amount: u64,
+    },
+    #[es_event(rename = "order_shipped")]
+    #[serde(rename = "order_shipped")]
+    Shipped {
+        carrier: String,
+    },
+}
+
+#[test]
+fn event_schema_describes_tagged_envelope() {
+    let schema = serde_json::to_value(OrderEvent::event_schema()).unwrap();
+    let variants = schema["oneOf"].as_array().expect("tagged union");
+
+    let tags: Vec<_> = variants
+        .iter()
+        .map(|v| v["properties"]["type"]["const"].as_str().unwrap())
+        .collect();
+    assert_eq!(tags, vec!["initialized", "order_shipped"]);
+
+    for variant in variants {
+        let required = variant["required"].as_array().unwrap();
+        assert!(required.contains(&serde_json::json!("type")));
+    }
+    assert!(variants[0]["properties"]["amount"].is_object());
+    assert!(variants[1]["properties"]["carrier"].is_object());
+}
@@ -127,6 +127,8 @@ impl IntoEvents<SubscriberEvent> for NewSubscriber {
     entity = "Subscriber",
     forgettable,
     delete = "soft",
+    allow_hard_delete,
+    generate(delete_all),
     columns(email(ty = "Forgettable<String>", list_by), plan(ty = "String"))
 )]
 pub struct Subscribers {
@@ -320,3 +322,74 @@ async fn soft_delete_auto_forgets_the_index_column() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn delete_all_matches_single_soft_delete() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let subscribers = Subscribers::new(pool.clone());
+
+    let (first, _) = new_subscriber(&subscribers).await?;
+    let (second, _) = new_subscriber(&subscribers).await?;
+    let ids = [first.id, second.id];
+
+    let mut entities = [first, second];
+    subscribers.delete_all(&mut entities).await?;
+
+    for id in ids {
+        let row = sqlx::query!(
+            "SELECT email, plan, deleted FROM subscribers WHERE id = $1",
+            id as SubscriberId
+        )
+        .fetch_one(&pool)
+        .await?;
+        assert!(row.deleted);
+        assert_eq!(row.email, None);
+        assert_eq!(row.plan, "pro");
+
+        let payloads = sqlx::query!(
+            r#"SELECT COUNT(*) AS "count!" FROM subscribers_forgettable_payloads
+               WHERE entity_id = $1"#,
+            id as SubscriberId
+        )
+        .fetch_one(&pool)
+        .await?;
+        assert_eq!(payloads.count, 0);
+
+        assert!(subscribers.maybe_find_by_id(id).await?.is_none());
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn hard_delete_erases_row_events_and_payloads() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let subscribers = Subscribers::new(pool.clone());
+
+    let (mut subscriber, _) = new_subscriber(&subscribers).await?;
+    let id = subscriber.id;
+
+    // Refuses to drop events that were never persisted
+    let _ = subscriber.change_plan("free");
+    let err = subscribers.hard_delete(subscriber).await.unwrap_err();
+    assert!(matches!(err, SubscriberModifyError::UnpersistedEvents));
+
+    let subscriber = subscribers.find_by_id(id).await?;
+    subscribers.hard_delete(subscriber).await?;
+
+    let counts = sqlx::query!(
+        r#"SELECT
+            (SELECT COUNT(*) FROM subscribers WHERE id = $1) as "rows!",
+            (SELECT COUNT(*) FROM subscriber_events WHERE id = $1) as "events!",
+            (SELECT COUNT(*) FROM subscribers_forgettable_payloads WHERE entity_id = $1) as "payloads!""#,
+        id as SubscriberId
+    )
+    .fetch_one(&pool)
+    .await?;
+    assert_eq!((counts.rows, counts.events, counts.payloads), (0, 0, 0));
+
+    let res = subscribers.find_by_id_include_deleted(id).await;
+    assert!(res.is_err_and(|e| e.was_not_found()));
+
+    Ok(())
+}
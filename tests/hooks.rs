@@ -301,7 +301,6 @@ async fn commit_hook_delegates_through_time_wrappers() -> anyhow::Result<()> {
         .commit_hook::<MergingGetterHook>()
         .expect("OpWithTime should delegate to wrapped op");
     assert_eq!(hook.payloads, vec!["e1"]);
-    drop(wrapped);
 
     op.commit().await?;
 
@@ -0,0 +1,107 @@
+mod entities;
+mod helpers;
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use entities::user::*;
+use es_entity::*;
+use sqlx::PgPool;
+
+/// Same on-disk format as `UserEvent` but the `id` is injected from the
+/// aggregate instead of being part of the variant.
+#[derive(EsEvent, Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "UserId", event_context, implicit_id)]
+pub enum MemberEvent {
+    Initialized { name: String },
+    NameUpdated { name: String },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EntityHydrationError"))]
+pub struct Member {
+    pub id: UserId,
+    pub name: String,
+    events: EntityEvents<MemberEvent>,
+}
+
+impl TryFromEvents<MemberEvent> for Member {
+    fn try_from_events(events: EntityEvents<MemberEvent>) -> Result<Self, EntityHydrationError> {
+        let mut builder = MemberBuilder::default().id(*events.id());
+        for event in events.iter_all() {
+            match event {
+                MemberEvent::Initialized { name } | MemberEvent::NameUpdated { name } => {
+                    builder = builder.name(name.clone());
+                }
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+pub struct NewMember {
+    id: UserId,
+    name: String,
+}
+
+impl IntoEvents<MemberEvent> for NewMember {
+    fn into_events(self) -> EntityEvents<MemberEvent> {
+        EntityEvents::init(self.id, [MemberEvent::Initialized { name: self.name }])
+    }
+}
+
+#[derive(EsRepo, Debug)]
+#[es_repo(
+    entity = "Member",
+    id = "UserId",
+    tbl = "users",
+    events_tbl = "user_events",
+    columns(name(ty = "String"))
+)]
+pub struct Members {
+    pool: PgPool,
+}
+
+#[derive(EsRepo, Debug)]
+#[es_repo(entity = "User", columns(name(ty = "String")))]
+pub struct Users {
+    pool: PgPool,
+}
+
+#[tokio::test]
+async fn implicit_id_is_injected_into_the_payload() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let members = Members { pool: pool.clone() };
+    let users = Users { pool: pool.clone() };
+
+    let id = UserId::new();
+    members
+        .create(NewMember {
+            id,
+            name: "Implicit".to_string(),
+        })
+        .await?;
+
+    let row = sqlx::query!(
+        "SELECT event FROM user_events WHERE id = $1 AND sequence = 1",
+        id as UserId
+    )
+    .fetch_one(&pool)
+    .await?;
+    assert_eq!(
+        row.event,
+        serde_json::json!({ "type": "initialized", "id": id, "name": "Implicit" })
+    );
+
+    // Loads from the row's id column...
+    let member = members.find_by_id(id).await?;
+    assert_eq!(member.id, id);
+    assert_eq!(member.name, "Implicit");
+
+    // ...while consumers expecting the id in the payload keep working
+    let user = users.find_by_id(id).await?;
+    assert_eq!(user.name, "Implicit");
+
+    Ok(())
+}
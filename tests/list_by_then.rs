@@ -0,0 +1,80 @@
+mod entities;
+mod helpers;
+
+use entities::user::*;
+use es_entity::*;
+use sqlx::PgPool;
+
+#[derive(EsRepo, Debug)]
+#[es_repo(
+    entity = "User",
+    columns(name(ty = "String", list_by(then = created_at)))
+)]
+pub struct Users {
+    pool: PgPool,
+}
+
+#[tokio::test]
+async fn list_by_orders_by_the_secondary_column_before_the_id() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let users = Users { pool };
+
+    // Create the users in descending id order so that ordering by `id` alone
+    // would return them in the reverse order of their creation.
+    let name = format!("Then-{}", UserId::new());
+    let mut ids: Vec<_> = (0..3).map(|_| UserId::new()).collect();
+    ids.sort_by(|a, b| b.cmp(a));
+    for id in &ids {
+        users
+            .create(NewUser::builder().id(*id).name(name.clone()).build()?)
+            .await?;
+    }
+
+    let first_page = users
+        .list_by_name(
+            PaginatedQueryArgs {
+                first: 2,
+                after: Some(user_cursor::UserByNameCursor {
+                    id: uuid::Uuid::nil().into(),
+                    name: name.clone(),
+                    created_at: chrono::DateTime::UNIX_EPOCH,
+                }),
+            },
+            ListDirection::Ascending,
+        )
+        .await?;
+    assert!(first_page.has_next_page);
+    let end_cursor = first_page.end_cursor.expect("end cursor");
+    assert_eq!(end_cursor.name, name);
+    assert_eq!(
+        end_cursor.created_at,
+        first_page.entities[1]
+            .events()
+            .entity_first_persisted_at()
+            .unwrap()
+    );
+
+    // The cursor survives the trip through the combo cursor
+    let combo = user_cursor::UserCursor::from(end_cursor);
+    let end_cursor = user_cursor::UserByNameCursor::try_from(combo).expect("name cursor");
+
+    let second_page = users
+        .list_by_name(
+            PaginatedQueryArgs {
+                first: 1,
+                after: Some(end_cursor),
+            },
+            ListDirection::Ascending,
+        )
+        .await?;
+
+    let listed: Vec<_> = first_page
+        .entities
+        .iter()
+        .chain(second_page.entities.iter())
+        .map(|u| u.id)
+        .collect();
+    assert_eq!(listed, ids);
+
+    Ok(())
+}
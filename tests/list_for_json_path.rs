@@ -0,0 +1,189 @@
+mod entities;
+mod helpers;
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use entities::user::UserId;
+use es_entity::*;
+use sqlx::PgPool;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Metadata {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region: Option<String>,
+}
+
+/// Same table and variants as `UserEvent` with a `metadata` object that is not indexed.
+#[derive(EsEvent, Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "UserId")]
+pub enum RegionalUserEvent {
+    Initialized {
+        id: UserId,
+        name: String,
+        metadata: Metadata,
+    },
+    NameUpdated {
+        name: String,
+        metadata: Metadata,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EntityHydrationError"))]
+pub struct RegionalUser {
+    pub id: UserId,
+    pub name: String,
+    events: EntityEvents<RegionalUserEvent>,
+}
+
+impl RegionalUser {
+    fn move_to(&mut self, region: &str) {
+        self.events.push(RegionalUserEvent::NameUpdated {
+            name: self.name.clone(),
+            metadata: Metadata {
+                region: Some(region.to_string()),
+            },
+        });
+    }
+}
+
+impl TryFromEvents<RegionalUserEvent> for RegionalUser {
+    fn try_from_events(
+        events: EntityEvents<RegionalUserEvent>,
+    ) -> Result<Self, EntityHydrationError> {
+        let mut builder = RegionalUserBuilder::default();
+        for event in events.iter_all() {
+            if let RegionalUserEvent::Initialized { id, name, .. } = event {
+                builder = builder.id(*id).name(name.clone());
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+pub struct NewRegionalUser {
+    id: UserId,
+    name: String,
+    region: Option<String>,
+}
+
+impl IntoEvents<RegionalUserEvent> for NewRegionalUser {
+    fn into_events(self) -> EntityEvents<RegionalUserEvent> {
+        EntityEvents::init(
+            self.id,
+            [RegionalUserEvent::Initialized {
+                id: self.id,
+                name: self.name,
+                metadata: Metadata {
+                    region: self.region,
+                },
+            }],
+        )
+    }
+}
+
+#[derive(EsRepo, Debug)]
+#[es_repo(
+    entity = "RegionalUser",
+    id = "UserId",
+    tbl = "users",
+    events_tbl = "user_events",
+    columns(
+        name(ty = "String", list_for),
+        region(ty = "String", list_for(json_path = "metadata->>'region'"))
+    )
+)]
+pub struct RegionalUsers {
+    pool: PgPool,
+}
+
+async fn list(users: &RegionalUsers, filters: RegionalUserFilters) -> anyhow::Result<Vec<UserId>> {
+    let ret = users
+        .list_for_filters(
+            filters,
+            Sort {
+                by: RegionalUserSortBy::Id,
+                direction: ListDirection::Ascending,
+            },
+            PaginatedQueryArgs {
+                first: 100,
+                after: None,
+            },
+        )
+        .await?;
+    Ok(ret.entities.into_iter().map(|u| u.id).collect())
+}
+
+#[tokio::test]
+async fn json_path_filter_matches_the_latest_event_value() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let users = RegionalUsers { pool };
+
+    let name = format!("Regional-{}", UserId::new());
+    let eu = format!("eu-{}", UserId::new());
+    let us = format!("us-{}", UserId::new());
+    let mut ids: Vec<_> = (0..3).map(|_| UserId::new()).collect();
+    ids.sort();
+    let regions = [Some(eu.clone()), Some(us.clone()), None];
+    for (id, region) in ids.iter().zip(regions) {
+        users
+            .create(NewRegionalUser {
+                id: *id,
+                name: name.clone(),
+                region,
+            })
+            .await?;
+    }
+
+    let in_eu = list(
+        &users,
+        RegionalUserFilters {
+            region: Some(eu.clone()),
+            ..Default::default()
+        },
+    )
+    .await?;
+    assert_eq!(in_eu, vec![ids[0]]);
+
+    // Entities without the path are only listed when the filter is unset
+    let all = list(
+        &users,
+        RegionalUserFilters {
+            name: Some(name.clone()),
+            region: None,
+        },
+    )
+    .await?;
+    assert_eq!(all, ids);
+
+    // A later event carrying the path replaces the earlier value
+    let mut user = users.find_by_id(ids[1]).await?;
+    user.move_to(&eu);
+    users.update(&mut user).await?;
+    let mut user = users.find_by_id(ids[2]).await?;
+    user.move_to(&eu);
+    users.update(&mut user).await?;
+
+    let in_eu = list(
+        &users,
+        RegionalUserFilters {
+            name: Some(name.clone()),
+            region: Some(eu),
+        },
+    )
+    .await?;
+    assert_eq!(in_eu, ids);
+    let in_us = list(
+        &users,
+        RegionalUserFilters {
+            region: Some(us),
+            ..Default::default()
+        },
+    )
+    .await?;
+    assert!(in_us.is_empty());
+
+    Ok(())
+}
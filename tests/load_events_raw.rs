@@ -0,0 +1,99 @@
+mod entities;
+mod helpers;
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use entities::user::*;
+use es_entity::*;
+use sqlx::PgPool;
+
+/// Reads `user_events` but does not know about `name_updated`.
+#[derive(EsEvent, Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "UserId")]
+pub enum StrictUserEvent {
+    Initialized { id: UserId, name: String },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EntityHydrationError"))]
+pub struct StrictUser {
+    pub id: UserId,
+    events: EntityEvents<StrictUserEvent>,
+}
+
+impl TryFromEvents<StrictUserEvent> for StrictUser {
+    fn try_from_events(
+        events: EntityEvents<StrictUserEvent>,
+    ) -> Result<Self, EntityHydrationError> {
+        StrictUserBuilder::default()
+            .id(*events.id())
+            .events(events)
+            .build()
+    }
+}
+
+pub struct NewStrictUser {
+    id: UserId,
+}
+
+impl IntoEvents<StrictUserEvent> for NewStrictUser {
+    fn into_events(self) -> EntityEvents<StrictUserEvent> {
+        EntityEvents::init(
+            self.id,
+            [StrictUserEvent::Initialized {
+                id: self.id,
+                name: String::new(),
+            }],
+        )
+    }
+}
+
+#[derive(EsRepo, Debug)]
+#[es_repo(
+    entity = "StrictUser",
+    id = "UserId",
+    tbl = "users",
+    events_tbl = "user_events",
+    generate(load_events_raw)
+)]
+pub struct StrictUsers {
+    pool: PgPool,
+}
+
+#[derive(EsRepo, Debug)]
+#[es_repo(entity = "User", columns(name(ty = "String")))]
+pub struct Users {
+    pool: PgPool,
+}
+
+#[tokio::test]
+async fn load_events_raw_returns_events_that_fail_to_hydrate() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let users = Users { pool: pool.clone() };
+    let strict_users = StrictUsers { pool };
+
+    let id = UserId::new();
+    let mut user = users
+        .create(NewUser::builder().id(id).name("Raw").build()?)
+        .await?;
+    let _ = user.update_name("Renamed");
+    users.update(&mut user).await?;
+
+    assert!(matches!(
+        strict_users.find_by_id(id).await,
+        Err(StrictUserFindError::HydrationError(_))
+    ));
+
+    let events = strict_users.load_events_raw(&id).await?;
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0].sequence, 1);
+    assert_eq!(events[0].event_type, "initialized");
+    assert_eq!(events[1].sequence, 2);
+    assert_eq!(events[1].event_type, "name_updated");
+    assert_eq!(events[1].event["name"], "Renamed");
+    assert!(events[0].recorded_at <= events[1].recorded_at);
+
+    Ok(())
+}
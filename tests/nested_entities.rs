@@ -240,6 +240,53 @@ async fn find_all_with_nested_entities() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn list_loads_children_of_all_parents_in_event_order() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let orders = Orders::new(pool);
+
+    let mut order_ids = Vec::new();
+    let mut loaded = Vec::new();
+    for name in ["Pen", "Pencil", "Eraser"] {
+        let order_id = OrderId::new();
+        let mut order = orders
+            .create(NewOrderBuilder::default().id(order_id).build().unwrap())
+            .await?;
+        order.add_item(
+            NewOrderItemBuilder::default()
+                .id(OrderItemId::new())
+                .order_id(order_id)
+                .product_name(name)
+                .quantity(1)
+                .price(1.0)
+                .build()
+                .unwrap(),
+        );
+        orders.update(&mut order).await?;
+        order_ids.push(order_id);
+        loaded.push(orders.find_by_id(order_id).await?);
+    }
+
+    // Interleave the item events of the different orders
+    for quantity in 2..=4 {
+        for (order, name) in loaded.iter_mut().zip(["Pen", "Pencil", "Eraser"]) {
+            let _ = order.update_item_quantity(name, quantity * 10);
+            orders.update(order).await?;
+        }
+    }
+
+    let orders_by_id = orders.find_all_by_ids(&order_ids).await?;
+    assert_eq!(orders_by_id.len(), 3);
+    for (order, name) in orders_by_id.iter().zip(["Pen", "Pencil", "Eraser"]) {
+        assert_eq!(order.n_items(), 1);
+        let item = order.find_item_with_name(name).expect("item of the order");
+        assert_eq!(item.order_id, order.id);
+        assert_eq!(item.quantity, 40);
+    }
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn delete_parent_cascades_to_children() -> anyhow::Result<()> {
     let pool = helpers::init_pool().await?;
@@ -0,0 +1,64 @@
+mod entities;
+mod helpers;
+
+use entities::user::*;
+use es_entity::*;
+use sqlx::{PgPool, postgres::PgListener};
+
+#[derive(EsRepo, Debug)]
+#[es_repo(
+    entity = "User",
+    notify_channel = "user_changes",
+    columns(name(ty = "String"))
+)]
+pub struct Users {
+    pool: PgPool,
+}
+
+async fn next_payload(listener: &mut PgListener) -> anyhow::Result<String> {
+    let notification =
+        tokio::time::timeout(std::time::Duration::from_secs(5), listener.recv()).await??;
+    Ok(notification.payload().to_string())
+}
+
+#[tokio::test]
+async fn notifies_once_per_entity_on_commit() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let users = Users { pool: pool.clone() };
+    let mut listener = PgListener::connect_with(&pool).await?;
+    listener.listen("user_changes").await?;
+
+    let id = UserId::new();
+    let mut op = users.begin_op().await?;
+    let mut user = users
+        .create_in_op(
+            &mut op,
+            NewUser::builder().id(id).name("Notified").build().unwrap(),
+        )
+        .await?;
+    let _ = user.update_name("Notified twice");
+    users.update_in_op(&mut op, &mut user).await?;
+    op.commit().await?;
+    // Same payload within one transaction is delivered once
+    assert_eq!(next_payload(&mut listener).await?, id.to_string());
+
+    let new_users = [UserId::new(), UserId::new()];
+    users
+        .create_all(
+            new_users
+                .iter()
+                .map(|id| NewUser::builder().id(*id).name("Batch").build().unwrap())
+                .collect(),
+        )
+        .await?;
+    let mut payloads = vec![
+        next_payload(&mut listener).await?,
+        next_payload(&mut listener).await?,
+    ];
+    payloads.sort();
+    let mut expected: Vec<_> = new_users.iter().map(|id| id.to_string()).collect();
+    expected.sort();
+    assert_eq!(payloads, expected);
+
+    Ok(())
+}
@@ -0,0 +1,137 @@
+mod entities;
+mod helpers;
+
+use entities::user::*;
+use es_entity::{context::EventContext, *};
+use sqlx::PgPool;
+
+#[derive(EsRepo, Debug)]
+#[es_repo(
+    entity = "User",
+    persist_event_context = true,
+    columns(name(ty = "String"))
+)]
+pub struct Users {
+    pool: PgPool,
+}
+
+async fn stored_request_ids(pool: &PgPool, id: UserId) -> anyhow::Result<Vec<serde_json::Value>> {
+    let rows = sqlx::query!(
+        "SELECT context FROM user_events WHERE id = $1 ORDER BY sequence",
+        id as UserId
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(rows
+        .into_iter()
+        .map(|row| row.context.expect("context")["request_id"].clone())
+        .collect())
+}
+
+#[tokio::test]
+async fn begin_op_with_context_pins_the_persisted_context() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let users = Users { pool: pool.clone() };
+
+    let pinned = {
+        let mut ctx = EventContext::fork();
+        ctx.insert("request_id", &"pinned").unwrap();
+        ctx.data()
+    };
+    let mut ctx = EventContext::current();
+    ctx.insert("request_id", &"thread-local").unwrap();
+
+    let id = UserId::new();
+    let mut op = users.begin_op_with_context(pinned.clone()).await?;
+    let mut user = users
+        .create_in_op(
+            &mut op,
+            NewUser::builder().id(id).name("Pinned").build().unwrap(),
+        )
+        .await?;
+    let _ = user.update_name("Pinned again");
+    users
+        .update_all_in_op(&mut op, std::slice::from_mut(&mut user))
+        .await?;
+    op.commit().await?;
+
+    assert_eq!(
+        stored_request_ids(&pool, id).await?,
+        vec![serde_json::json!("pinned"), serde_json::json!("pinned")]
+    );
+
+    // Without a pinned context the thread-local context is stored
+    let id = UserId::new();
+    users
+        .create(NewUser::builder().id(id).name("Ambient").build().unwrap())
+        .await?;
+    assert_eq!(
+        stored_request_ids(&pool, id).await?,
+        vec![serde_json::json!("thread-local")]
+    );
+
+    Ok(())
+}
+
+#[cfg(not(feature = "tracing-context"))]
+#[tokio::test]
+async fn empty_context_is_stored_as_null() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let users = Users { pool: pool.clone() };
+
+    let id = UserId::new();
+    let mut user = users
+        .create(NewUser::builder().id(id).name("NoContext").build().unwrap())
+        .await?;
+    {
+        let mut ctx = EventContext::fork();
+        ctx.insert("request_id", &"update").unwrap();
+        let _ = user.update_name("WithContext");
+    }
+    users.update(&mut user).await?;
+
+    let rows = sqlx::query!(
+        "SELECT context FROM user_events WHERE id = $1 ORDER BY sequence",
+        id as UserId
+    )
+    .fetch_all(&pool)
+    .await?;
+    assert_eq!(rows[0].context, None);
+    assert_eq!(
+        rows[1].context,
+        Some(serde_json::json!({ "request_id": "update" }))
+    );
+
+    Ok(())
+}
+
+#[cfg(not(feature = "tracing-context"))]
+#[tokio::test]
+async fn null_and_empty_contexts_hydrate_alike() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let users = Users { pool: pool.clone() };
+
+    let id = UserId::new();
+    let mut user = users
+        .create(NewUser::builder().id(id).name("Mixed").build().unwrap())
+        .await?;
+    let _ = user.update_name("Mixed again");
+    users.update(&mut user).await?;
+    // Rows written before empty contexts were stored as NULL hold `{}`
+    sqlx::query!(
+        "UPDATE user_events SET context = '{}' WHERE id = $1 AND sequence = 2",
+        id as UserId
+    )
+    .execute(&pool)
+    .await?;
+
+    let user = users.find_by_id(id).await?;
+    let contexts: Vec<_> = user
+        .events()
+        .iter_persisted()
+        .map(|event| event.context.as_ref().map(|context| context.is_empty()))
+        .collect();
+    assert_eq!(contexts, vec![Some(true), Some(true)]);
+
+    Ok(())
+}
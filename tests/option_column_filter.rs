@@ -21,7 +21,7 @@ use sqlx::PgPool;
 #[es_repo(
     entity = "Task",
     columns(
-        workspace_id(ty = "Option<WorkspaceId>", list_for),
+        workspace_id(ty = "Option<WorkspaceId>", list_for, find_all),
         status(ty = "String", list_for(by(created_at)))
     )
 )]
@@ -335,3 +335,363 @@ async fn list_for_filters_some_none_matches_only_null_rows() -> anyhow::Result<(
 
     Ok(())
 }
+
+/// Test: find_by on an Option column uses `IS NOT DISTINCT FROM`, so `None`
+/// finds rows where the column IS NULL.
+#[tokio::test]
+async fn find_by_option_column_none_matches_null_rows() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let tasks = Tasks::new(pool);
+
+    let ws_id = WorkspaceId::new();
+    let task_with_ws = tasks
+        .create(
+            NewTask::builder()
+                .id(TaskId::new())
+                .workspace_id(ws_id)
+                .status("any")
+                .build()
+                .unwrap(),
+        )
+        .await?;
+    tasks
+        .create(
+            NewTask::builder()
+                .id(TaskId::new())
+                .status("any")
+                .build()
+                .unwrap(),
+        )
+        .await?;
+
+    let found = tasks.find_by_workspace_id(Some(ws_id)).await?;
+    assert_eq!(found.id, task_with_ws.id);
+
+    let found = tasks.maybe_find_by_workspace_id(None).await?;
+    assert!(found.is_some_and(|t| t.workspace_id.is_none()));
+
+    Ok(())
+}
+
+/// Test: find_all_by on an Option column matches NULL rows for `None` and
+/// only the given value for `Some`.
+#[tokio::test]
+async fn find_all_by_option_column_matches_null_rows() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let tasks = Tasks::new(pool);
+
+    let ws_id = WorkspaceId::new();
+    let mut with_ws = Vec::new();
+    for _ in 0..2 {
+        let task = tasks
+            .create(
+                NewTask::builder()
+                    .id(TaskId::new())
+                    .workspace_id(ws_id)
+                    .status("any")
+                    .build()
+                    .unwrap(),
+            )
+            .await?;
+        with_ws.push(task.id);
+    }
+    let root = tasks
+        .create(
+            NewTask::builder()
+                .id(TaskId::new())
+                .status("any")
+                .build()
+                .unwrap(),
+        )
+        .await?;
+
+    let mut found: Vec<_> = tasks
+        .find_all_by_workspace_id(Some(ws_id))
+        .await?
+        .into_iter()
+        .map(|t| t.id)
+        .collect();
+    found.sort();
+    with_ws.sort();
+    assert_eq!(found, with_ws);
+
+    let roots = tasks.find_all_by_workspace_id(None).await?;
+    assert!(roots.iter().any(|t| t.id == root.id));
+    assert!(roots.iter().all(|t| t.workspace_id.is_none()));
+
+    Ok(())
+}
+
+/// Ids that sort in the order of `suffix` (Postgres compares uuids bytewise)
+fn ordered_task_id(base: uuid::Uuid, suffix: u8) -> TaskId {
+    let mut bytes = *base.as_bytes();
+    bytes[15] = suffix;
+    TaskId::from(uuid::Uuid::from_bytes(bytes))
+}
+
+async fn create_ordered_task(
+    tasks: &Tasks,
+    base: uuid::Uuid,
+    suffix: u8,
+    ws_id: WorkspaceId,
+    status: &str,
+) -> anyhow::Result<TaskId> {
+    let id = ordered_task_id(base, suffix);
+    tasks
+        .create(
+            NewTask::builder()
+                .id(id)
+                .workspace_id(ws_id)
+                .status(status)
+                .build()
+                .unwrap(),
+        )
+        .await?;
+    Ok(id)
+}
+
+/// Test: rows inserted between page fetches - on either side of the cursor -
+/// neither duplicate nor skip any row of the filtered keyset pagination.
+#[tokio::test]
+async fn list_for_filters_is_stable_under_concurrent_inserts() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let tasks = Tasks::new(pool);
+
+    for direction in [ListDirection::Ascending, ListDirection::Descending] {
+        let base = uuid::Uuid::now_v7();
+        let ws_id = WorkspaceId::new();
+        let status = format!("paging_{}", TaskId::new());
+        let filters = || TaskFilters {
+            workspace_id: Some(Some(ws_id)),
+            status: Some(status.clone()),
+        };
+        let sort = Sort {
+            by: TaskSortBy::Id,
+            direction,
+        };
+        let ascending = matches!(direction, ListDirection::Ascending);
+
+        let mut initial = Vec::new();
+        for suffix in [0x10, 0x30, 0x50, 0x70] {
+            initial.push(create_ordered_task(&tasks, base, suffix, ws_id, &status).await?);
+        }
+        // Same status but another workspace - must never show up
+        create_ordered_task(&tasks, base, 0x40, WorkspaceId::new(), &status).await?;
+
+        let page = tasks
+            .list_for_filters(
+                filters(),
+                sort,
+                PaginatedQueryArgs {
+                    first: 2,
+                    after: None,
+                },
+            )
+            .await?;
+        assert!(page.has_next_page);
+        let mut seen: Vec<_> = page.entities.iter().map(|t| t.id).collect();
+
+        // One row lands on the already fetched side of the cursor, one on the pending side
+        let (passed, pending) = if ascending {
+            (0x20, 0x60)
+        } else {
+            (0x60, 0x20)
+        };
+        let passed = create_ordered_task(&tasks, base, passed, ws_id, &status).await?;
+        let pending = create_ordered_task(&tasks, base, pending, ws_id, &status).await?;
+
+        let mut after = page.end_cursor;
+        loop {
+            let page = tasks
+                .list_for_filters(filters(), sort, PaginatedQueryArgs { first: 2, after })
+                .await?;
+            seen.extend(page.entities.iter().map(|t| t.id));
+            if !page.has_next_page {
+                break;
+            }
+            after = page.end_cursor;
+        }
+
+        let mut expected = initial.clone();
+        expected.push(pending);
+        expected.sort();
+        if !ascending {
+            expected.reverse();
+        }
+        assert_eq!(seen, expected, "direction: {direction:?}");
+        assert!(!seen.contains(&passed));
+    }
+
+    Ok(())
+}
+
+/// Test: same as above for the `(created_at, id)` keyset - new rows always sort
+/// after the existing ones so they only show up when paging ascending.
+#[tokio::test]
+async fn list_for_filters_by_created_at_is_stable_under_concurrent_inserts() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let tasks = Tasks::new(pool);
+
+    for direction in [ListDirection::Ascending, ListDirection::Descending] {
+        let base = uuid::Uuid::now_v7();
+        let ws_id = WorkspaceId::new();
+        let status = format!("paging_{}", TaskId::new());
+        let filters = || TaskFilters {
+            workspace_id: Some(Some(ws_id)),
+            status: Some(status.clone()),
+        };
+        let sort = Sort {
+            by: TaskSortBy::CreatedAt,
+            direction,
+        };
+        let ascending = matches!(direction, ListDirection::Ascending);
+
+        let mut initial = Vec::new();
+        for suffix in [0x10, 0x20, 0x30, 0x40] {
+            initial.push(create_ordered_task(&tasks, base, suffix, ws_id, &status).await?);
+        }
+
+        let page = tasks
+            .list_for_filters(
+                filters(),
+                sort,
+                PaginatedQueryArgs {
+                    first: 2,
+                    after: None,
+                },
+            )
+            .await?;
+        let mut seen: Vec<_> = page.entities.iter().map(|t| t.id).collect();
+
+        let inserted = create_ordered_task(&tasks, base, 0x01, ws_id, &status).await?;
+
+        let mut after = page.end_cursor;
+        loop {
+            let page = tasks
+                .list_for_filters(filters(), sort, PaginatedQueryArgs { first: 2, after })
+                .await?;
+            seen.extend(page.entities.iter().map(|t| t.id));
+            if !page.has_next_page {
+                break;
+            }
+            after = page.end_cursor;
+        }
+
+        let mut expected = initial.clone();
+        if ascending {
+            expected.push(inserted);
+        } else {
+            expected.reverse();
+        }
+        assert_eq!(seen, expected, "direction: {direction:?}");
+    }
+
+    Ok(())
+}
+
+/// Test: sorting doesn't depend on which filters are set - a status filter can be
+/// sorted by `created_at` (its paired `list_for_status_by_created_at`) as well as by
+/// `id` (COALESCE fallback), and a workspace filter paired only with `id` can still be
+/// sorted by `created_at`.
+#[tokio::test]
+async fn list_for_filters_status_sorted_by_created_at() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let tasks = Tasks::new(pool);
+
+    let ws_id = WorkspaceId::new();
+    let status = format!("sorted_{}", TaskId::new());
+    let mut created = Vec::new();
+    // Ids are random so the creation order differs from the id order
+    for _ in 0..3 {
+        let task = tasks
+            .create(
+                NewTask::builder()
+                    .id(TaskId::new())
+                    .workspace_id(ws_id)
+                    .status(&status)
+                    .build()
+                    .unwrap(),
+            )
+            .await?;
+        created.push(task.id);
+    }
+    tasks
+        .create(
+            NewTask::builder()
+                .id(TaskId::new())
+                .workspace_id(ws_id)
+                .status("other")
+                .build()
+                .unwrap(),
+        )
+        .await?;
+
+    let list = |filters: TaskFilters, by: TaskSortBy, direction: ListDirection| {
+        tasks.list_for_filters(
+            filters,
+            Sort { by, direction },
+            PaginatedQueryArgs {
+                first: 10,
+                after: None,
+            },
+        )
+    };
+    let status_only = || TaskFilters {
+        workspace_id: None,
+        status: Some(status.clone()),
+    };
+
+    let asc = list(
+        status_only(),
+        TaskSortBy::CreatedAt,
+        ListDirection::Ascending,
+    )
+    .await?;
+    let ids: Vec<_> = asc.entities.iter().map(|t| t.id).collect();
+    assert_eq!(ids, created);
+
+    let desc = list(
+        status_only(),
+        TaskSortBy::CreatedAt,
+        ListDirection::Descending,
+    )
+    .await?;
+    let ids: Vec<_> = desc.entities.iter().map(|t| t.id).collect();
+    assert_eq!(ids, created.iter().rev().copied().collect::<Vec<_>>());
+
+    let by_id = list(status_only(), TaskSortBy::Id, ListDirection::Ascending).await?;
+    let ids: Vec<_> = by_id.entities.iter().map(|t| t.id).collect();
+    let mut sorted = created.clone();
+    sorted.sort();
+    assert_eq!(ids, sorted);
+
+    let workspace_only = TaskFilters {
+        workspace_id: Some(Some(ws_id)),
+        status: None,
+    };
+    let by_workspace = list(
+        workspace_only,
+        TaskSortBy::CreatedAt,
+        ListDirection::Ascending,
+    )
+    .await?;
+    let ids: Vec<_> = by_workspace.entities.iter().map(|t| t.id).collect();
+    assert_eq!(ids.len(), 4);
+    assert_eq!(ids[..3], created[..]);
+
+    Ok(())
+}
+
+#[test]
+fn sort_by_parses_column_names() {
+    assert_eq!(
+        "created_at".parse::<TaskSortBy>().unwrap(),
+        TaskSortBy::CreatedAt
+    );
+    assert_eq!(TaskSortBy::try_from("id").unwrap(), TaskSortBy::Id);
+
+    let err = "createdAt".parse::<TaskSortBy>().unwrap_err();
+    assert_eq!(err.value(), "createdAt");
+    assert_eq!(err.valid_values(), TaskSortBy::VALUES);
+    assert!(err.to_string().contains("created_at, id"));
+}
@@ -0,0 +1,101 @@
+#![cfg(feature = "otel-metrics")]
+
+mod entities;
+mod helpers;
+
+use entities::user::*;
+use es_entity::*;
+use opentelemetry_sdk::metrics::{
+    InMemoryMetricExporter, PeriodicReader, SdkMeterProvider,
+    data::{AggregatedMetrics, MetricData, ResourceMetrics},
+};
+use sqlx::PgPool;
+
+#[derive(EsRepo, Debug)]
+#[es_repo(entity = "User", columns(name(ty = "String", list_for)))]
+pub struct Users {
+    pool: PgPool,
+}
+
+fn counter_value(metrics: &[ResourceMetrics], name: &str) -> u64 {
+    metrics
+        .iter()
+        .flat_map(|rm| rm.scope_metrics())
+        .flat_map(|sm| sm.metrics())
+        .filter(|m| m.name() == name)
+        .map(|m| match m.data() {
+            AggregatedMetrics::U64(MetricData::Sum(sum)) => sum
+                .data_points()
+                .filter(|dp| {
+                    dp.attributes()
+                        .any(|kv| kv.key.as_str() == "entity" && kv.value.as_str() == "User")
+                })
+                .map(|dp| dp.value())
+                .sum(),
+            _ => 0,
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+fn histogram_count(metrics: &[ResourceMetrics], name: &str) -> u64 {
+    metrics
+        .iter()
+        .flat_map(|rm| rm.scope_metrics())
+        .flat_map(|sm| sm.metrics())
+        .filter(|m| m.name() == name)
+        .map(|m| match m.data() {
+            AggregatedMetrics::F64(MetricData::Histogram(hist)) => {
+                hist.data_points().map(|dp| dp.count()).sum()
+            }
+            _ => 0,
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+#[tokio::test]
+async fn repo_operations_record_metrics() -> anyhow::Result<()> {
+    // Must be installed before the first repo call creates the instruments
+    let exporter = InMemoryMetricExporter::default();
+    let provider = SdkMeterProvider::builder()
+        .with_reader(PeriodicReader::builder(exporter.clone()).build())
+        .build();
+    opentelemetry::global::set_meter_provider(provider.clone());
+
+    let pool = helpers::init_pool().await?;
+    let users = Users { pool };
+
+    let name = format!("metrics-{}", UserId::new());
+    let mut user = users
+        .create(NewUser::builder().id(UserId::new()).name(&name).build()?)
+        .await?;
+    let _ = user.update_name(format!("{name}-renamed"));
+    users.update_all(std::slice::from_mut(&mut user)).await?;
+    // Nothing new to persist - not counted
+    users.update_all(std::slice::from_mut(&mut user)).await?;
+
+    let _ = users
+        .list_for_filters(
+            UserFilters {
+                name: Some(format!("{name}-renamed")),
+            },
+            Sort {
+                by: UserSortBy::Id,
+                direction: ListDirection::Ascending,
+            },
+            PaginatedQueryArgs {
+                first: 10,
+                after: None,
+            },
+        )
+        .await?;
+
+    provider.force_flush()?;
+    let metrics = exporter.get_finished_metrics()?;
+    assert_eq!(counter_value(&metrics, "es_entity.create.count"), 1);
+    assert_eq!(counter_value(&metrics, "es_entity.update.count"), 1);
+    assert_eq!(histogram_count(&metrics, "es_entity.query.duration"), 1);
+
+    Ok(())
+}
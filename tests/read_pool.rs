@@ -0,0 +1,111 @@
+mod entities;
+mod helpers;
+
+use entities::user::*;
+use es_entity::*;
+use sqlx::PgPool;
+
+#[derive(EsRepo, Debug)]
+#[es_repo(
+    entity = "User",
+    columns(name(ty = "String", list_by)),
+    read_pool_accessor = "replica_pool()"
+)]
+pub struct Users {
+    pool: PgPool,
+    replica: PgPool,
+}
+
+impl Users {
+    fn replica_pool(&self) -> &PgPool {
+        &self.replica
+    }
+}
+
+async fn init_replica_pool() -> anyhow::Result<PgPool> {
+    let pg_con = std::env::var("PG_CON").unwrap();
+    let pool = sqlx::postgres::PgPoolOptions::new()
+        .after_connect(|conn, _| {
+            Box::pin(async move {
+                sqlx::query("SET default_transaction_read_only = on")
+                    .execute(conn)
+                    .await?;
+                Ok(())
+            })
+        })
+        .connect(&pg_con)
+        .await?;
+    Ok(pool)
+}
+
+#[tokio::test]
+async fn reads_use_read_pool_and_writes_use_primary() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let replica = init_replica_pool().await?;
+    let users = Users {
+        pool: pool.clone(),
+        replica: replica.clone(),
+    };
+
+    // Writes go to the primary - the replica connections are read only
+    let id = UserId::new();
+    let new_user = NewUser::builder().id(id).name("Replica").build().unwrap();
+    let mut user = users.create(new_user).await?;
+    let _ = user.update_name("Replicated");
+    users.update(&mut user).await?;
+
+    let loaded = users.find_by_id(id).await?;
+    assert_eq!(loaded.name, "Replicated");
+    let page = users
+        .list_by_name(
+            PaginatedQueryArgs {
+                first: 1,
+                after: None,
+            },
+            ListDirection::Ascending,
+        )
+        .await?;
+    assert_eq!(page.entities.len(), 1);
+
+    // Once the replica is gone the convenience reads fail while explicit ops still work
+    replica.close().await;
+    assert!(users.find_by_id(id).await.is_err());
+    let loaded = users.find_by_id_in_op(users.pool(), id).await?;
+    assert_eq!(loaded.name, "Replicated");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn read_only_op_rejects_writes() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    // The replica is writable so only the transaction itself is read only
+    let users = Users {
+        pool: pool.clone(),
+        replica: pool.clone(),
+    };
+    let id = UserId::new();
+    users
+        .create(NewUser::builder().id(id).name("ReadOnly").build().unwrap())
+        .await?;
+
+    let mut op = users.begin_read_only_op().await?;
+    assert!(op.is_read_only());
+    let loaded = users.find_by_id_in_op(&mut op, id).await?;
+    assert_eq!(loaded.name, "ReadOnly");
+
+    let new_user = NewUser::builder()
+        .id(UserId::new())
+        .name("Rejected")
+        .build()
+        .unwrap();
+    match users.create_in_op(&mut op, new_user).await {
+        Err(UserCreateError::Sqlx(sqlx::Error::Database(e))) => {
+            assert_eq!(e.code().as_deref(), Some("25006"))
+        }
+        Err(e) => panic!("expected read only violation, got {e:?}"),
+        Ok(_) => panic!("write succeeded in a read only transaction"),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+mod entities;
+mod helpers;
+
+use entities::user::*;
+use es_entity::*;
+use sqlx::PgPool;
+
+#[derive(EsRepo, Debug)]
+#[es_repo(
+    entity = "User",
+    columns(name(ty = "String")),
+    recorded_at_precision = "ms"
+)]
+pub struct Users {
+    pool: PgPool,
+}
+
+#[tokio::test]
+async fn recorded_at_is_truncated_to_configured_precision() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let users = Users { pool };
+
+    let new_user = NewUser::builder()
+        .id(UserId::new())
+        .name("Precise")
+        .build()
+        .unwrap();
+    let mut user = users.create(new_user).await?;
+    let _ = user.update_name("Still precise");
+    users.update(&mut user).await?;
+
+    let loaded = users.find_by_id(user.id).await?;
+    let in_memory: Vec<_> = user
+        .events()
+        .iter_persisted()
+        .map(|e| e.recorded_at)
+        .collect();
+    let from_db: Vec<_> = loaded
+        .events()
+        .iter_persisted()
+        .map(|e| e.recorded_at)
+        .collect();
+    assert_eq!(in_memory, from_db);
+    assert_eq!(from_db.len(), 2);
+    for recorded_at in from_db {
+        assert_eq!(recorded_at.timestamp_subsec_nanos() % 1_000_000, 0);
+    }
+
+    Ok(())
+}
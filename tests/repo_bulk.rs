@@ -75,3 +75,64 @@ async fn update_all_with_custom_accessors() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+mod chunked {
+    use es_entity::*;
+    use sqlx::PgPool;
+
+    use crate::entities::profile::*;
+
+    /// Same table as `Profiles` but persisting bulk operations two entities at a time.
+    #[derive(EsRepo, Debug)]
+    #[es_repo(
+        entity = "Profile",
+        batch_size = 2,
+        columns(
+            name(ty = "String", update(accessor = "data.name")),
+            display_name(
+                ty = "String",
+                create(accessor = "display_name()"),
+                update(accessor = "display_name()")
+            ),
+            email(ty = "String"),
+        )
+    )]
+    pub struct ChunkedProfiles {
+        pub pool: PgPool,
+    }
+}
+
+#[tokio::test]
+async fn bulk_operations_larger_than_batch_size() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let profiles = chunked::ChunkedProfiles { pool };
+
+    let new_profiles: Vec<_> = (0..5)
+        .map(|i| {
+            NewProfile::builder()
+                .id(ProfileId::new())
+                .name(format!("Chunked {i}"))
+                .email(format!("chunked_{i}_{}@test.com", ProfileId::new()))
+                .build()
+                .unwrap()
+        })
+        .collect();
+    let ids: Vec<_> = new_profiles.iter().map(|p| p.id).collect();
+
+    let mut created = profiles.create_all(new_profiles).await?;
+    assert_eq!(created.len(), 5);
+    assert_eq!(created.iter().map(|p| p.id).collect::<Vec<_>>(), ids);
+
+    for (i, profile) in created.iter_mut().enumerate() {
+        let _ = profile.update_name(format!("Chunked {i} updated"));
+    }
+    let n_events = profiles.update_all(&mut created).await?;
+    assert_eq!(n_events, 5);
+
+    for (i, id) in ids.iter().enumerate() {
+        let loaded = profiles.find_by_id(*id).await?;
+        assert_eq!(loaded.data.name, format!("Chunked {i} updated"));
+    }
+
+    Ok(())
+}
@@ -72,6 +72,38 @@ mod users_with_required_clock {
 
 use users_with_required_clock::UsersWithRequiredClock;
 
+/// A separate module for the repo configured with `clock = "..."`
+mod users_with_clock_option {
+    use es_entity::{EsEntity, EsEvent, EsRepo, clock::ClockHandle};
+    use sqlx::PgPool;
+
+    use crate::entities::user::*;
+
+    /// A repo whose clock is exposed through an accessor instead of a `clock` field
+    #[derive(EsRepo, Debug)]
+    #[es_repo(
+        entity = "User",
+        columns(name(ty = "String", list_for)),
+        clock = "self.sim_clock()"
+    )]
+    pub struct UsersWithClockOption {
+        pool: PgPool,
+        sim: ClockHandle,
+    }
+
+    impl UsersWithClockOption {
+        pub fn new(pool: PgPool, sim: ClockHandle) -> Self {
+            Self { pool, sim }
+        }
+
+        fn sim_clock(&self) -> &ClockHandle {
+            &self.sim
+        }
+    }
+}
+
+use users_with_clock_option::UsersWithClockOption;
+
 #[tokio::test]
 async fn create_with_manual_clock() -> anyhow::Result<()> {
     let pool = helpers::init_pool().await?;
@@ -218,3 +250,82 @@ async fn create_with_required_clock_field() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn clock_option_stamps_writes_on_ops_without_cached_time() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+
+    let fixed_time = {
+        let t = chrono::Utc::now() - chrono::Duration::days(120);
+        chrono::DateTime::from_timestamp_millis(t.timestamp_millis()).unwrap()
+    };
+    let (clock, ctrl) = ClockHandle::manual_at(fixed_time);
+    let users = UsersWithClockOption::new(pool.clone(), clock);
+
+    // A plain transaction carries no time - the repo clock is used instead of NOW()
+    let mut tx = pool.begin().await?;
+    let new_user = NewUser::builder()
+        .id(UserId::new())
+        .name("ClockOptionTest")
+        .build()
+        .unwrap();
+    let mut user = users.create_in_op(&mut tx, new_user).await?;
+    tx.commit().await?;
+
+    let row = sqlx::query!(
+        "SELECT created_at FROM users WHERE id = $1",
+        user.id as UserId
+    )
+    .fetch_one(&pool)
+    .await?;
+    assert_eq!(row.created_at, fixed_time);
+
+    // The convenience functions begin their op with the configured clock
+    ctrl.advance(std::time::Duration::from_secs(60)).await;
+    let _ = user.update_name("ClockOptionUpdated");
+    users.update(&mut user).await?;
+
+    let loaded = users.find_by_id(user.id).await?;
+    assert_eq!(
+        loaded.events().entity_first_persisted_at(),
+        Some(fixed_time)
+    );
+    assert_eq!(
+        loaded.events().entity_last_modified_at(),
+        Some(fixed_time + chrono::Duration::seconds(60))
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn clock_field_stamps_writes_on_ops_without_cached_time() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+
+    let fixed_time = {
+        let t = chrono::Utc::now() - chrono::Duration::days(150);
+        chrono::DateTime::from_timestamp_millis(t.timestamp_millis()).unwrap()
+    };
+    let (clock, _ctrl) = ClockHandle::manual_at(fixed_time);
+    let users = UsersWithRequiredClock::new(pool.clone(), clock);
+
+    // The detected clock field is bound just like a configured `clock = "..."`
+    let mut tx = pool.begin().await?;
+    let new_user = NewUser::builder()
+        .id(UserId::new())
+        .name("ClockFieldTxTest")
+        .build()
+        .unwrap();
+    let user = users.create_in_op(&mut tx, new_user).await?;
+    tx.commit().await?;
+
+    let row = sqlx::query!(
+        "SELECT created_at FROM users WHERE id = $1",
+        user.id as UserId
+    )
+    .fetch_one(&pool)
+    .await?;
+    assert_eq!(row.created_at, fixed_time);
+
+    Ok(())
+}
@@ -27,7 +27,11 @@ mod generic_e_repo {
 }
 
 #[derive(EsRepo, Debug)]
-#[es_repo(entity = "User", columns(name(ty = "String", list_for)))]
+#[es_repo(
+    entity = "User",
+    generate(stream_all, events_between, exists_by, list_by_id_parallel),
+    columns(name(ty = "String", list_for, find_all, group_count))
+)]
 pub struct Users {
     pool: PgPool,
 }
@@ -66,6 +70,322 @@ async fn create() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn validate_new_does_not_persist() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let users = Users::new(pool);
+
+    let id = UserId::new();
+    let user = users.validate_new(NewUser::builder().id(id).name("Dry").build().unwrap())?;
+    assert_eq!(user.id, id);
+    assert_eq!(user.name, "Dry");
+
+    let res = users.find_by_id(id).await;
+    assert!(res.is_err_and(|e| e.was_not_found()));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn to_snapshot_json() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let users = Users::new(pool);
+
+    let id = UserId::new();
+    let new_user = NewUser::builder().id(id).name("Snap").build().unwrap();
+    let mut user = users.create(new_user).await?;
+    let _ = user.update_name("Shot");
+
+    // Only the projected state is serialized, not the events
+    assert_eq!(
+        user.to_snapshot_json(),
+        serde_json::json!({ "id": id, "name": "Shot" })
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn stream_all() -> anyhow::Result<()> {
+    use futures::TryStreamExt;
+
+    let pool = helpers::init_pool().await?;
+    let users = Users::new(pool);
+
+    let mut ids = Vec::new();
+    for _ in 0..3 {
+        let id = UserId::new();
+        let new_user = NewUser::builder().id(id).name("Streamed").build().unwrap();
+        users.create(new_user).await?;
+        ids.push(id);
+    }
+
+    let streamed: Vec<User> = users.stream_all().try_collect().await?;
+    let streamed_ids: Vec<_> = streamed.iter().map(|u| u.id).collect();
+    assert!(ids.iter().all(|id| streamed_ids.contains(id)));
+    assert!(streamed_ids.is_sorted());
+
+    let mut op = users.begin_op().await?;
+    let streamed_in_op: Vec<User> = users.stream_all_in_op(&mut op).try_collect().await?;
+    assert!(
+        ids.iter()
+            .all(|id| streamed_in_op.iter().any(|u| &u.id == id))
+    );
+    op.commit().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exists_by() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let users = Users::new(pool);
+
+    let id = UserId::new();
+    let name = format!("exists_{id}");
+    assert!(!users.exists_by_id(id).await?);
+    assert!(!users.exists_by_name(&name).await?);
+
+    let new_user = NewUser::builder().id(id).name(&name).build().unwrap();
+    let mut op = users.begin_op().await?;
+    users.create_in_op(&mut op, new_user).await?;
+    assert!(users.exists_by_name_in_op(&mut op, &name).await?);
+    op.commit().await?;
+
+    assert!(users.exists_by_id(id).await?);
+    assert!(users.exists_by_name(&name).await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn events_between() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let users = Users::new(pool);
+
+    let id = UserId::new();
+    let new_user = NewUser::builder().id(id).name("First").build().unwrap();
+    let mut user = users.create(new_user).await?;
+    for name in ["Second", "Third", "Fourth"] {
+        let _ = user.update_name(name);
+        users.update(&mut user).await?;
+    }
+
+    let events = users.events_between(&id, 2, 3).await?;
+    let names: Vec<_> = events
+        .iter()
+        .map(|(sequence, event)| match event {
+            UserEvent::NameUpdated { name } => (*sequence, name.as_str()),
+            _ => panic!("unexpected event"),
+        })
+        .collect();
+    assert_eq!(names, vec![(2, "Second"), (3, "Third")]);
+
+    assert!(users.events_between(&id, 5, 10).await?.is_empty());
+
+    // Bounds beyond the stored sequence range don't wrap around
+    assert_eq!(users.events_between(&id, 3, usize::MAX).await?.len(), 2);
+    assert!(
+        users
+            .events_between(&id, usize::MAX, usize::MAX)
+            .await?
+            .is_empty()
+    );
+
+    Ok(())
+}
+
+mod ignore_conflicts {
+    use es_entity::*;
+    use sqlx::PgPool;
+
+    use crate::entities::user::*;
+
+    #[derive(EsRepo, Debug)]
+    #[es_repo(
+        entity = "User",
+        create(on_conflict = "ignore"),
+        columns(name(ty = "String"))
+    )]
+    pub struct Users {
+        pub pool: PgPool,
+    }
+}
+
+#[tokio::test]
+async fn create_ignoring_conflicts() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let users = ignore_conflicts::Users { pool: pool.clone() };
+
+    let id = UserId::new();
+    let new_user = NewUser::builder().id(id).name("Original").build().unwrap();
+    let created = users.create(new_user).await?;
+    assert!(created.did_create());
+
+    let redelivered = NewUser::builder().id(id).name("Original").build().unwrap();
+    let res = users.create(redelivered).await?;
+    assert!(res.was_ignored());
+    let existing = res.into_inner();
+    assert_eq!(existing.id, id);
+    assert_eq!(existing.name, "Original");
+
+    // A different entity claiming the same id is not a redelivery
+    let duplicate = NewUser::builder().id(id).name("Duplicate").build().unwrap();
+    let Err(err) = users.create(duplicate).await else {
+        panic!("expected AlreadyExists");
+    };
+    assert!(matches!(
+        err,
+        ignore_conflicts::UserCreateError::AlreadyExists
+    ));
+    assert!(err.was_duplicate());
+
+    // Neither the projection nor the events were touched
+    let loaded = users.find_by_id(id).await?;
+    assert_eq!(loaded.name, "Original");
+    assert_eq!(loaded.events().len_persisted(), 1);
+
+    Ok(())
+}
+
+mod lost_projection {
+    use es_entity::*;
+    use sqlx::PgPool;
+
+    use crate::entities::user::*;
+
+    #[derive(EsRepo, Debug)]
+    #[es_repo(
+        entity = "User",
+        tbl = "recovered_users",
+        events_tbl = "recovered_user_events",
+        allow_find_by_id_from_events,
+        columns(name(ty = "String"))
+    )]
+    pub struct RecoveredUsers {
+        pub pool: PgPool,
+    }
+}
+
+#[tokio::test]
+async fn find_by_id_from_events() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let users = lost_projection::RecoveredUsers { pool: pool.clone() };
+
+    let id = UserId::new();
+    let new_user = NewUser::builder().id(id).name("First").build().unwrap();
+    let mut user = users.create(new_user).await?;
+    let _ = user.update_name("Second");
+    users.update(&mut user).await?;
+
+    // Lose the projection row - the events remain
+    sqlx::query!("DELETE FROM recovered_users WHERE id = $1", id as UserId)
+        .execute(&pool)
+        .await?;
+    assert!(users.maybe_find_by_id(id).await?.is_none());
+
+    let recovered = users.find_by_id_from_events(id).await?;
+    assert_eq!(recovered.name, "Second");
+    assert_eq!(recovered.events().len_persisted(), 2);
+
+    let res = users.find_by_id_from_events(UserId::new()).await;
+    assert!(matches!(res, Err(e) if e.was_not_found()));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn find_all_by() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+
+    let users = Users::new(pool);
+
+    let name = format!("shared_{}", UserId::new());
+    let new_users = (0..3)
+        .map(|_| {
+            NewUser::builder()
+                .id(UserId::new())
+                .name(name.clone())
+                .build()
+                .unwrap()
+        })
+        .collect();
+    let created = users.create_all(new_users).await?;
+
+    let mut found = users.find_all_by_name(&name).await?;
+    assert_eq!(found.len(), 3);
+    found.sort_by_key(|u| u.id);
+    let mut expected: Vec<_> = created.iter().map(|u| u.id).collect();
+    expected.sort();
+    assert_eq!(found.iter().map(|u| u.id).collect::<Vec<_>>(), expected);
+
+    let none = users.find_all_by_name("does-not-exist").await?;
+    assert!(none.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn count_by() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let users = Users::new(pool);
+    let name = format!("Counted {}", UserId::new());
+    for _ in 0..2 {
+        users
+            .create(
+                NewUser::builder()
+                    .id(UserId::new())
+                    .name(name.clone())
+                    .build()
+                    .unwrap(),
+            )
+            .await?;
+    }
+
+    let counts = users.count_by_name().await?;
+    assert_eq!(counts.get(&name), Some(&2));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn find_all_by_ids_preserves_input_order() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+
+    let users = Users::new(pool);
+
+    let new_users = (0..3)
+        .map(|i| {
+            NewUser::builder()
+                .id(UserId::new())
+                .name(format!("ordered_{i}"))
+                .build()
+                .unwrap()
+        })
+        .collect();
+    let created = users.create_all(new_users).await?;
+    let missing = UserId::new();
+    let ids = vec![
+        created[2].id,
+        missing,
+        created[0].id,
+        created[1].id,
+        created[2].id,
+    ];
+
+    let found = users.find_all_by_ids(&ids).await?;
+    assert_eq!(
+        found.iter().map(|u| u.id).collect::<Vec<_>>(),
+        vec![created[2].id, created[0].id, created[1].id]
+    );
+
+    let map = users.find_all_by_ids_map(&ids).await?;
+    assert_eq!(map.len(), 3);
+    assert!(!map.contains_key(&missing));
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn list_by() -> anyhow::Result<()> {
     let pool = helpers::init_pool().await?;
@@ -98,8 +418,45 @@ async fn list_by() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn list_by_id_parallel() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let users = Users::new(pool);
+
+    for _ in 0..5 {
+        let new_user = NewUser::builder()
+            .id(UserId::new())
+            .name("Parallel")
+            .build()
+            .unwrap();
+        users.create(new_user).await?;
+    }
+    let marker = UserId::new();
+
+    for direction in [ListDirection::Descending, ListDirection::Ascending] {
+        let args = || PaginatedQueryArgs {
+            first: 5,
+            after: Some(user_cursor::UserByIdCursor { id: marker }),
+        };
+        let single = users.list_by_id(args(), direction).await?;
+        let parallel = users.list_by_id_parallel(args(), direction, 2).await?;
+
+        let single_ids: Vec<_> = single.entities.iter().map(|u| u.id).collect();
+        let parallel_ids: Vec<_> = parallel.entities.iter().map(|u| u.id).collect();
+        assert_eq!(parallel_ids, single_ids);
+        assert_eq!(parallel.has_next_page, single.has_next_page);
+        assert_eq!(
+            parallel.end_cursor.map(|c| c.id),
+            single.end_cursor.map(|c| c.id)
+        );
+    }
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn list_for_filters() -> anyhow::Result<()> {
+    assert_eq!(UserSortBy::default(), UserSortBy::Id);
     let pool = helpers::init_pool().await?;
 
     let users = Users::new(pool);
@@ -112,10 +469,7 @@ async fn list_for_filters() -> anyhow::Result<()> {
     } = users
         .list_for_filters(
             UserFilters::default(),
-            Sort {
-                by: UserSortBy::Id,
-                direction: ListDirection::Ascending,
-            },
+            Sort::default(),
             PaginatedQueryArgs {
                 first: 10,
                 after: None,
@@ -194,6 +548,159 @@ async fn list_for_filters() -> anyhow::Result<()> {
     Ok(())
 }
 
+mod created_at_range {
+    use es_entity::*;
+    use sqlx::PgPool;
+
+    use crate::entities::user::*;
+
+    #[derive(EsRepo, Debug)]
+    #[es_repo(
+        entity = "User",
+        columns(created_at(list_for(range)), name(ty = "String", list_for))
+    )]
+    pub struct Users {
+        pub pool: PgPool,
+    }
+}
+
+#[tokio::test]
+async fn list_for_filters_created_at_range() -> anyhow::Result<()> {
+    use created_at_range::{UserFilters, UserSortBy, Users};
+
+    let pool = helpers::init_pool().await?;
+    let users = Users { pool };
+
+    let unique_name = format!("RangeTest_{}", UserId::new());
+    let mut created = Vec::new();
+    for _ in 0..3 {
+        let new_user = NewUser::builder()
+            .id(UserId::new())
+            .name(&unique_name)
+            .build()
+            .unwrap();
+        let user = users.create(new_user).await?;
+        created.push((user.id, user.events().entity_first_persisted_at().unwrap()));
+    }
+    let sort = || Sort {
+        by: UserSortBy::CreatedAt,
+        direction: ListDirection::Ascending,
+    };
+    let ids = |entities: Vec<User>| entities.into_iter().map(|u| u.id).collect::<Vec<_>>();
+
+    // Omitting both bounds matches everything
+    let res = users
+        .list_for_filters(
+            UserFilters {
+                name: Some(unique_name.clone()),
+                ..Default::default()
+            },
+            sort(),
+            PaginatedQueryArgs {
+                first: 10,
+                after: None,
+            },
+        )
+        .await?;
+    assert_eq!(
+        ids(res.entities),
+        vec![created[0].0, created[1].0, created[2].0]
+    );
+
+    // Each bound is independently optional and inclusive
+    let res = users
+        .list_for_filters(
+            UserFilters {
+                name: Some(unique_name.clone()),
+                created_at_from: Some(created[1].1),
+                ..Default::default()
+            },
+            sort(),
+            PaginatedQueryArgs {
+                first: 10,
+                after: None,
+            },
+        )
+        .await?;
+    assert_eq!(ids(res.entities), vec![created[1].0, created[2].0]);
+
+    let res = users
+        .list_for_filters(
+            UserFilters {
+                name: Some(unique_name.clone()),
+                created_at_from: Some(created[1].1),
+                created_at_to: Some(created[1].1),
+            },
+            sort(),
+            PaginatedQueryArgs {
+                first: 10,
+                after: None,
+            },
+        )
+        .await?;
+    assert_eq!(ids(res.entities), vec![created[1].0]);
+
+    // Cursor pagination over the filtered column
+    let first_page = users
+        .list_for_filters(
+            UserFilters {
+                name: Some(unique_name.clone()),
+                created_at_to: Some(created[1].1),
+                ..Default::default()
+            },
+            sort(),
+            PaginatedQueryArgs {
+                first: 1,
+                after: None,
+            },
+        )
+        .await?;
+    assert!(first_page.has_next_page);
+    let second_page = users
+        .list_for_filters(
+            UserFilters {
+                name: Some(unique_name.clone()),
+                created_at_to: Some(created[1].1),
+                ..Default::default()
+            },
+            sort(),
+            PaginatedQueryArgs {
+                first: 1,
+                after: first_page.end_cursor,
+            },
+        )
+        .await?;
+    assert!(!second_page.has_next_page);
+    assert_eq!(
+        [ids(first_page.entities), ids(second_page.entities)].concat(),
+        vec![created[0].0, created[1].0]
+    );
+
+    // An empty range is rejected without querying
+    let res = users
+        .list_for_filters(
+            UserFilters {
+                created_at_from: Some(created[2].1),
+                created_at_to: Some(created[0].1),
+                ..Default::default()
+            },
+            sort(),
+            PaginatedQueryArgs {
+                first: 10,
+                after: None,
+            },
+        )
+        .await;
+    assert!(matches!(
+        res,
+        Err(created_at_range::UserQueryError::InvalidFilters(
+            FilterError::EmptyRange("created_at")
+        ))
+    ));
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn update_all() -> anyhow::Result<()> {
     let pool = helpers::init_pool().await?;
@@ -238,3 +745,112 @@ async fn update_all() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn update_all_in_op_counts() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let users = Users::new(pool);
+
+    let new_users = ["Dora", "Emil", "Fritz"]
+        .iter()
+        .map(|name| {
+            NewUser::builder()
+                .id(UserId::new())
+                .name(*name)
+                .build()
+                .unwrap()
+        })
+        .collect();
+    let mut created = users.create_all(new_users).await?;
+
+    let _ = created[0].update_name("Dora_updated");
+    let _ = created[1].update_name("Emil_updated");
+    let _ = created[1].update_name("Emil_updated_again");
+
+    let mut op = users.begin_op().await?;
+    let counts = users.update_all_in_op_counts(&mut op, &mut created).await?;
+    op.commit().await?;
+
+    assert_eq!(counts.len(), 2);
+    assert_eq!(counts[&created[0].id], 1);
+    assert_eq!(counts[&created[1].id], 2);
+    assert!(!counts.contains_key(&created[2].id));
+
+    Ok(())
+}
+
+mod page_probe {
+    use es_entity::*;
+    use sqlx::PgPool;
+
+    use crate::entities::user::*;
+
+    #[derive(EsRepo, Debug)]
+    #[es_repo(entity = "User", page_probe, columns(name(ty = "String", list_for)))]
+    pub struct Users {
+        pub pool: PgPool,
+    }
+}
+
+#[tokio::test]
+async fn list_with_page_probe() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let users = page_probe::Users { pool };
+
+    let name = format!("probed_{}", UserId::new());
+    let new_users = (0..3)
+        .map(|_| {
+            NewUser::builder()
+                .id(UserId::new())
+                .name("unprobed")
+                .build()
+                .unwrap()
+        })
+        .collect();
+    let mut created = users.create_all(new_users).await?;
+    for user in created.iter_mut() {
+        let _ = user.update_name(&name);
+    }
+    users.update_all(&mut created).await?;
+
+    let filters = || page_probe::UserFilters {
+        name: Some(name.clone()),
+    };
+    let sort = Sort {
+        by: page_probe::UserSortBy::Id,
+        direction: ListDirection::Ascending,
+    };
+    let first_page = users
+        .list_for_filters(
+            filters(),
+            sort,
+            PaginatedQueryArgs {
+                first: 2,
+                after: None,
+            },
+        )
+        .await?;
+    assert!(first_page.has_next_page);
+    assert_eq!(first_page.entities.len(), 2);
+    for user in &first_page.entities {
+        assert_eq!(user.name, name);
+        assert_eq!(user.events().iter_persisted().count(), 2);
+    }
+
+    let second_page = users
+        .list_for_filters(
+            filters(),
+            sort,
+            PaginatedQueryArgs {
+                first: 2,
+                after: first_page.end_cursor,
+            },
+        )
+        .await?;
+    assert!(!second_page.has_next_page);
+    assert_eq!(second_page.entities.len(), 1);
+    assert_eq!(second_page.entities[0].name, name);
+    assert_eq!(second_page.entities[0].events().iter_persisted().count(), 2);
+
+    Ok(())
+}
@@ -171,15 +171,20 @@ async fn find_by_id_not_found_has_column_and_value() -> anyhow::Result<()> {
         .expect("not_found_value should be parseable as UserId");
     assert_eq!(parsed, missing_id);
 
+    // The typed id is preserved as well
+    assert_eq!(err.not_found_id(), Some(&missing_id));
+
     // Pattern matching on the variant
     match &err {
         UserFindError::NotFound {
             column: Some(UserColumn::Id),
             value,
+            id,
             ..
         } => {
             let parsed: UserId = value.parse().expect("value should be parseable as UserId");
             assert_eq!(parsed, missing_id);
+            assert_eq!(*id, Some(missing_id));
         }
         other => panic!("expected NotFound with column Id, got: {other:?}"),
     }
@@ -207,6 +212,7 @@ async fn find_by_name_not_found_has_column_and_value() -> anyhow::Result<()> {
         value.contains(&missing_name),
         "not_found_value should contain the name: got {value}"
     );
+    assert!(err.not_found_id().is_none());
 
     // Pattern matching on the variant
     match &err {
@@ -219,3 +225,82 @@ async fn find_by_name_not_found_has_column_and_value() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn column_for_constraint_maps_constraint_names() {
+    assert_eq!(
+        Profiles::column_for_constraint("profiles_email_key"),
+        Some(ProfileColumn::Email)
+    );
+    assert_eq!(
+        Profiles::column_for_constraint("profiles_pkey"),
+        Some(ProfileColumn::Id)
+    );
+    assert_eq!(Profiles::column_for_constraint("users_pkey"), None);
+}
+
+mod composite {
+    use super::entities::profile::{NewProfile, Profile, ProfileEvent, ProfileId};
+    use super::helpers;
+    use es_entity::*;
+    use sqlx::PgPool;
+
+    #[derive(EsRepo, Debug)]
+    #[es_repo(
+        tbl_prefix = "composite",
+        entity = "Profile",
+        columns(
+            name(ty = "String", update(accessor = "data.name")),
+            email(ty = "String"),
+        ),
+        unique(columns = "name, email")
+    )]
+    pub struct CompositeProfiles {
+        pool: PgPool,
+    }
+
+    #[tokio::test]
+    async fn composite_unique_violation_maps_to_composite_column() -> anyhow::Result<()> {
+        let pool = helpers::init_pool().await?;
+        let profiles = CompositeProfiles { pool };
+
+        let email = format!("composite_{}@test.com", ProfileId::new());
+        let first = NewProfile::builder()
+            .id(ProfileId::new())
+            .name("Same")
+            .email(&email)
+            .build()
+            .unwrap();
+        profiles.create(first).await?;
+
+        // Same email with a different name does not violate the composite constraint
+        let other_name = NewProfile::builder()
+            .id(ProfileId::new())
+            .name("Other")
+            .email(&email)
+            .build()
+            .unwrap();
+        profiles.create(other_name).await?;
+
+        let duplicate = NewProfile::builder()
+            .id(ProfileId::new())
+            .name("Same")
+            .email(&email)
+            .build()
+            .unwrap();
+        let err = match profiles.create(duplicate).await {
+            Err(e) => e,
+            Ok(_) => panic!("expected constraint violation"),
+        };
+
+        assert!(err.was_duplicate_by(ProfileColumn::NameEmail));
+        assert!(err.was_duplicate_composite(&[ProfileColumn::Email, ProfileColumn::Name]));
+        assert!(!err.was_duplicate_composite(&[ProfileColumn::Email]));
+        assert_eq!(
+            CompositeProfiles::column_for_constraint("composite_profiles_name_email_key"),
+            Some(ProfileColumn::NameEmail)
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+#![cfg(feature = "testing")]
+
+mod entities;
+mod helpers;
+
+use entities::user::*;
+use es_entity::*;
+use sqlx::PgPool;
+
+#[derive(EsRepo, Debug)]
+#[es_repo(entity = "User", columns(name(ty = "String")))]
+struct Users {
+    pool: PgPool,
+}
+
+#[test]
+fn fabricate_entity_from_events() -> anyhow::Result<()> {
+    let id = UserId::new();
+    let user = Users::__fabricate(
+        id,
+        [
+            UserEvent::Initialized {
+                id,
+                name: "Alice".to_string(),
+            },
+            UserEvent::NameUpdated {
+                name: "Bob".to_string(),
+            },
+        ],
+    )?;
+
+    assert_eq!(user.id, id);
+    assert_eq!(user.name, "Bob");
+    assert!(!user.events().any_new());
+    assert_eq!(user.events().len_persisted(), 2);
+
+    Ok(())
+}
+
+#[test]
+fn fabricator_applies_events_at_recorded_at() -> anyhow::Result<()> {
+    let id = UserId::new();
+    let recorded_at = prelude::chrono::DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+    let mut user = Users::__fabricator(id)
+        .event(UserEvent::Initialized {
+            id,
+            name: "Alice".to_string(),
+        })
+        .recorded_at(recorded_at)
+        .build()?;
+
+    assert_eq!(user.events().entity_first_persisted_at(), Some(recorded_at));
+    assert!(user.update_name("Carol").did_execute());
+    assert!(user.events().any_new());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn fabricated_entity_matches_loaded_entity() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let users = Users { pool };
+
+    let id = UserId::new();
+    let new_user = NewUser::builder().id(id).name("Dave").build().unwrap();
+    let loaded = users.create(new_user).await?;
+
+    let fabricated = Users::__fabricate(
+        id,
+        [UserEvent::Initialized {
+            id,
+            name: "Dave".to_string(),
+        }],
+    )?;
+    assert_eq!(fabricated.to_snapshot_json(), loaded.to_snapshot_json());
+    assert_eq!(
+        fabricated.events().len_persisted(),
+        loaded.events().len_persisted()
+    );
+
+    Ok(())
+}
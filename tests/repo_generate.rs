@@ -0,0 +1,34 @@
+mod entities;
+mod helpers;
+
+use entities::user::*;
+use es_entity::*;
+use sqlx::PgPool;
+
+#[derive(EsRepo, Debug)]
+#[es_repo(
+    entity = "User",
+    generate(list = false),
+    columns(name(ty = "String", list_by, list_for))
+)]
+pub struct Users {
+    pool: PgPool,
+}
+
+#[tokio::test]
+async fn repo_without_list_fns_still_finds() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let users = Users { pool };
+
+    let name = format!("NoLists-{}", UserId::new());
+    let user = users
+        .create(NewUser::builder().id(UserId::new()).name(&name).build()?)
+        .await?;
+
+    let found = users.find_by_name(&name).await?;
+    assert_eq!(found.id, user.id);
+    // The cursors are still generated
+    let _ = user_cursor::UserByNameCursor::from(&found);
+
+    Ok(())
+}
@@ -112,8 +112,79 @@ mod users_with_persist_hook {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Repo with post_persist_async_spawn — publishes persisted events after commit
+// ---------------------------------------------------------------------------
+
+mod users_with_spawned_persist_hook {
+    use es_entity::*;
+    use sqlx::PgPool;
+    use tokio::sync::mpsc;
+
+    use crate::entities::user::*;
+
+    #[derive(EsRepo, Debug, Clone)]
+    #[es_repo(
+        entity = "User",
+        columns(name = "String"),
+        post_persist_hook = "publish",
+        post_persist_async_spawn
+    )]
+    pub struct UsersWithSpawnedPersistHook {
+        pool: PgPool,
+        published: mpsc::UnboundedSender<(UserId, usize)>,
+    }
+
+    impl UsersWithSpawnedPersistHook {
+        pub fn new(pool: PgPool, published: mpsc::UnboundedSender<(UserId, usize)>) -> Self {
+            Self { pool, published }
+        }
+
+        async fn publish(&self, new_events: Vec<PersistedEvent<UserEvent>>) {
+            for event in new_events {
+                let _ = self.published.send((event.entity_id, event.sequence));
+            }
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Repo with pre_persist_hook — stamps a schema version onto stored events
+// ---------------------------------------------------------------------------
+
+mod users_with_pre_persist_hook {
+    use es_entity::*;
+    use sqlx::PgPool;
+
+    use crate::entities::user::*;
+
+    #[derive(EsRepo, Debug)]
+    #[es_repo(
+        entity = "User",
+        columns(name = "String"),
+        pre_persist_hook = "stamp_events"
+    )]
+    pub struct UsersWithPrePersistHook {
+        pool: PgPool,
+    }
+
+    impl UsersWithPrePersistHook {
+        pub fn new(pool: PgPool) -> Self {
+            Self { pool }
+        }
+
+        fn stamp_events(&self, events: &mut [serde_json::Value]) {
+            for event in events {
+                event["schema_version"] = serde_json::json!(2);
+            }
+        }
+    }
+}
+
 use users_with_hydrate_hook::UsersWithHydrateHook;
 use users_with_persist_hook::UsersWithPersistHook;
+use users_with_pre_persist_hook::UsersWithPrePersistHook;
+use users_with_spawned_persist_hook::UsersWithSpawnedPersistHook;
 
 // ===========================================================================
 // post_hydrate_hook tests
@@ -278,3 +349,80 @@ async fn post_persist_hook_error_propagates_through_update() -> anyhow::Result<(
 
     Ok(())
 }
+
+// ===========================================================================
+// post_persist_async_spawn tests
+// ===========================================================================
+
+#[tokio::test]
+async fn post_persist_async_spawn_runs_hook_after_commit() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    let users = UsersWithSpawnedPersistHook::new(pool, tx);
+
+    let id = UserId::new();
+    let new_user = NewUser::builder().id(id).name("Dave").build().unwrap();
+    let mut op = users.begin_op().await?;
+    users.create_in_op(&mut op, new_user).await?;
+    tokio::task::yield_now().await;
+    assert!(rx.try_recv().is_err());
+
+    op.commit().await?;
+    assert_eq!(rx.recv().await, Some((id, 1)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn post_persist_async_spawn_skips_hook_on_rollback() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    let users = UsersWithSpawnedPersistHook::new(pool, tx);
+
+    let new_user = NewUser::builder()
+        .id(UserId::new())
+        .name("Erin")
+        .build()
+        .unwrap();
+    let mut op = users.begin_op().await?;
+    users.create_in_op(&mut op, new_user).await?;
+    drop(op);
+    drop(users);
+
+    // All senders are dropped without anything being published
+    assert_eq!(rx.recv().await, None);
+
+    Ok(())
+}
+
+// ===========================================================================
+// pre_persist_hook tests
+// ===========================================================================
+
+#[tokio::test]
+async fn pre_persist_hook_transforms_stored_events() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let users = UsersWithPrePersistHook::new(pool.clone());
+
+    let id = UserId::new();
+    let new_user = NewUser::builder().id(id).name("Frank").build().unwrap();
+    let mut user = users.create(new_user).await?;
+    let _ = user.update_name("Francis");
+    users.update(&mut user).await?;
+
+    let rows = sqlx::query!(
+        "SELECT event FROM user_events WHERE id = $1 ORDER BY sequence",
+        id as UserId
+    )
+    .fetch_all(&pool)
+    .await?;
+    assert_eq!(rows.len(), 2);
+    for row in rows {
+        assert_eq!(row.event["schema_version"], serde_json::json!(2));
+    }
+
+    let loaded = users.find_by_id(id).await?;
+    assert_eq!(loaded.name, "Francis");
+
+    Ok(())
+}
@@ -0,0 +1,123 @@
+#![cfg(feature = "testing")]
+
+mod entities;
+mod helpers;
+
+use entities::user::*;
+use es_entity::*;
+use sqlx::PgPool;
+
+#[derive(EsRepo, Debug)]
+#[es_repo(tbl_prefix = "truncate", entity = "User", columns(name(ty = "String")))]
+struct Users {
+    pool: PgPool,
+}
+
+#[tokio::test]
+async fn truncate_all_for_tests() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let users = Users { pool };
+
+    for name in ["Alice", "Bob"] {
+        let new_user = NewUser::builder()
+            .id(UserId::new())
+            .name(name)
+            .build()
+            .unwrap();
+        users.create(new_user).await?;
+    }
+    let ret = users
+        .list_by_id(
+            PaginatedQueryArgs {
+                first: 10,
+                after: None,
+            },
+            ListDirection::Ascending,
+        )
+        .await?;
+    assert_eq!(ret.entities.len(), 2);
+
+    users.truncate_all_for_tests().await?;
+
+    let ret = users
+        .list_by_id(
+            PaginatedQueryArgs {
+                first: 10,
+                after: None,
+            },
+            ListDirection::Ascending,
+        )
+        .await?;
+    assert!(ret.entities.is_empty());
+
+    Ok(())
+}
+
+mod admins {
+    use es_entity::*;
+    use sqlx::PgPool;
+
+    use crate::entities::user::*;
+
+    #[derive(EsRepo, Debug)]
+    #[es_repo(
+        entity = "User",
+        tbl = "truncate_admins",
+        events_tbl = "truncate_shared_user_events",
+        events_discriminator(column = "kind", value = "admin"),
+        columns(name(ty = "String"))
+    )]
+    pub struct Admins {
+        pub pool: PgPool,
+    }
+}
+
+mod members {
+    use es_entity::*;
+    use sqlx::PgPool;
+
+    use crate::entities::user::*;
+
+    #[derive(EsRepo, Debug)]
+    #[es_repo(
+        entity = "User",
+        tbl = "truncate_members",
+        events_tbl = "truncate_shared_user_events",
+        events_discriminator(column = "kind", value = "member"),
+        columns(name(ty = "String"))
+    )]
+    pub struct Members {
+        pub pool: PgPool,
+    }
+}
+
+#[tokio::test]
+async fn truncate_keeps_the_events_of_repos_sharing_the_table() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let admins = admins::Admins { pool: pool.clone() };
+    let members = members::Members { pool: pool.clone() };
+
+    let admin_id = UserId::new();
+    admins
+        .create(NewUser::builder().id(admin_id).name("Admin").build()?)
+        .await?;
+    let member_id = UserId::new();
+    members
+        .create(NewUser::builder().id(member_id).name("Member").build()?)
+        .await?;
+
+    admins.truncate_all_for_tests().await?;
+
+    assert!(admins.maybe_find_by_id(admin_id).await?.is_none());
+    let remaining = sqlx::query_scalar!(
+        "SELECT COUNT(*) FROM truncate_shared_user_events WHERE kind = 'admin'"
+    )
+    .fetch_one(&pool)
+    .await?;
+    assert_eq!(remaining, Some(0));
+
+    let member = members.find_by_id(member_id).await?;
+    assert_eq!(member.name, "Member");
+
+    Ok(())
+}
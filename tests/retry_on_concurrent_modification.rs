@@ -0,0 +1,117 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+
+#[derive(Debug)]
+enum TestError {
+    ConcurrentModification,
+    Other,
+}
+
+impl TestError {
+    fn was_concurrent_modification(&self) -> bool {
+        matches!(self, TestError::ConcurrentModification)
+    }
+}
+
+struct Service {
+    attempts: AtomicU32,
+    fail_times: u32,
+    error: fn() -> TestError,
+}
+
+impl Service {
+    fn new(fail_times: u32, error: fn() -> TestError) -> Self {
+        Self {
+            attempts: AtomicU32::new(0),
+            fail_times,
+            error,
+        }
+    }
+
+    fn attempt(&self) -> Result<u32, TestError> {
+        let n = self.attempts.fetch_add(1, Ordering::SeqCst) + 1;
+        if n <= self.fail_times {
+            Err((self.error)())
+        } else {
+            Ok(n)
+        }
+    }
+
+    #[es_entity::retry_on_concurrent_modification]
+    async fn default_retries(&self) -> Result<u32, TestError> {
+        self.attempt()
+    }
+
+    #[es_entity::retry_on_concurrent_modification(max_retries = 5, backoff_ms = 10)]
+    async fn with_backoff(&self) -> Result<u32, TestError> {
+        self.attempt()
+    }
+
+    #[es_entity::retry_on_concurrent_modification(is_conflict = AppError::is_conflict)]
+    async fn wrapped(&self) -> Result<u32, AppError> {
+        self.attempt().map_err(AppError::Test)
+    }
+}
+
+/// Wraps the error without forwarding `was_concurrent_modification`.
+#[derive(Debug)]
+enum AppError {
+    Test(TestError),
+}
+
+impl AppError {
+    fn is_conflict(&self) -> bool {
+        matches!(self, AppError::Test(TestError::ConcurrentModification))
+    }
+}
+
+#[tokio::test]
+async fn retries_until_success() {
+    let service = Service::new(2, || TestError::ConcurrentModification);
+    assert_eq!(service.default_retries().await.unwrap(), 3);
+}
+
+#[tokio::test]
+async fn returns_last_error_when_retries_are_exhausted() {
+    let service = Service::new(10, || TestError::ConcurrentModification);
+    assert!(
+        service
+            .default_retries()
+            .await
+            .unwrap_err()
+            .was_concurrent_modification()
+    );
+    assert_eq!(service.attempts.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn does_not_retry_other_errors() {
+    let service = Service::new(1, || TestError::Other);
+    assert!(matches!(
+        service.with_backoff().await,
+        Err(TestError::Other)
+    ));
+    assert_eq!(service.attempts.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn backs_off_exponentially_between_attempts() {
+    let service = Service::new(3, || TestError::ConcurrentModification);
+
+    let start = std::time::Instant::now();
+    assert_eq!(service.with_backoff().await.unwrap(), 4);
+    // 10ms + 20ms + 40ms
+    assert!(start.elapsed() >= std::time::Duration::from_millis(70));
+}
+
+#[tokio::test]
+async fn custom_predicate_decides_what_to_retry() {
+    let service = Service::new(2, || TestError::ConcurrentModification);
+    assert_eq!(service.wrapped().await.unwrap(), 3);
+
+    let service = Service::new(1, || TestError::Other);
+    assert!(matches!(
+        service.wrapped().await,
+        Err(AppError::Test(TestError::Other))
+    ));
+    assert_eq!(service.attempts.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,93 @@
+mod entities;
+mod helpers;
+
+use entities::user::*;
+use es_entity::*;
+use sqlx::PgPool;
+
+#[derive(EsRepo, Debug)]
+#[es_repo(entity = "User", columns(name(ty = "String")))]
+struct Users {
+    pool: PgPool,
+}
+
+fn new_user(name: &str) -> NewUser {
+    NewUser::builder()
+        .id(UserId::new())
+        .name(name)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn rollback_to_discards_work() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let users = Users { pool };
+
+    let mut op = users.begin_op().await?;
+    let kept = users.create_in_op(&mut op, new_user("Kept")).await?;
+
+    let mut savepoint = op.savepoint().await?;
+    let discarded = users
+        .create_in_op(&mut savepoint, new_user("Discarded"))
+        .await?;
+    savepoint.rollback_to().await?;
+
+    assert!(
+        users
+            .maybe_find_by_id_in_op(&mut op, kept.id)
+            .await?
+            .is_some()
+    );
+    assert!(
+        users
+            .maybe_find_by_id_in_op(&mut op, discarded.id)
+            .await?
+            .is_none()
+    );
+    op.commit().await?;
+
+    assert!(users.maybe_find_by_id(kept.id).await?.is_some());
+    assert!(users.maybe_find_by_id(discarded.id).await?.is_none());
+    Ok(())
+}
+
+#[tokio::test]
+async fn release_keeps_work() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let users = Users { pool };
+
+    let mut op = users.begin_op().await?;
+    let mut savepoint = op.savepoint().await?;
+    let user = users
+        .create_in_op(&mut savepoint, new_user("Released"))
+        .await?;
+    savepoint.release().await?;
+    op.commit().await?;
+
+    assert!(users.maybe_find_by_id(user.id).await?.is_some());
+    Ok(())
+}
+
+#[tokio::test]
+async fn nested_savepoints() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let users = Users { pool };
+
+    let mut op = users.begin_op().await?;
+    let mut outer = op.savepoint().await?;
+    let outer_user = users.create_in_op(&mut outer, new_user("Outer")).await?;
+
+    let outer_name = outer.name().to_string();
+    let mut inner = outer.savepoint().await?;
+    assert_ne!(inner.name(), outer_name);
+    let inner_user = users.create_in_op(&mut inner, new_user("Inner")).await?;
+    inner.rollback_to().await?;
+
+    outer.release().await?;
+    op.commit().await?;
+
+    assert!(users.maybe_find_by_id(outer_user.id).await?.is_some());
+    assert!(users.maybe_find_by_id(inner_user.id).await?.is_none());
+    Ok(())
+}
@@ -0,0 +1,160 @@
+mod entities;
+mod helpers;
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use entities::user::*;
+use es_entity::*;
+use sqlx::PgPool;
+
+/// Same on-disk format as `UserEvent` plus a `schema_version` next to `type`.
+#[derive(EsEvent, Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "UserId", event_context, version = 2)]
+pub enum MemberEvent {
+    Initialized {
+        id: UserId,
+        name: String,
+    },
+    #[es_event(version = 3)]
+    NameUpdated {
+        name: String,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EntityHydrationError"))]
+pub struct Member {
+    pub id: UserId,
+    pub name: String,
+    pub schema_versions: Vec<u32>,
+    events: EntityEvents<MemberEvent>,
+}
+
+impl Member {
+    fn update_name(&mut self, name: impl Into<String>) {
+        let name = name.into();
+        self.name = name.clone();
+        self.events.push(MemberEvent::NameUpdated { name });
+    }
+}
+
+impl TryFromEvents<MemberEvent> for Member {
+    fn try_from_events(events: EntityEvents<MemberEvent>) -> Result<Self, EntityHydrationError> {
+        let mut builder = MemberBuilder::default().schema_versions(
+            events
+                .iter_persisted()
+                .map(|event| event.schema_version)
+                .collect(),
+        );
+        for event in events.iter_all() {
+            match event {
+                MemberEvent::Initialized { id, name } => {
+                    builder = builder.id(*id).name(name.clone());
+                }
+                MemberEvent::NameUpdated { name } => {
+                    builder = builder.name(name.clone());
+                }
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+pub struct NewMember {
+    id: UserId,
+    name: String,
+}
+
+impl IntoEvents<MemberEvent> for NewMember {
+    fn into_events(self) -> EntityEvents<MemberEvent> {
+        EntityEvents::init(
+            self.id,
+            [MemberEvent::Initialized {
+                id: self.id,
+                name: self.name,
+            }],
+        )
+    }
+}
+
+#[derive(EsRepo, Debug)]
+#[es_repo(
+    entity = "Member",
+    id = "UserId",
+    tbl = "users",
+    events_tbl = "user_events",
+    columns(name(ty = "String"))
+)]
+pub struct Members {
+    pool: PgPool,
+}
+
+#[derive(EsRepo, Debug)]
+#[es_repo(entity = "User", columns(name(ty = "String")))]
+pub struct Users {
+    pool: PgPool,
+}
+
+#[tokio::test]
+async fn schema_version_is_stored_alongside_type() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let members = Members { pool: pool.clone() };
+
+    let id = UserId::new();
+    let mut member = members
+        .create(NewMember {
+            id,
+            name: "Versioned".to_string(),
+        })
+        .await?;
+    assert_eq!(member.schema_versions, vec![2]);
+
+    member.update_name("Renamed");
+    members.update(&mut member).await?;
+
+    let rows = sqlx::query!(
+        "SELECT event FROM user_events WHERE id = $1 ORDER BY sequence",
+        id as UserId
+    )
+    .fetch_all(&pool)
+    .await?;
+    assert_eq!(
+        rows[0].event,
+        serde_json::json!({ "type": "initialized", "schema_version": 2, "id": id, "name": "Versioned" })
+    );
+    assert_eq!(
+        rows[1].event,
+        serde_json::json!({ "type": "name_updated", "schema_version": 3, "name": "Renamed" })
+    );
+
+    let member = members.find_by_id(id).await?;
+    assert_eq!(member.name, "Renamed");
+    assert_eq!(member.schema_versions, vec![2, 3]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn missing_schema_version_defaults_to_one() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let members = Members { pool: pool.clone() };
+    let users = Users { pool: pool.clone() };
+
+    // Written without a version, as rows from before the event was versioned
+    let user = users
+        .create(
+            NewUser::builder()
+                .id(UserId::new())
+                .name("Legacy")
+                .build()?,
+        )
+        .await?;
+
+    let member = members.find_by_id(user.id).await?;
+    assert_eq!(member.name, "Legacy");
+    assert_eq!(member.schema_versions, vec![1]);
+
+    Ok(())
+}
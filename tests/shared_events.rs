@@ -0,0 +1,111 @@
+mod entities;
+mod helpers;
+
+use entities::user::*;
+use es_entity::*;
+use sqlx::PgPool;
+
+#[derive(EsRepo, Debug)]
+#[es_repo(
+    entity = "User",
+    tbl = "shared_admins",
+    events_tbl = "shared_user_events",
+    events_discriminator(column = "kind", value = "admin"),
+    generate(events_between),
+    columns(name(ty = "String", list_for))
+)]
+pub struct Admins {
+    pool: PgPool,
+}
+
+impl Admins {
+    pub async fn maybe_find_by_lowercase_name(
+        &self,
+        name: &str,
+    ) -> Result<Option<User>, UserQueryError> {
+        es_query!(
+            events_tbl = "shared_user_events",
+            discriminator_column = "kind",
+            discriminator = "admin",
+            entity = User,
+            "SELECT id FROM shared_admins WHERE LOWER(name) = $1",
+            name
+        )
+        .fetch_optional(self.pool())
+        .await
+    }
+}
+
+mod members {
+    use es_entity::*;
+    use sqlx::PgPool;
+
+    use crate::entities::user::*;
+
+    #[derive(EsRepo, Debug)]
+    #[es_repo(
+        entity = "User",
+        tbl = "shared_members",
+        events_tbl = "shared_user_events",
+        events_discriminator(column = "kind", value = "member"),
+        generate(events_between),
+        allow_find_by_id_from_events,
+        columns(name(ty = "String"))
+    )]
+    pub struct Members {
+        pub pool: PgPool,
+    }
+}
+
+#[tokio::test]
+async fn repos_sharing_an_events_table() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let admins = Admins { pool: pool.clone() };
+    let members = members::Members { pool: pool.clone() };
+
+    let admin_id = UserId::new();
+    let new_admin = NewUser::builder()
+        .id(admin_id)
+        .name(format!("Admin-{admin_id}"))
+        .build()
+        .unwrap();
+    let mut admin = admins.create(new_admin).await?;
+    let _ = admin.update_name(format!("Root-{admin_id}"));
+    admins.update(&mut admin).await?;
+
+    let member_id = UserId::new();
+    let new_member = NewUser::builder()
+        .id(member_id)
+        .name("Member")
+        .build()
+        .unwrap();
+    members.create(new_member).await?;
+
+    let rows = sqlx::query!(
+        "SELECT id, kind FROM shared_user_events WHERE id = ANY($1) ORDER BY kind, sequence",
+        &[admin_id, member_id] as &[UserId]
+    )
+    .fetch_all(&pool)
+    .await?;
+    let kinds: Vec<_> = rows.iter().map(|r| r.kind.as_str()).collect();
+    assert_eq!(kinds, ["admin", "admin", "member"]);
+
+    let loaded = admins.find_by_id(admin_id).await?;
+    assert_eq!(loaded.name, format!("Root-{admin_id}"));
+    assert_eq!(loaded.events().len_persisted(), 2);
+    let loaded = admins
+        .maybe_find_by_lowercase_name(&format!("root-{admin_id}").to_lowercase())
+        .await?;
+    assert_eq!(loaded.map(|u| u.id), Some(admin_id));
+    assert_eq!(members.find_by_id(member_id).await?.name, "Member");
+
+    // Events recorded by the other repo are never picked up
+    assert!(matches!(
+        members.find_by_id_from_events(admin_id).await,
+        Err(e) if e.was_not_found()
+    ));
+    assert!(members.events_between(&admin_id, 1, 2).await?.is_empty());
+    assert_eq!(admins.events_between(&admin_id, 1, 2).await?.len(), 2);
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+mod entities;
+mod helpers;
+
+use entities::{profile::*, user::*};
+use es_entity::*;
+use sqlx::PgPool;
+
+#[derive(EsRepo, Debug)]
+#[es_repo(entity = "User", columns(name(ty = "String")))]
+struct Users {
+    pool: PgPool,
+}
+
+#[derive(EsRepo, Debug)]
+#[es_repo(
+    entity = "Profile",
+    columns(
+        name(ty = "String", update(accessor = "data.name")),
+        display_name(
+            ty = "String",
+            create(accessor = "display_name()"),
+            update(accessor = "display_name()")
+        ),
+        email(ty = "String"),
+    )
+)]
+struct Profiles {
+    pool: PgPool,
+}
+
+fn new_user() -> NewUser {
+    NewUser::builder()
+        .id(UserId::new())
+        .name("Shared")
+        .build()
+        .unwrap()
+}
+
+fn new_profile() -> NewProfile {
+    let id = ProfileId::new();
+    NewProfile::builder()
+        .id(id)
+        .name("Shared")
+        .email(format!("shared_{id}@test.com"))
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn repos_enlist_in_an_external_transaction() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let users = Users { pool: pool.clone() };
+    let profiles = Profiles { pool: pool.clone() };
+
+    // Committing the transaction persists the writes of both repos
+    let mut tx = pool.begin().await?;
+    let user = users.create_in_op(&mut tx, new_user()).await?;
+    let profile = profiles.create_in_op(&mut tx, new_profile()).await?;
+    assert!(users.maybe_find_by_id(user.id).await?.is_none());
+    tx.commit().await?;
+    assert!(users.maybe_find_by_id(user.id).await?.is_some());
+    assert!(profiles.maybe_find_by_id(profile.id).await?.is_some());
+
+    // Dropping it discards the writes of both repos
+    let mut tx = pool.begin().await?;
+    let user = users.create_in_op(&mut tx, new_user()).await?;
+    let profile = profiles.create_in_op(&mut tx, new_profile()).await?;
+    drop(tx);
+    assert!(users.maybe_find_by_id(user.id).await?.is_none());
+    assert!(profiles.maybe_find_by_id(profile.id).await?.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn external_transaction_converts_into_db_op() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let users = Users { pool: pool.clone() };
+    let profiles = Profiles { pool: pool.clone() };
+
+    let tx = pool.begin().await?;
+    let mut op: DbOp = tx.into();
+    assert!(op.supports_hooks());
+    let user = users.create_in_op(&mut op, new_user()).await?;
+    let profile = profiles.create_in_op(&mut op, new_profile()).await?;
+    op.commit().await?;
+
+    assert!(users.maybe_find_by_id(user.id).await?.is_some());
+    assert!(profiles.maybe_find_by_id(profile.id).await?.is_some());
+
+    Ok(())
+}
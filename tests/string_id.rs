@@ -0,0 +1,172 @@
+mod helpers;
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use es_entity::*;
+use sqlx::PgPool;
+
+/// Natural key - a slug instead of a uuid
+#[derive(
+    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, sqlx::Type,
+)]
+#[sqlx(transparent)]
+#[serde(transparent)]
+pub struct ArticleSlug(String);
+
+impl From<&str> for ArticleSlug {
+    fn from(slug: &str) -> Self {
+        Self(slug.to_string())
+    }
+}
+
+impl std::fmt::Display for ArticleSlug {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[derive(EsEvent, Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "ArticleSlug")]
+pub enum ArticleEvent {
+    Initialized { id: ArticleSlug, title: String },
+    TitleUpdated { title: String },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EntityHydrationError"))]
+pub struct Article {
+    pub id: ArticleSlug,
+    pub title: String,
+    events: EntityEvents<ArticleEvent>,
+}
+
+impl Article {
+    pub fn update_title(&mut self, title: impl Into<String>) -> Idempotent<()> {
+        let title = title.into();
+        self.title = title.clone();
+        self.events.push(ArticleEvent::TitleUpdated { title });
+        Idempotent::Executed(())
+    }
+}
+
+impl TryFromEvents<ArticleEvent> for Article {
+    fn try_from_events(events: EntityEvents<ArticleEvent>) -> Result<Self, EntityHydrationError> {
+        let mut builder = ArticleBuilder::default();
+        for event in events.iter_all() {
+            match event {
+                ArticleEvent::Initialized { id, title } => {
+                    builder = builder.id(id.clone()).title(title.clone());
+                }
+                ArticleEvent::TitleUpdated { title } => {
+                    builder = builder.title(title.clone());
+                }
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+pub struct NewArticle {
+    id: ArticleSlug,
+    title: String,
+}
+
+impl IntoEvents<ArticleEvent> for NewArticle {
+    fn into_events(self) -> EntityEvents<ArticleEvent> {
+        EntityEvents::init(
+            self.id.clone(),
+            [ArticleEvent::Initialized {
+                id: self.id,
+                title: self.title,
+            }],
+        )
+    }
+}
+
+#[derive(EsRepo, Debug)]
+#[es_repo(
+    entity = "Article",
+    id = "ArticleSlug",
+    columns(title(ty = "String", list_for))
+)]
+pub struct Articles {
+    pool: PgPool,
+}
+
+#[tokio::test]
+async fn string_ids_round_trip() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let articles = Articles { pool };
+
+    let prefix = uuid::Uuid::now_v7().to_string();
+    let slug = ArticleSlug(format!("{prefix}-hello-world"));
+    let mut article = articles
+        .create(NewArticle {
+            id: slug.clone(),
+            title: "Hello".to_string(),
+        })
+        .await?;
+    let _ = article.update_title("Hello World");
+    articles.update(&mut article).await?;
+
+    let loaded = articles.find_by_id(&slug).await?;
+    assert_eq!(loaded.id, slug);
+    assert_eq!(loaded.title, "Hello World");
+    let found = articles
+        .find_all::<Article>(std::slice::from_ref(&slug))
+        .await?;
+    assert!(found.contains_key(&slug));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn string_ids_paginate_lexicographically() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let articles = Articles { pool };
+
+    let title = format!("paging-{}", uuid::Uuid::now_v7());
+    let prefix = uuid::Uuid::now_v7().to_string();
+    // Created out of order - "b10" sorts before "b2" as the ids are compared as text
+    let mut slugs: Vec<ArticleSlug> = ["c", "b2", "b10", "b"]
+        .iter()
+        .map(|s| ArticleSlug(format!("{prefix}-{s}")))
+        .collect();
+    for slug in slugs.iter() {
+        articles
+            .create(NewArticle {
+                id: slug.clone(),
+                title: title.clone(),
+            })
+            .await?;
+    }
+    slugs.sort();
+
+    for direction in [ListDirection::Ascending, ListDirection::Descending] {
+        let mut seen = Vec::new();
+        let mut after = None;
+        loop {
+            let page = articles
+                .list_for_title_by_id(
+                    title.clone(),
+                    PaginatedQueryArgs { first: 1, after },
+                    direction,
+                )
+                .await?;
+            seen.extend(page.entities.into_iter().map(|a| a.id));
+            if !page.has_next_page {
+                break;
+            }
+            after = page.end_cursor;
+        }
+        let mut expected = slugs.clone();
+        if matches!(direction, ListDirection::Descending) {
+            expected.reverse();
+        }
+        assert_eq!(seen, expected, "direction: {direction:?}");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+mod entities;
+mod helpers;
+
+use entities::user::*;
+use es_entity::*;
+use sqlx::PgPool;
+
+#[derive(EsRepo, Debug)]
+#[es_repo(
+    entity = "User",
+    tbl = "stamped_users",
+    events_tbl = "stamped_user_events",
+    columns(updated_at, name(ty = "String"))
+)]
+pub struct StampedUsers {
+    pool: PgPool,
+}
+
+async fn updated_at(pool: &PgPool, id: UserId) -> anyhow::Result<chrono::DateTime<chrono::Utc>> {
+    let row = sqlx::query!(
+        "SELECT updated_at FROM stamped_users WHERE id = $1",
+        id as UserId
+    )
+    .fetch_one(pool)
+    .await?;
+    Ok(row.updated_at)
+}
+
+#[tokio::test]
+async fn updated_at_follows_latest_recorded_at() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let users = StampedUsers { pool: pool.clone() };
+
+    let new_user = NewUser::builder()
+        .id(UserId::new())
+        .name("Stamped")
+        .build()
+        .unwrap();
+    let mut user = users.create(new_user).await?;
+    assert_eq!(
+        updated_at(&pool, user.id).await?,
+        user.events().entity_first_persisted_at().unwrap()
+    );
+
+    let _ = user.update_name("Restamped");
+    users.update(&mut user).await?;
+    let last_modified = user.events().entity_last_modified_at().unwrap();
+    assert!(last_modified > user.events().entity_first_persisted_at().unwrap());
+    assert_eq!(updated_at(&pool, user.id).await?, last_modified);
+
+    let _ = user.update_name("Batch stamped");
+    users.update_all(std::slice::from_mut(&mut user)).await?;
+    assert_eq!(
+        updated_at(&pool, user.id).await?,
+        user.events().entity_last_modified_at().unwrap()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn create_all_sets_updated_at() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let users = StampedUsers { pool: pool.clone() };
+
+    let new_users = (0..2)
+        .map(|i| {
+            NewUser::builder()
+                .id(UserId::new())
+                .name(format!("stamped_{i}"))
+                .build()
+                .unwrap()
+        })
+        .collect();
+    for user in users.create_all(new_users).await? {
+        assert_eq!(
+            updated_at(&pool, user.id).await?,
+            user.events().entity_first_persisted_at().unwrap()
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,168 @@
+mod entities;
+mod helpers;
+
+use derive_builder::Builder;
+
+use entities::user::*;
+use es_entity::*;
+use sqlx::PgPool;
+
+/// A user as received from an upstream system: `New` carries its full history.
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EntityHydrationError"))]
+#[es_entity(event = "UserEvent", new = "SyncedUserState")]
+pub struct SyncedUser {
+    pub id: UserId,
+    pub name: String,
+    events: EntityEvents<UserEvent>,
+}
+
+impl TryFromEvents<UserEvent> for SyncedUser {
+    fn try_from_events(events: EntityEvents<UserEvent>) -> Result<Self, EntityHydrationError> {
+        let mut builder = SyncedUserBuilder::default();
+        for event in events.iter_all() {
+            match event {
+                UserEvent::Initialized { id, name } => {
+                    builder = builder.id(*id).name(name.clone());
+                }
+                UserEvent::NameUpdated { name } => {
+                    builder = builder.name(name.clone());
+                }
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+pub struct SyncedUserState {
+    id: UserId,
+    names: Vec<String>,
+}
+
+impl SyncedUserState {
+    /// Names are suffixed with the id to stay unique across test runs
+    fn new(id: UserId, names: &[&str]) -> Self {
+        Self {
+            id,
+            names: names.iter().map(|name| format!("{name}-{id}")).collect(),
+        }
+    }
+}
+
+impl SyncedUserState {
+    fn current_name(&self) -> String {
+        self.names.last().cloned().expect("at least one name")
+    }
+}
+
+impl IntoEvents<UserEvent> for SyncedUserState {
+    fn into_events(self) -> EntityEvents<UserEvent> {
+        let mut names = self.names.into_iter();
+        let first = names.next().expect("at least one name");
+        EntityEvents::init(
+            self.id,
+            std::iter::once(UserEvent::Initialized {
+                id: self.id,
+                name: first,
+            })
+            .chain(names.map(|name| UserEvent::NameUpdated { name })),
+        )
+    }
+}
+
+#[derive(EsRepo, Debug)]
+#[es_repo(
+    entity = "SyncedUser",
+    event = "UserEvent",
+    id = "UserId",
+    tbl = "users",
+    events_tbl = "user_events",
+    generate(upsert),
+    columns(name(ty = "String", create(accessor = "current_name()")))
+)]
+pub struct SyncedUsers {
+    pool: PgPool,
+}
+
+impl SyncedUsers {
+    async fn upsert(
+        &self,
+        state: SyncedUserState,
+    ) -> Result<Idempotent<SyncedUser>, SyncedUserCreateError> {
+        let mut op = self.begin_op().await?;
+        let res = self.upsert_in_op(&mut op, state).await?;
+        op.commit().await?;
+        Ok(res)
+    }
+}
+
+#[tokio::test]
+async fn upsert_creates_then_appends() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let users = SyncedUsers { pool };
+    let id = UserId::new();
+
+    let created = users.upsert(SyncedUserState::new(id, &["Alice"])).await?;
+    assert!(
+        matches!(created, Idempotent::Executed(ref user) if user.name == format!("Alice-{id}"))
+    );
+
+    let updated = users
+        .upsert(SyncedUserState::new(id, &["Alice", "Alicia", "Ali"]))
+        .await?;
+    assert!(matches!(updated, Idempotent::Executed(ref user) if user.name == format!("Ali-{id}")));
+
+    let loaded = users.find_by_id(id).await?;
+    assert_eq!(loaded.name, format!("Ali-{id}"));
+    assert_eq!(loaded.events().len_persisted(), 3);
+    // The index column follows the appended events
+    assert_eq!(users.find_by_name(format!("Ali-{id}")).await?.id, id);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn upsert_redelivery_is_a_no_op() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let users = SyncedUsers { pool };
+    let id = UserId::new();
+
+    let created = users
+        .upsert(SyncedUserState::new(id, &["Bob", "Bobby"]))
+        .await?;
+    assert!(created.did_execute());
+    let again = users
+        .upsert(SyncedUserState::new(id, &["Bob", "Bobby"]))
+        .await?;
+    assert!(again.was_already_applied());
+    assert_eq!(users.find_by_id(id).await?.events().len_persisted(), 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn upsert_diverging_history_is_a_concurrent_modification() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+    let users = SyncedUsers { pool };
+    let id = UserId::new();
+
+    let created = users
+        .upsert(SyncedUserState::new(id, &["Carol", "Caroline"]))
+        .await?;
+    assert!(created.did_execute());
+
+    let diverged = users
+        .upsert(SyncedUserState::new(id, &["Carol", "Carrie", "Caz"]))
+        .await;
+    assert!(matches!(
+        diverged,
+        Err(SyncedUserCreateError::ConcurrentModification)
+    ));
+    // An older (shorter) history diverges as well
+    let stale = users.upsert(SyncedUserState::new(id, &["Carol"])).await;
+    assert!(stale.is_err_and(|e| e.was_concurrent_modification()));
+
+    assert_eq!(users.find_by_id(id).await?.name, format!("Caroline-{id}"));
+
+    Ok(())
+}